@@ -5,12 +5,15 @@
 
 use std::collections::HashMap;
 use std::time::Instant;
+use uuid::Uuid;
 
 use rand;
 
 use super::BenchmarkScenario;
 
+use crate::neural;
 use crate::neural::neuron::Neuron;
+use crate::neural::synapse::Synapse;
 use crate::telemetry::TelemetryRegistry;
 use crate::telemetry::collector::TelemetryCollector;
 
@@ -92,8 +95,9 @@ impl BenchmarkScenario for SingleNeuronBenchmark {
 
 /// Einfacher Netzwerk-Stub für Benchmarks
 ///
-/// Diese Implementierung wird für Benchmarks verwendet, solange das
-/// vollständige Netzwerk-Modul noch nicht implementiert ist.
+/// Die Benchmarks verwenden inzwischen das vollständige [`neural::Network`]; der Stub
+/// bleibt nur für bestehende Aufrufer erhalten und wird in einer späteren Version entfernt.
+#[deprecated(note = "Die Benchmarks verwenden das vollständige `neural::Network`")]
 pub struct Network {
     #[allow(dead_code)]
     name: String,
@@ -101,6 +105,7 @@ pub struct Network {
     connections: Vec<(usize, usize, f32)>, // (Quelle, Ziel, Stärke)
 }
 
+#[allow(deprecated)]
 impl Network {
     /// Erstellt ein neues Netzwerk mit dem angegebenen Namen
     pub fn new(name: &str) -> Self {
@@ -157,6 +162,15 @@ impl Network {
     }
 }
 
+/// Obergrenze für die ausgehenden Verbindungen je Neuron im Skalierungsbenchmark
+///
+/// Kleine Netzwerke sind zu etwa 10% vernetzt; ab 1000 Neuronen bleibt der Ausgrad
+/// konstant, sodass die Synapsenzahl linear mit der Netzwerkgröße wächst.
+const MAX_CONNECTIONS_PER_NEURON: usize = 100;
+
+/// Zeitschritt eines Netzwerkzyklus im Skalierungsbenchmark in Sekunden
+const CYCLE_TIME_STEP: f32 = 0.001;
+
 /// Dieses Szenario misst, wie effizient das Netzwerk große Mengen an Neuronen verarbeiten kann.
 /// Es erstellt ein Netzwerk mit einer festgelegten Anzahl an Neuronen und führt eine bestimmte
 /// Anzahl an Zyklen durch.
@@ -171,7 +185,10 @@ pub struct NetworkScalabilityBenchmark<R = TelemetryRegistry> {
     cycles_per_iteration: usize,
 
     /// Innere Struktur des Netzwerks (wird dynamisch erstellt)
-    network: Option<Network>,
+    network: Option<neural::Network>,
+
+    /// IDs der Neuronen in Erstellungsreihenfolge, um Eingaben zufällig zu verteilen
+    neuron_ids: Vec<Uuid>,
 
     /// Eine benutzerdefinierte Registry, die für Tests verwendet werden kann
     /// Dies ermöglicht isolierte Tests, ohne die globale Registry zu beeinflussen
@@ -192,6 +209,7 @@ where
             network_size,
             cycles_per_iteration: 1000,
             network: None,
+            neuron_ids: Vec::new(),
             custom_registry: None,
         }
     }
@@ -258,33 +276,43 @@ where
 
     fn setup(&mut self) {
         // Neues Netzwerk erstellen
-        let mut network = Network::new(&format!("benchmark_network_{}", self.network_size));
+        let mut network = neural::Network::new();
 
         // Neuronen mit verschiedenen Geschwindigkeiten hinzufügen
-        for i in 0..self.network_size {
-            let speed = 200_u16.saturating_add((i % 800) as u16); // Neuronen mit unterschiedlichen Geschwindigkeiten
-            let neuron = Neuron::new(speed);
-            network.add_neuron(neuron);
-        }
-
-        // Zufällige Verbindungen zwischen Neuronen herstellen (ca. 10% Vernetzung)
+        self.neuron_ids = (0..self.network_size)
+            .map(|i| {
+                let speed = 200_u16.saturating_add((i % 800) as u16); // Neuronen mit unterschiedlichen Geschwindigkeiten
+                let neuron = Neuron::new(speed);
+                let id = *neuron.id();
+                network.add_neuron(neuron);
+                id
+            })
+            .collect();
+
+        // Zufällige Verbindungen mit begrenztem Ausgrad herstellen
         if self.network_size > 1 {
-            let connection_count = (self.network_size * self.network_size / 10).max(1);
-
-            for _ in 0..connection_count {
-                let source = rand::random::<usize>() % self.network_size;
-                let mut target = rand::random::<usize>() % self.network_size;
-
-                // Vermeidet Selbstverbindungen
-                while target == source {
-                    target = rand::random::<usize>() % self.network_size;
+            let connections_per_neuron =
+                (self.network_size / 10).clamp(1, MAX_CONNECTIONS_PER_NEURON);
+
+            for source in 0..self.network_size {
+                for _ in 0..connections_per_neuron {
+                    let mut target = rand::random::<usize>() % self.network_size;
+
+                    // Vermeidet Selbstverbindungen
+                    while target == source {
+                        target = rand::random::<usize>() % self.network_size;
+                    }
+
+                    // Verbindungsstärke zwischen 0.1 und 1.0
+                    let strength = 0.1 + rand::random::<f32>() * 0.9;
+
+                    // Verbindung herstellen
+                    network.add_synapse(Synapse::new(
+                        self.neuron_ids[source],
+                        self.neuron_ids[target],
+                        strength,
+                    ));
                 }
-
-                // Verbindungsstärke zwischen 0.1 und 1.0
-                let strength = 0.1 + rand::random::<f32>() * 0.9;
-
-                // Verbindung herstellen
-                network.connect_neurons(source, target, strength);
             }
         }
 
@@ -294,6 +322,7 @@ where
     fn teardown(&mut self) {
         // Netzwerk freigeben
         self.network = None;
+        self.neuron_ids.clear();
     }
 
     fn run_iteration(&mut self) {
@@ -317,13 +346,18 @@ where
                     let target = rand::random::<usize>() % self.network_size;
                     let input_value = rand::random::<f32>();
 
-                    network.send_input(target, input_value);
+                    network.stimulate_neuron(&self.neuron_ids[target], input_value);
                 }
 
                 // Netzwerkzyklus ausführen
                 let start_time = Instant::now();
-                let active_neurons = network.cycle(); // Kein time_step-Parameter
+                network.cycle(CYCLE_TIME_STEP);
                 let cycle_duration = start_time.elapsed();
+                let active_neurons = network
+                    .neurons()
+                    .values()
+                    .filter(|neuron| neuron.is_spiking())
+                    .count();
 
                 // Telemetrie aufzeichnen
                 if let Some(ref mut test_registry) = self.custom_registry {
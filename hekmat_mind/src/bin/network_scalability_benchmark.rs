@@ -12,14 +12,21 @@ fn main() {
     println!("HekmatMind Netzwerk-Skalierungstest");
     println!("===================================\n");
 
-    // Telemetrie-Umgebung vorbereiten
-    if let Ok(mut registry) = registry_mut() {
-        registry.clear();
+    // Telemetrie-Umgebung vorbereiten; die Schreibsperre muss vor dem Benchmark
+    // freigegeben werden, da die Szenarien selbst in die Registry schreiben
+    let registry_ready = match registry_mut() {
+        Ok(mut registry) => {
+            registry.clear();
 
-        // Telemetrie-Collector registrieren
-        let collector = InMemoryCollector::new(2000);
-        registry.register(Box::new(collector));
+            // Telemetrie-Collector registrieren
+            let collector = InMemoryCollector::new(2000);
+            registry.register(Box::new(collector));
+            true
+        }
+        Err(_) => false,
+    };
 
+    if registry_ready {
         // Skalierungstestgrößen definieren
         let network_sizes = [100, 1_000, 5_000, 10_000];
 
@@ -142,12 +142,11 @@ impl SystemNoiseSource {
                 let mut contents = String::new();
                 if file.read_to_string(&mut contents).is_ok() {
                     let fields: Vec<&str> = contents.split_whitespace().collect();
-                    if fields.len() > 14 {
-                        if let Ok(utime) = fields[13].parse::<u64>() {
-                            if let Ok(stime) = fields[14].parse::<u64>() {
-                                return Ok(utime + stime);
-                            }
-                        }
+                    if fields.len() > 14
+                        && let Ok(utime) = fields[13].parse::<u64>()
+                        && let Ok(stime) = fields[14].parse::<u64>()
+                    {
+                        return Ok(utime + stime);
                     }
                 }
             }
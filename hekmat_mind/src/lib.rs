@@ -137,7 +137,8 @@
 //! use hekmat_mind::telemetry::in_memory::InMemoryCollector;
 //!
 //! // Skalierbarkeitstest für ein neuronales Netzwerk mit 1000 Neuronen
-//! let mut scenario: NetworkScalabilityBenchmark<InMemoryCollector> = NetworkScalabilityBenchmark::new(1000);
+//! let mut scenario: NetworkScalabilityBenchmark<InMemoryCollector> =
+//!     NetworkScalabilityBenchmark::new(1000).with_cycles(10);
 //! let config = BenchmarkConfig::new("netzwerk_test", "Test der Netzwerkskalierbarkeit")
 //!     .with_iterations(3)
 //!     .with_warmup(1);
@@ -186,33 +187,66 @@ pub mod telemetry;
 pub use event_broker::EventBroker;
 
 // Neuronale Komponenten
+pub use neural::neuron::HodgkinHuxleyNeuron;
+pub use neural::neuron::LifNeuron;
 pub use neural::neuron::Neuron;
+pub use neural::neuron::NeuronModel;
 pub use neural::neuron::NeuronState;
 pub use neural::neuron::constants as neuron_constants;
+pub use neural::neuron::{IzhikevichNeuron, IzhikevichPreset};
 
 // Synaptische Komponenten
 pub use neural::synapse::Synapse;
 pub use neural::synapse::SynapseBuilder;
+pub use neural::synapse::SynapseType;
 pub use neural::synapse::constants as synapse_constants;
+pub use neural::synapse::{BcmRule, CovarianceRule, HebbianRule, OjaRule, PlasticityRule};
+pub use neural::synapse::{ShortTermPlasticity, StdpBounds, StdpRule, StpParams};
 
 // Netzwerkkomponenten
+pub use neural::ExecutionMode;
 pub use neural::Network;
 pub use neural::NetworkBuilder;
+pub use neural::SpikeEvent;
+pub use neural::network::NetworkError;
 
 pub mod prelude {
     // Neuronale Kernkomponenten
+    pub use crate::neural::neuron::HodgkinHuxleyNeuron;
+    pub use crate::neural::neuron::LifNeuron;
     pub use crate::neural::neuron::Neuron;
+    pub use crate::neural::neuron::NeuronModel;
     pub use crate::neural::neuron::NeuronState;
     pub use crate::neural::neuron::constants as neuron_constants;
+    pub use crate::neural::neuron::{IzhikevichNeuron, IzhikevichPreset};
 
     // Synaptische Komponenten
     pub use crate::neural::synapse::Synapse;
     pub use crate::neural::synapse::SynapseBuilder;
+    pub use crate::neural::synapse::SynapseType;
     pub use crate::neural::synapse::constants as synapse_constants;
+    pub use crate::neural::synapse::{
+        BcmRule, CovarianceRule, HebbianRule, OjaRule, PlasticityRule,
+    };
+    pub use crate::neural::synapse::{ShortTermPlasticity, StdpBounds, StdpRule, StpParams};
 
     // Netzwerkkomponenten
+    pub use crate::neural::ConnectionRule;
+    pub use crate::neural::DistanceKernel;
+    pub use crate::neural::ExecutionMode;
+    pub use crate::neural::GraphFilter;
     pub use crate::neural::Network;
     pub use crate::neural::NetworkBuilder;
+    pub use crate::neural::Placement;
+    pub use crate::neural::SnapshotFormat;
+    pub use crate::neural::SpikeEvent;
+    pub use crate::neural::Topology;
+    pub use crate::neural::{BenchmarkTask, Reservoir, ReservoirBuilder};
+    pub use crate::neural::{Decoder, Decoding, LinearReadout};
+    pub use crate::neural::{Encoder, Encoding, StimulusSchedule};
+    pub use crate::neural::{GraphAnalysis, Motif, Weighting};
+    pub use crate::neural::{Monitor, MonitorId, RateMonitor, SpikeRecorder, StateProbe};
+    pub use crate::neural::{Population, Projection};
 
     // Systemfunktionen
     pub use crate::event_broker::EventBroker;
@@ -181,7 +181,7 @@ impl AxonGrowth {
         let mut direction_change = [0.0, 0.0, 0.0];
 
         // Zufällige kleine Ablenkung für natürlicheres Wachstum (verhindert perfekt gerade Linien)
-        if self.segments.len() % 3 == 0 {
+        if self.segments.len().is_multiple_of(3) {
             // Jedes dritte Segment leichte Zufallsbewegung hinzufügen
             use std::f32::consts::PI;
             let noise_angle = (self.time * 7.0) % (2.0 * PI); // Deterministisches "Rauschen"
@@ -356,7 +356,7 @@ mod tests {
 
         // Wachstum mit attraktivem Faktor
         for _ in 0..5 {
-            axon.grow(&[attractive.clone()], 1.0);
+            axon.grow(std::slice::from_ref(&attractive), 1.0);
         }
 
         // Richtung sollte Y-Komponente haben
@@ -378,7 +378,7 @@ mod tests {
 
         // Sehr kleinen Zeitschritt verwenden, um das Hindernis nicht zu überspringen
         for i in 0..20 {
-            let _growth = axon.grow(&[obstacle.clone()], 0.1);
+            let _growth = axon.grow(std::slice::from_ref(&obstacle), 0.1);
 
             // Wenn wir genug gewachsen sind, sollten wir vom Pfad abweichen
             if i > 5 && axon.position().x > 0.5 {
@@ -506,8 +506,8 @@ mod tests {
 
         // Beide wachsen lassen
         for _ in 0..5 {
-            axon_attracted.grow(&[attractive.clone()], 1.0);
-            axon_repelled.grow(&[repulsive.clone()], 1.0);
+            axon_attracted.grow(std::slice::from_ref(&attractive), 1.0);
+            axon_repelled.grow(std::slice::from_ref(&repulsive), 1.0);
         }
 
         // Angezogenes Axon sollte schneller wachsen
@@ -58,9 +58,10 @@ pub mod constants {
 }
 
 /// Status einer Synapse
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
 pub enum SynapseState {
     /// Aktive, funktionierende Synapse
+    #[default]
     Active,
     /// Geschwächte Synapse, die zurückgebildet werden könnte
     Weakened,
@@ -68,12 +69,6 @@ pub enum SynapseState {
     Ghost,
 }
 
-impl Default for SynapseState {
-    fn default() -> Self {
-        Self::Active
-    }
-}
-
 /// Eine einzelne Synapse an einem dendritischen Segment
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Synapse {
@@ -1202,7 +1197,7 @@ mod tests {
         // Mehrere Wachstumsschritte
         let mut growth_count = 0;
         for _ in 0..20 {
-            if tree.grow(&[attractive.clone()], 0.5, 0.8) {
+            if tree.grow(std::slice::from_ref(&attractive), 0.5, 0.8) {
                 growth_count += 1;
             }
         }
@@ -1319,7 +1314,7 @@ mod tests {
         );
 
         for _ in 0..15 {
-            complex_tree.grow(&[attractive.clone()], 0.5, 1.0);
+            complex_tree.grow(std::slice::from_ref(&attractive), 0.5, 1.0);
         }
 
         // Komplexer Baum sollte höhere Komplexität haben
@@ -1351,8 +1346,8 @@ mod tests {
 
         // Wachstum für beide
         for _ in 0..5 {
-            let grew1 = tree1.grow(&[attractive.clone()], 0.5, 0.8);
-            let grew2 = tree2.grow(&[attractive.clone()], 0.5, 0.8);
+            let grew1 = tree1.grow(std::slice::from_ref(&attractive), 0.5, 0.8);
+            let grew2 = tree2.grow(std::slice::from_ref(&attractive), 0.5, 0.8);
 
             // Identisches Verhalten mit gleichem Seed
             assert_eq!(grew1, grew2);
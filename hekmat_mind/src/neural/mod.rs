@@ -13,12 +13,17 @@
 //! - Adaptive Schwellwerte durch Plastizität
 //! - Geschwindigkeits- und kapazitätsbasierte Informationsverarbeitung
 //!
+//! Alle Neuronenmodelle implementieren das [`NeuronModel`]-Trait. Das Netzwerk
+//! arbeitet ausschließlich über dieses Trait, sodass alternative Zellmodelle
+//! neben dem Standard-[`Neuron`] betrieben werden können, etwa das
+//! Leaky-Integrate-and-Fire-Modell [`neuron::LifNeuron`].
+//!
 //! ### Synapsen
 //!
 //! Die [`Synapse`]-Struktur modelliert die Verbindung zwischen Neuronen:
 //!
 //! - Gewichtete Signalübertragung zwischen Neuronen
-//! - Bidirektionale Plastizität (Hebbsches Lernen)
+//! - Bidirektionale Plastizität (Hebbsches Lernen, Spike-Timing-abhängige Plastizität)
 //! - Realistische Signalverzögerung
 //!
 //! ### Neuronale Netzwerke
@@ -26,7 +31,20 @@
 //! Das [`Network`]-Modul verbindet Neuronen und Synapsen zu funktionalen Einheiten:
 //!
 //! - Signalpropagation zwischen verbundenen Neuronen
-//! - Hebbsche Plastizität auf Netzwerkebene
+//! - Zustellung von Spikes nach der synaptischen Verzögerung
+//! - Austauschbare Lernregeln (Hebb, Oja, BCM, Kovarianz, STDP) netzwerkweit oder je Projektion
+//! - Sequentielle oder parallele Zyklen mit bitgenau gleichen Ergebnissen
+//! - Ereignisgesteuerte Simulation spärlich aktiver LIF-Netzwerke
+//! - Versionierte Snapshots (binär oder JSON) zum Fortsetzen langer Trainingsläufe
+//! - Benannte Populationen und Projektionen mit eigenen Verbindungsregeln
+//! - Standardtopologien (Ring, Kleine Welt, skalenfrei, Gitter, Schichten)
+//! - Räumliche Anordnung mit abstandsabhängigen Verbindungen und Verzögerungen
+//! - Export nach GraphML und Graphviz-DOT sowie Import aus GraphML
+//! - Kodierung reellwertiger Eingaben als Raten-, Poisson-, Latenz- oder Populationscode
+//! - Dekodierung von Ausgangsneuronen und lineare Auslese (kleinste Quadrate, Ridge)
+//! - Reservoir Computing mit Spektralradius-Skalierung und Benchmarkaufgaben (NARMA)
+//! - Graphanalyse (Gradverteilungen, Clusterkoeffizient, Pfadlänge, Motive, Small-World-Index)
+//! - Monitore für Spikes, Zustandsgrößen und Populationsraten mit CSV-/JSON-Export
 //! - Aufbau komplexer neuronaler Strukturen
 //!
 //! ### Geplante Komponenten
@@ -63,8 +81,21 @@ pub mod network;
 pub mod neuron;
 pub mod synapse;
 
+pub use network::analysis::{GraphAnalysis, Motif, Weighting};
+pub use network::decoding::{Decoder, Decoding, LinearReadout};
+pub use network::encoding::{Encoder, Encoding, Stimulus, StimulusSchedule};
+pub use network::event_driven::SpikeEvent;
+pub use network::execution::ExecutionMode;
+pub use network::graph_io::GraphFilter;
 pub use network::model::Network;
 pub use network::model::NetworkBuilder;
+pub use network::monitor::{Monitor, MonitorId, RateMonitor, SpikeRecorder, StateProbe};
+pub use network::persistence::SnapshotFormat;
+pub use network::population::{ConnectionRule, Population, Projection};
+pub use network::reservoir::{BenchmarkTask, Reservoir, ReservoirBuilder};
+pub use network::spatial::{DistanceKernel, Placement};
+pub use network::topology::Topology;
 pub use neuron::model::Neuron;
 pub use neuron::model::NeuronState;
+pub use neuron::traits::NeuronModel;
 pub use synapse::model::Synapse;
@@ -0,0 +1,243 @@
+//! Synapsenspeicher mit Adjazenzindex
+//!
+//! Die Synapsen eines Netzwerks sind nach `(präsynaptisch, postsynaptisch)` indiziert.
+//! Zusätzlich führt der Speicher für jedes Neuron die Listen seiner ausgehenden und
+//! eingehenden Verbindungen, sodass ein Spike nur die Synapsen seines Neurons
+//! berührt statt alle Synapsen des Netzwerks.
+//!
+//! Serialisiert wird nur die Liste der Synapsen, nach Schlüssel sortiert; der Index
+//! wird beim Laden neu aufgebaut. Ältere Daten, in denen die Synapsen als Tabelle
+//! mit Tupelschlüsseln vorliegen, werden weiterhin gelesen.
+
+use crate::neural::network::execution::Executor;
+use crate::neural::synapse::model::Synapse;
+use serde::de::{MapAccess, SeqAccess, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::collections::HashMap;
+use std::fmt;
+use uuid::Uuid;
+
+/// Schlüssel einer Synapse: (präsynaptische Neuron-ID, postsynaptische Neuron-ID)
+pub type SynapseKey = (Uuid, Uuid);
+
+/// Synapsentabelle mit ausgehenden und eingehenden Adjazenzlisten je Neuron
+#[derive(Debug, Clone, Default)]
+pub struct SynapseMap {
+    /// Synapsen, indiziert nach ihrem Schlüssel
+    synapses: HashMap<SynapseKey, Synapse>,
+    /// Postsynaptische Neuronen je präsynaptischem Neuron, in Einfügereihenfolge
+    outgoing: HashMap<Uuid, Vec<Uuid>>,
+    /// Präsynaptische Neuronen je postsynaptischem Neuron, in Einfügereihenfolge
+    incoming: HashMap<Uuid, Vec<Uuid>>,
+}
+
+impl SynapseMap {
+    /// Erstellt einen leeren Speicher
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Fügt eine Synapse ein und gibt eine ersetzte Synapse zurück
+    pub fn insert(&mut self, synapse: Synapse) -> Option<Synapse> {
+        let key = (*synapse.pre_neuron_id(), *synapse.post_neuron_id());
+        let replaced = self.synapses.insert(key, synapse);
+        if replaced.is_none() {
+            self.outgoing.entry(key.0).or_default().push(key.1);
+            self.incoming.entry(key.1).or_default().push(key.0);
+        }
+        replaced
+    }
+
+    /// Entfernt eine Synapse samt ihrer Einträge in den Adjazenzlisten
+    pub fn remove(&mut self, key: &SynapseKey) -> Option<Synapse> {
+        let removed = self.synapses.remove(key)?;
+        remove_from_list(&mut self.outgoing, &key.0, &key.1);
+        remove_from_list(&mut self.incoming, &key.1, &key.0);
+        Some(removed)
+    }
+
+    /// Entfernt alle ein- und ausgehenden Synapsen eines Neurons
+    pub fn remove_neuron(&mut self, neuron_id: &Uuid) -> Vec<Synapse> {
+        let outgoing = self
+            .targets(neuron_id)
+            .iter()
+            .map(|post_id| (*neuron_id, *post_id));
+        let incoming = self
+            .sources(neuron_id)
+            .iter()
+            .map(|pre_id| (*pre_id, *neuron_id));
+        let keys: Vec<SynapseKey> = outgoing.chain(incoming).collect();
+        keys.iter().filter_map(|key| self.remove(key)).collect()
+    }
+
+    /// Entfernt alle Synapsen, für die `keep` `false` liefert
+    pub fn retain<F>(&mut self, mut keep: F) -> Vec<Synapse>
+    where
+        F: FnMut(&Synapse) -> bool,
+    {
+        let mut keys: Vec<SynapseKey> = self
+            .synapses
+            .iter()
+            .filter(|(_, synapse)| !keep(synapse))
+            .map(|(key, _)| *key)
+            .collect();
+        keys.sort_unstable();
+        keys.iter().filter_map(|key| self.remove(key)).collect()
+    }
+
+    /// Gibt die Synapse zu einem Schlüssel zurück
+    pub fn get(&self, key: &SynapseKey) -> Option<&Synapse> {
+        self.synapses.get(key)
+    }
+
+    /// Gibt die Synapse zu einem Schlüssel veränderbar zurück
+    pub fn get_mut(&mut self, key: &SynapseKey) -> Option<&mut Synapse> {
+        self.synapses.get_mut(key)
+    }
+
+    /// Prüft, ob eine Synapse mit dem Schlüssel existiert
+    pub fn contains_key(&self, key: &SynapseKey) -> bool {
+        self.synapses.contains_key(key)
+    }
+
+    /// Gibt die Anzahl der Synapsen zurück
+    pub fn len(&self) -> usize {
+        self.synapses.len()
+    }
+
+    /// Gibt an, ob der Speicher leer ist
+    pub fn is_empty(&self) -> bool {
+        self.synapses.is_empty()
+    }
+
+    /// Gibt die Synapsentabelle zurück
+    pub fn as_map(&self) -> &HashMap<SynapseKey, Synapse> {
+        &self.synapses
+    }
+
+    /// Iteriert über alle Synapsen
+    pub fn values(&self) -> impl Iterator<Item = &Synapse> {
+        self.synapses.values()
+    }
+
+    /// Iteriert veränderbar über alle Synapsen
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut Synapse> {
+        self.synapses.values_mut()
+    }
+
+    /// Iteriert veränderbar über alle Synapsen samt Schlüssel
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (&SynapseKey, &mut Synapse)> {
+        self.synapses.iter_mut()
+    }
+
+    /// Ruft `f` für jede Synapse auf, im parallelen Modus auf Worker-Threads verteilt
+    pub fn for_each_mut<F>(&mut self, executor: &Executor, f: F)
+    where
+        F: Fn(&SynapseKey, &mut Synapse) + Sync + Send,
+    {
+        executor.for_each_mut(&mut self.synapses, f);
+    }
+
+    /// Gibt die Zielneuronen der ausgehenden Synapsen eines Neurons zurück
+    pub fn targets(&self, pre_id: &Uuid) -> &[Uuid] {
+        self.outgoing.get(pre_id).map_or(&[], Vec::as_slice)
+    }
+
+    /// Gibt die Quellneuronen der eingehenden Synapsen eines Neurons zurück
+    pub fn sources(&self, post_id: &Uuid) -> &[Uuid] {
+        self.incoming.get(post_id).map_or(&[], Vec::as_slice)
+    }
+
+    /// Iteriert über die ausgehenden Synapsen eines Neurons
+    pub fn outgoing(&self, pre_id: &Uuid) -> impl Iterator<Item = &Synapse> {
+        let pre_id = *pre_id;
+        self.targets(&pre_id)
+            .iter()
+            .filter_map(move |post_id| self.synapses.get(&(pre_id, *post_id)))
+    }
+
+    /// Iteriert über die eingehenden Synapsen eines Neurons
+    pub fn incoming(&self, post_id: &Uuid) -> impl Iterator<Item = &Synapse> {
+        let post_id = *post_id;
+        self.sources(&post_id)
+            .iter()
+            .filter_map(move |pre_id| self.synapses.get(&(*pre_id, post_id)))
+    }
+
+    /// Ruft `f` für jede ausgehende Synapse eines Neurons auf
+    pub fn for_each_outgoing_mut<F>(&mut self, pre_id: &Uuid, mut f: F)
+    where
+        F: FnMut(&mut Synapse),
+    {
+        let Some(targets) = self.outgoing.get(pre_id) else {
+            return;
+        };
+        for post_id in targets {
+            if let Some(synapse) = self.synapses.get_mut(&(*pre_id, *post_id)) {
+                f(synapse);
+            }
+        }
+    }
+}
+
+/// Entfernt `value` aus der Adjazenzliste von `id` und verwirft leere Listen
+fn remove_from_list(lists: &mut HashMap<Uuid, Vec<Uuid>>, id: &Uuid, value: &Uuid) {
+    if let Some(list) = lists.get_mut(id) {
+        list.retain(|entry| entry != value);
+        if list.is_empty() {
+            lists.remove(id);
+        }
+    }
+}
+
+impl FromIterator<Synapse> for SynapseMap {
+    fn from_iter<I: IntoIterator<Item = Synapse>>(iter: I) -> Self {
+        let mut map = Self::new();
+        for synapse in iter {
+            map.insert(synapse);
+        }
+        map
+    }
+}
+
+impl Serialize for SynapseMap {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        // Tupelschlüssel sind in JSON nicht darstellbar, die Synapse enthält sie ohnehin
+        let mut synapses: Vec<(&SynapseKey, &Synapse)> = self.synapses.iter().collect();
+        synapses.sort_unstable_by_key(|(key, _)| **key);
+        serializer.collect_seq(synapses.into_iter().map(|(_, synapse)| synapse))
+    }
+}
+
+impl<'de> Deserialize<'de> for SynapseMap {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(SynapseMapVisitor)
+    }
+}
+
+/// Liest Synapsen als Liste oder im älteren Format als Tabelle
+struct SynapseMapVisitor;
+
+impl<'de> Visitor<'de> for SynapseMapVisitor {
+    type Value = SynapseMap;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("eine Liste oder Tabelle von Synapsen")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut map = SynapseMap::new();
+        while let Some(synapse) = seq.next_element::<Synapse>()? {
+            map.insert(synapse);
+        }
+        Ok(map)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut entries: A) -> Result<Self::Value, A::Error> {
+        let mut map = SynapseMap::new();
+        while let Some((_, synapse)) = entries.next_entry::<SynapseKey, Synapse>()? {
+            map.insert(synapse);
+        }
+        Ok(map)
+    }
+}
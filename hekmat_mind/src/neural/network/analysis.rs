@@ -0,0 +1,621 @@
+//! Graphentheoretische Analyse der Netzwerktopologie
+//!
+//! Eine [`GraphAnalysis`] hält eine Momentaufnahme der Verbindungen eines Netzwerks
+//! und berechnet daraus gängige Kennzahlen: Grad- und Stärkeverteilungen,
+//! Clusterkoeffizient, charakteristische Pfadlänge, stark zusammenhängende
+//! Komponenten, Rich-Club-Koeffizient, Zählung der 3-Knoten-Motive und
+//! Small-World-Index.
+//!
+//! Synapsen werden als gerichtete Kanten mit dem Betrag ihres Gewichts behandelt.
+//! Clusterkoeffizient, Rich-Club-Koeffizient und Small-World-Index beziehen sich auf
+//! den ungerichteten Graphen, in dem zwei Neuronen verbunden sind, wenn eine Synapse
+//! in mindestens einer Richtung existiert; das Gewicht einer ungerichteten Kante ist
+//! die Summe der Beträge beider Richtungen. Mit [`Weighting::Weighted`] fließen die
+//! Gewichte in die Kennzahlen ein, sonst zählt nur die Existenz einer Kante.
+
+use crate::neural::network::model::Network;
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap, VecDeque};
+use uuid::Uuid;
+
+/// Berücksichtigung der Synapsengewichte
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Weighting {
+    /// Nur die Existenz einer Verbindung zählt
+    #[default]
+    Unweighted,
+    /// Beträge der Gewichte gehen in die Kennzahl ein
+    Weighted,
+}
+
+/// Zusammenhängende Motive aus drei Neuronen (Triaden nach Holland und Leinhardt)
+///
+/// Die Bezeichnung in Klammern ist der MAN-Code: Anzahl wechselseitiger,
+/// einseitiger und fehlender Verbindungen der drei Paare.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Motif {
+    /// Ein Neuron sendet an beide anderen (021D)
+    OutStar,
+    /// Ein Neuron empfängt von beiden anderen (021U)
+    InStar,
+    /// Kette A → B → C (021C)
+    Chain,
+    /// Wechselseitiges Paar, das eine einseitige Verbindung empfängt (111D)
+    MutualWithIncoming,
+    /// Wechselseitiges Paar, das eine einseitige Verbindung aussendet (111U)
+    MutualWithOutgoing,
+    /// Vorwärtsgerichtete Schleife A → B → C und A → C (030T)
+    FeedForwardLoop,
+    /// Zyklus A → B → C → A (030C)
+    Cycle,
+    /// Zwei wechselseitige Paare mit gemeinsamem Neuron (201)
+    MutualChain,
+    /// Ein Neuron sendet an beide Neuronen eines wechselseitigen Paars (120D)
+    CommonSource,
+    /// Beide Neuronen eines wechselseitigen Paars senden an ein drittes (120U)
+    CommonTarget,
+    /// Wechselseitiges Paar, durch eine Kette über das dritte Neuron verbunden (120C)
+    MutualWithChain,
+    /// Zwei wechselseitige Paare und eine einseitige Verbindung (210)
+    AlmostComplete,
+    /// Alle Paare wechselseitig verbunden (300)
+    Complete,
+}
+
+impl Motif {
+    /// Alle Motive in der Reihenfolge ihrer Definition
+    pub const ALL: [Motif; 13] = [
+        Motif::OutStar,
+        Motif::InStar,
+        Motif::Chain,
+        Motif::MutualWithIncoming,
+        Motif::MutualWithOutgoing,
+        Motif::FeedForwardLoop,
+        Motif::Cycle,
+        Motif::MutualChain,
+        Motif::CommonSource,
+        Motif::CommonTarget,
+        Motif::MutualWithChain,
+        Motif::AlmostComplete,
+        Motif::Complete,
+    ];
+
+    /// Gibt den MAN-Code der Triade zurück (z.B. "030T")
+    pub fn code(&self) -> &'static str {
+        match self {
+            Motif::OutStar => "021D",
+            Motif::InStar => "021U",
+            Motif::Chain => "021C",
+            Motif::MutualWithIncoming => "111D",
+            Motif::MutualWithOutgoing => "111U",
+            Motif::FeedForwardLoop => "030T",
+            Motif::Cycle => "030C",
+            Motif::MutualChain => "201",
+            Motif::CommonSource => "120D",
+            Motif::CommonTarget => "120U",
+            Motif::MutualWithChain => "120C",
+            Motif::AlmostComplete => "210",
+            Motif::Complete => "300",
+        }
+    }
+
+    /// Bestimmt das Motiv aus der Adjazenz dreier Neuronen (`edges[i][j]`: i → j)
+    ///
+    /// Gibt `None` zurück, wenn die drei Neuronen nicht zusammenhängen.
+    fn classify(edges: [[bool; 3]; 3]) -> Option<Motif> {
+        let out_degree = |i: usize| (0..3).filter(|&j| edges[i][j]).count();
+        let in_degree = |i: usize| (0..3).filter(|&j| edges[j][i]).count();
+        let pairs = [(0, 1), (0, 2), (1, 2)];
+        let mutual: Vec<(usize, usize)> = pairs
+            .iter()
+            .copied()
+            .filter(|&(i, j)| edges[i][j] && edges[j][i])
+            .collect();
+        let asymmetric: Vec<(usize, usize)> = pairs
+            .iter()
+            .filter_map(|&(i, j)| match (edges[i][j], edges[j][i]) {
+                (true, false) => Some((i, j)),
+                (false, true) => Some((j, i)),
+                _ => None,
+            })
+            .collect();
+        // Das Neuron außerhalb eines Paars
+        let third = |(i, j): (usize, usize)| 3 - i - j;
+
+        let motif = match (mutual.len(), asymmetric.len()) {
+            (0, 2) => {
+                if (0..3).any(|i| out_degree(i) == 2) {
+                    Motif::OutStar
+                } else if (0..3).any(|i| in_degree(i) == 2) {
+                    Motif::InStar
+                } else {
+                    Motif::Chain
+                }
+            }
+            (1, 1) => {
+                // Einseitige Verbindung vom Paar weg oder zum Paar hin
+                if third(mutual[0]) == asymmetric[0].1 {
+                    Motif::MutualWithOutgoing
+                } else {
+                    Motif::MutualWithIncoming
+                }
+            }
+            (0, 3) => {
+                if (0..3).any(|i| out_degree(i) == 2) {
+                    Motif::FeedForwardLoop
+                } else {
+                    Motif::Cycle
+                }
+            }
+            (2, 0) => Motif::MutualChain,
+            (1, 2) => {
+                let outsider = third(mutual[0]);
+                if out_degree(outsider) == 2 {
+                    Motif::CommonSource
+                } else if in_degree(outsider) == 2 {
+                    Motif::CommonTarget
+                } else {
+                    Motif::MutualWithChain
+                }
+            }
+            (2, 1) => Motif::AlmostComplete,
+            (3, 0) => Motif::Complete,
+            _ => return None,
+        };
+        Some(motif)
+    }
+}
+
+/// Distanz für die Prioritätswarteschlange der gewichteten kürzesten Wege
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Distance(f64);
+
+impl Eq for Distance {}
+
+impl PartialOrd for Distance {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Distance {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+/// Momentaufnahme der Verbindungen eines Netzwerks für die Graphanalyse
+#[derive(Debug, Clone)]
+pub struct GraphAnalysis {
+    /// Neuronen in aufsteigender Reihenfolge ihrer IDs
+    ids: Vec<Uuid>,
+    /// Ausgehende Kanten je Neuron (Ziel, Betrag des Gewichts), nach Ziel sortiert
+    outgoing: Vec<Vec<(usize, f64)>>,
+    /// Eingehende Kanten je Neuron (Quelle, Betrag des Gewichts)
+    incoming: Vec<Vec<(usize, f64)>>,
+    /// Ungerichtete Nachbarn je Neuron mit summiertem Gewicht, ohne Selbstverbindungen
+    neighbors: Vec<BTreeMap<usize, f64>>,
+}
+
+impl GraphAnalysis {
+    /// Erstellt eine Momentaufnahme der Neuronen und Synapsen eines Netzwerks
+    pub fn new(network: &Network) -> Self {
+        let mut ids: Vec<Uuid> = network.neurons().keys().copied().collect();
+        ids.sort();
+        let index: HashMap<Uuid, usize> = ids
+            .iter()
+            .enumerate()
+            .map(|(position, id)| (*id, position))
+            .collect();
+
+        let mut outgoing = vec![Vec::new(); ids.len()];
+        let mut incoming = vec![Vec::new(); ids.len()];
+        let mut neighbors = vec![BTreeMap::new(); ids.len()];
+        for synapse in network.synapses().values() {
+            let (Some(&pre), Some(&post)) = (
+                index.get(synapse.pre_neuron_id()),
+                index.get(synapse.post_neuron_id()),
+            ) else {
+                continue;
+            };
+            let weight = synapse.weight().abs() as f64;
+            outgoing[pre].push((post, weight));
+            incoming[post].push((pre, weight));
+            if pre != post {
+                *neighbors[pre].entry(post).or_insert(0.0) += weight;
+                *neighbors[post].entry(pre).or_insert(0.0) += weight;
+            }
+        }
+        for edges in outgoing.iter_mut().chain(incoming.iter_mut()) {
+            edges.sort_by_key(|(node, _)| *node);
+        }
+
+        Self {
+            ids,
+            outgoing,
+            incoming,
+            neighbors,
+        }
+    }
+
+    /// Gibt die Anzahl der Neuronen zurück
+    pub fn node_count(&self) -> usize {
+        self.ids.len()
+    }
+
+    /// Gibt die Anzahl der gerichteten Kanten (Synapsen) zurück
+    pub fn edge_count(&self) -> usize {
+        self.outgoing.iter().map(Vec::len).sum()
+    }
+
+    /// Gibt den Eingangsgrad jedes Neurons zurück
+    pub fn in_degrees(&self) -> HashMap<Uuid, usize> {
+        self.per_node(|node| self.incoming[node].len())
+    }
+
+    /// Gibt den Ausgangsgrad jedes Neurons zurück
+    pub fn out_degrees(&self) -> HashMap<Uuid, usize> {
+        self.per_node(|node| self.outgoing[node].len())
+    }
+
+    /// Gibt die Summe der eingehenden Gewichtsbeträge jedes Neurons zurück
+    pub fn in_strengths(&self) -> HashMap<Uuid, f64> {
+        self.per_node(|node| self.incoming[node].iter().map(|(_, w)| w).sum())
+    }
+
+    /// Gibt die Summe der ausgehenden Gewichtsbeträge jedes Neurons zurück
+    pub fn out_strengths(&self) -> HashMap<Uuid, f64> {
+        self.per_node(|node| self.outgoing[node].iter().map(|(_, w)| w).sum())
+    }
+
+    /// Verteilung der Eingangsgrade: Grad → Anzahl der Neuronen
+    pub fn in_degree_distribution(&self) -> BTreeMap<usize, usize> {
+        distribution(self.incoming.iter().map(Vec::len))
+    }
+
+    /// Verteilung der Ausgangsgrade: Grad → Anzahl der Neuronen
+    pub fn out_degree_distribution(&self) -> BTreeMap<usize, usize> {
+        distribution(self.outgoing.iter().map(Vec::len))
+    }
+
+    /// Lokaler Clusterkoeffizient eines Neurons im ungerichteten Graphen
+    ///
+    /// Anteil der verbundenen Paare unter den Nachbarn; gewichtet nach Onnela als
+    /// geometrisches Mittel der auf das größte Gewicht normierten Dreieckskanten.
+    /// Neuronen mit weniger als zwei Nachbarn haben den Koeffizienten 0.0.
+    pub fn local_clustering(&self, neuron_id: &Uuid, weighting: Weighting) -> Option<f64> {
+        let node = self.ids.binary_search(neuron_id).ok()?;
+        Some(self.clustering_of(node, weighting, self.max_undirected_weight()))
+    }
+
+    /// Mittlerer lokaler Clusterkoeffizient über alle Neuronen
+    pub fn clustering_coefficient(&self, weighting: Weighting) -> f64 {
+        if self.ids.is_empty() {
+            return 0.0;
+        }
+        let max_weight = self.max_undirected_weight();
+        (0..self.ids.len())
+            .map(|node| self.clustering_of(node, weighting, max_weight))
+            .sum::<f64>()
+            / self.ids.len() as f64
+    }
+
+    /// Mittlere Länge der kürzesten gerichteten Wege
+    ///
+    /// Gemittelt über alle geordneten Paare verschiedener Neuronen, zwischen denen ein
+    /// Weg existiert; `None`, wenn es keinen solchen Weg gibt. Gewichtet ist die Länge
+    /// einer Kante der Kehrwert ihres Gewichtsbetrags, starke Synapsen sind also kurz.
+    pub fn characteristic_path_length(&self, weighting: Weighting) -> Option<f64> {
+        let adjacency: Vec<Vec<(usize, f64)>> = self
+            .outgoing
+            .iter()
+            .map(|edges| edges.iter().map(|(node, w)| (*node, *w)).collect())
+            .collect();
+        mean_path_length(&adjacency, weighting)
+    }
+
+    /// Stark zusammenhängende Komponenten, die größte zuerst
+    ///
+    /// Innerhalb einer Komponente ist jedes Neuron von jedem anderen über gerichtete
+    /// Wege erreichbar. Die Neuronen einer Komponente sind nach ID sortiert.
+    pub fn strongly_connected_components(&self) -> Vec<Vec<Uuid>> {
+        let count = self.ids.len();
+        let mut index = vec![usize::MAX; count];
+        let mut lowlink = vec![0; count];
+        let mut on_stack = vec![false; count];
+        let mut stack = Vec::new();
+        let mut next_index = 0;
+        let mut components = Vec::new();
+
+        // Tarjan-Algorithmus mit explizitem Aufrufstapel (Knoten, nächste Kante)
+        for root in 0..count {
+            if index[root] != usize::MAX {
+                continue;
+            }
+            let mut calls = vec![(root, 0)];
+            index[root] = next_index;
+            lowlink[root] = next_index;
+            next_index += 1;
+            stack.push(root);
+            on_stack[root] = true;
+
+            while let Some(&mut (node, ref mut edge)) = calls.last_mut() {
+                if let Some(&(successor, _)) = self.outgoing[node].get(*edge) {
+                    *edge += 1;
+                    if index[successor] == usize::MAX {
+                        index[successor] = next_index;
+                        lowlink[successor] = next_index;
+                        next_index += 1;
+                        stack.push(successor);
+                        on_stack[successor] = true;
+                        calls.push((successor, 0));
+                    } else if on_stack[successor] {
+                        lowlink[node] = lowlink[node].min(index[successor]);
+                    }
+                    continue;
+                }
+
+                calls.pop();
+                if let Some(&(parent, _)) = calls.last() {
+                    lowlink[parent] = lowlink[parent].min(lowlink[node]);
+                }
+                if lowlink[node] == index[node] {
+                    let mut component = Vec::new();
+                    while let Some(member) = stack.pop() {
+                        on_stack[member] = false;
+                        component.push(self.ids[member]);
+                        if member == node {
+                            break;
+                        }
+                    }
+                    component.sort();
+                    components.push(component);
+                }
+            }
+        }
+
+        components.sort_by(|a, b| b.len().cmp(&a.len()).then_with(|| a.cmp(b)));
+        components
+    }
+
+    /// Rich-Club-Koeffizient für Neuronen mit einem ungerichteten Grad über `degree`
+    ///
+    /// Ungewichtet der Anteil der möglichen Verbindungen, die unter diesen Neuronen
+    /// bestehen. Gewichtet nach Opsahl et al.: das Gesamtgewicht dieser Verbindungen
+    /// geteilt durch die Summe ebenso vieler der stärksten Verbindungen im ganzen
+    /// Netzwerk. `None`, wenn weniger als zwei Neuronen den Grad übersteigen.
+    pub fn rich_club_coefficient(&self, degree: usize, weighting: Weighting) -> Option<f64> {
+        let rich: Vec<usize> = (0..self.ids.len())
+            .filter(|&node| self.neighbors[node].len() > degree)
+            .collect();
+        if rich.len() < 2 {
+            return None;
+        }
+        let mut edges = 0;
+        let mut weight = 0.0;
+        for &node in &rich {
+            for (&neighbor, &w) in self.neighbors[node].range(node + 1..) {
+                if self.neighbors[neighbor].len() > degree {
+                    edges += 1;
+                    weight += w;
+                }
+            }
+        }
+
+        match weighting {
+            Weighting::Unweighted => {
+                Some(2.0 * edges as f64 / (rich.len() * (rich.len() - 1)) as f64)
+            }
+            Weighting::Weighted => {
+                let mut ranked = self.undirected_weights();
+                ranked.sort_by(|a, b| b.total_cmp(a));
+                let strongest: f64 = ranked.iter().take(edges).sum();
+                Some(if strongest > 0.0 {
+                    weight / strongest
+                } else {
+                    0.0
+                })
+            }
+        }
+    }
+
+    /// Zählt die zusammenhängenden 3-Knoten-Motive
+    ///
+    /// Jede Gruppe aus drei Neuronen, die im ungerichteten Graphen zusammenhängt,
+    /// wird genau einmal ihrem [`Motif`] zugeordnet. Das Ergebnis enthält alle Motive,
+    /// auch solche, die nicht vorkommen.
+    pub fn motif_counts(&self) -> BTreeMap<Motif, usize> {
+        let mut counts: BTreeMap<Motif, usize> =
+            Motif::ALL.iter().map(|motif| (*motif, 0)).collect();
+        for u in 0..self.ids.len() {
+            for &v in self.neighbors[u].keys().filter(|&&v| v > u) {
+                let candidates: BTreeSet<usize> = self.neighbors[u]
+                    .keys()
+                    .chain(self.neighbors[v].keys())
+                    .copied()
+                    .filter(|&w| w != u && w != v)
+                    .collect();
+                for w in candidates {
+                    // Jede Triade nur von einer ihrer Kanten aus zählen
+                    let counted_here = w > v || (w > u && !self.neighbors[u].contains_key(&w));
+                    if !counted_here {
+                        continue;
+                    }
+                    let nodes = [u, v, w];
+                    let mut edges = [[false; 3]; 3];
+                    for i in 0..3 {
+                        for j in 0..3 {
+                            edges[i][j] = i != j && self.has_edge(nodes[i], nodes[j]);
+                        }
+                    }
+                    if let Some(motif) = Motif::classify(edges) {
+                        *counts.entry(motif).or_insert(0) += 1;
+                    }
+                }
+            }
+        }
+        counts
+    }
+
+    /// Small-World-Index σ = (C / C_zufall) / (L / L_zufall)
+    ///
+    /// Clusterkoeffizient C und Pfadlänge L des ungerichteten Graphen werden mit den
+    /// Erwartungswerten eines Zufallsgraphen gleicher Größe und Dichte verglichen
+    /// (C_zufall = k / (n − 1), L_zufall = ln n / ln k mit mittlerem Grad k).
+    /// Werte deutlich über 1 kennzeichnen ein Small-World-Netzwerk. `None`, wenn der
+    /// mittlere Grad höchstens 1 beträgt oder der Graph keine Dreiecke oder Wege hat.
+    pub fn small_worldness(&self) -> Option<f64> {
+        let n = self.ids.len() as f64;
+        let mean_degree =
+            self.neighbors.iter().map(BTreeMap::len).sum::<usize>() as f64 / n.max(1.0);
+        if n < 3.0 || mean_degree <= 1.0 {
+            return None;
+        }
+        let clustering = self.clustering_coefficient(Weighting::Unweighted);
+        let adjacency: Vec<Vec<(usize, f64)>> = self
+            .neighbors
+            .iter()
+            .map(|neighbors| neighbors.iter().map(|(node, w)| (*node, *w)).collect())
+            .collect();
+        let path_length = mean_path_length(&adjacency, Weighting::Unweighted)?;
+        if clustering == 0.0 || path_length == 0.0 {
+            return None;
+        }
+        let random_clustering = mean_degree / (n - 1.0);
+        let random_path_length = n.ln() / mean_degree.ln();
+        Some((clustering / random_clustering) / (path_length / random_path_length))
+    }
+
+    /// Wendet eine Funktion auf jedes Neuron an und ordnet das Ergebnis seiner ID zu
+    fn per_node<T>(&self, value: impl Fn(usize) -> T) -> HashMap<Uuid, T> {
+        self.ids
+            .iter()
+            .enumerate()
+            .map(|(node, id)| (*id, value(node)))
+            .collect()
+    }
+
+    /// Prüft, ob eine gerichtete Kante existiert
+    fn has_edge(&self, pre: usize, post: usize) -> bool {
+        self.outgoing[pre]
+            .binary_search_by_key(&post, |(node, _)| *node)
+            .is_ok()
+    }
+
+    /// Gewichte aller ungerichteten Kanten
+    fn undirected_weights(&self) -> Vec<f64> {
+        self.neighbors
+            .iter()
+            .enumerate()
+            .flat_map(|(node, neighbors)| neighbors.range(node + 1..).map(|(_, w)| *w))
+            .collect()
+    }
+
+    /// Größtes Gewicht einer ungerichteten Kante
+    fn max_undirected_weight(&self) -> f64 {
+        self.undirected_weights().into_iter().fold(0.0, f64::max)
+    }
+
+    /// Lokaler Clusterkoeffizient eines Knotens
+    fn clustering_of(&self, node: usize, weighting: Weighting, max_weight: f64) -> f64 {
+        let neighbors: Vec<(usize, f64)> =
+            self.neighbors[node].iter().map(|(n, w)| (*n, *w)).collect();
+        let degree = neighbors.len();
+        if degree < 2 {
+            return 0.0;
+        }
+        let mut triangles = 0.0;
+        for (position, &(first, first_weight)) in neighbors.iter().enumerate() {
+            for &(second, second_weight) in &neighbors[position + 1..] {
+                let Some(&closing_weight) = self.neighbors[first].get(&second) else {
+                    continue;
+                };
+                triangles += match weighting {
+                    Weighting::Unweighted => 1.0,
+                    Weighting::Weighted if max_weight > 0.0 => {
+                        (first_weight * second_weight * closing_weight / max_weight.powi(3)).cbrt()
+                    }
+                    Weighting::Weighted => 0.0,
+                };
+            }
+        }
+        2.0 * triangles / (degree * (degree - 1)) as f64
+    }
+}
+
+impl Network {
+    /// Erstellt eine Graphanalyse der aktuellen Verbindungen
+    pub fn analysis(&self) -> GraphAnalysis {
+        GraphAnalysis::new(self)
+    }
+}
+
+/// Häufigkeit jedes Werts
+fn distribution(values: impl Iterator<Item = usize>) -> BTreeMap<usize, usize> {
+    let mut counts = BTreeMap::new();
+    for value in values {
+        *counts.entry(value).or_insert(0) += 1;
+    }
+    counts
+}
+
+/// Mittlere Länge der kürzesten Wege über alle erreichbaren geordneten Paare
+fn mean_path_length(adjacency: &[Vec<(usize, f64)>], weighting: Weighting) -> Option<f64> {
+    let mut total = 0.0;
+    let mut pairs = 0usize;
+    for source in 0..adjacency.len() {
+        let distances = match weighting {
+            Weighting::Unweighted => breadth_first(adjacency, source),
+            Weighting::Weighted => dijkstra(adjacency, source),
+        };
+        for (target, distance) in distances.iter().enumerate() {
+            if target != source
+                && let Some(distance) = distance
+            {
+                total += distance;
+                pairs += 1;
+            }
+        }
+    }
+    (pairs > 0).then(|| total / pairs as f64)
+}
+
+/// Anzahl der Kanten auf den kürzesten Wegen ab `source`
+fn breadth_first(adjacency: &[Vec<(usize, f64)>], source: usize) -> Vec<Option<f64>> {
+    let mut distances = vec![None; adjacency.len()];
+    distances[source] = Some(0.0);
+    let mut queue = VecDeque::from([source]);
+    while let Some(node) = queue.pop_front() {
+        let distance = distances[node].expect("Knoten wurde erreicht");
+        for &(next, _) in &adjacency[node] {
+            if distances[next].is_none() {
+                distances[next] = Some(distance + 1.0);
+                queue.push_back(next);
+            }
+        }
+    }
+    distances
+}
+
+/// Gewichtete kürzeste Wege ab `source` mit Kantenlänge 1 / Gewicht
+fn dijkstra(adjacency: &[Vec<(usize, f64)>], source: usize) -> Vec<Option<f64>> {
+    let mut distances: Vec<Option<f64>> = vec![None; adjacency.len()];
+    let mut heap = BinaryHeap::from([Reverse((Distance(0.0), source))]);
+    distances[source] = Some(0.0);
+    while let Some(Reverse((Distance(distance), node))) = heap.pop() {
+        if distances[node].is_some_and(|best| distance > best) {
+            continue;
+        }
+        for &(next, weight) in &adjacency[node] {
+            if weight <= 0.0 {
+                continue;
+            }
+            let candidate = distance + 1.0 / weight;
+            if distances[next].is_none_or(|best| candidate < best) {
+                distances[next] = Some(candidate);
+                heap.push(Reverse((Distance(candidate), next)));
+            }
+        }
+    }
+    distances
+}
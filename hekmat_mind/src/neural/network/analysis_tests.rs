@@ -0,0 +1,283 @@
+use crate::neural::network::analysis::{Motif, Weighting};
+use crate::neural::network::model::{Network, NetworkBuilder};
+use crate::neural::network::topology::Topology;
+use crate::neural::neuron::model::Neuron;
+use crate::neural::synapse::model::Synapse;
+use std::collections::BTreeMap;
+use uuid::Uuid;
+
+/// Netzwerk aus `count` Neuronen mit den angegebenen gewichteten Kanten
+fn graph(count: usize, edges: &[(usize, usize, f32)]) -> (Network, Vec<Uuid>) {
+    let mut network = Network::new();
+    let mut ids = Vec::new();
+    for _ in 0..count {
+        let neuron = Neuron::new(100);
+        ids.push(*neuron.id());
+        network.add_neuron(neuron);
+    }
+    for &(pre, post, weight) in edges {
+        network.add_synapse(Synapse::new(ids[pre], ids[post], weight));
+    }
+    (network, ids)
+}
+
+/// Kanten eines vollständigen Graphen mit Verbindungen in beide Richtungen
+fn complete(count: usize) -> Vec<(usize, usize, f32)> {
+    (0..count)
+        .flat_map(|pre| {
+            (0..count)
+                .filter(move |&post| post != pre)
+                .map(move |post| (pre, post, 0.5))
+        })
+        .collect()
+}
+
+#[test]
+fn test_degrees_and_strongly_connected_components() {
+    // Zyklus 0 → 1 → 2 → 0, davon abgehend das wechselseitige Paar 3 ↔ 4, 5 isoliert
+    let (network, ids) = graph(
+        6,
+        &[
+            (0, 1, 0.2),
+            (1, 2, 0.4),
+            (2, 0, 0.6),
+            (2, 3, 0.8),
+            (3, 4, 0.5),
+            (4, 3, 0.5),
+        ],
+    );
+    let analysis = network.analysis();
+    assert_eq!(analysis.node_count(), 6);
+    assert_eq!(analysis.edge_count(), 6);
+
+    assert_eq!(analysis.out_degrees()[&ids[2]], 2);
+    assert_eq!(analysis.in_degrees()[&ids[3]], 2);
+    assert!((analysis.out_strengths()[&ids[2]] - 1.4).abs() < 1e-6);
+    assert!((analysis.in_strengths()[&ids[0]] - 0.6).abs() < 1e-6);
+    assert_eq!(
+        analysis.out_degree_distribution(),
+        BTreeMap::from([(0, 1), (1, 4), (2, 1)])
+    );
+    assert_eq!(
+        analysis.in_degree_distribution(),
+        BTreeMap::from([(0, 1), (1, 4), (2, 1)])
+    );
+
+    let components = analysis.strongly_connected_components();
+    let mut cycle = vec![ids[0], ids[1], ids[2]];
+    cycle.sort();
+    let mut pair = vec![ids[3], ids[4]];
+    pair.sort();
+    assert_eq!(components, vec![cycle, pair, vec![ids[5]]]);
+
+    // Ein Schichtnetzwerk ohne Rückkopplung zerfällt in einzelne Neuronen
+    let layered = NetworkBuilder::new()
+        .with_topology(Topology::FeedForward { layers: vec![3, 4] }, 0.5)
+        .build();
+    let components = layered.analysis().strongly_connected_components();
+    assert_eq!(components.len(), 7);
+    assert!(components.iter().all(|component| component.len() == 1));
+}
+
+#[test]
+fn test_clustering_and_path_length_on_known_graphs() {
+    // Vollständiger Graph: alle Nachbarn verbunden, jeder Weg hat Länge 1
+    let (network, ids) = graph(5, &complete(5));
+    let analysis = network.analysis();
+    assert_eq!(analysis.clustering_coefficient(Weighting::Unweighted), 1.0);
+    assert!((analysis.clustering_coefficient(Weighting::Weighted) - 1.0).abs() < 1e-9);
+    assert_eq!(
+        analysis.characteristic_path_length(Weighting::Unweighted),
+        Some(1.0)
+    );
+    assert_eq!(
+        analysis.characteristic_path_length(Weighting::Weighted),
+        Some(2.0)
+    );
+    assert_eq!(
+        analysis.local_clustering(&ids[0], Weighting::Unweighted),
+        Some(1.0)
+    );
+    assert_eq!(
+        analysis.local_clustering(&Uuid::new_v4(), Weighting::Unweighted),
+        None
+    );
+
+    // Gerichteter Ring aus sechs Neuronen: keine Dreiecke, mittlere Distanz (1 + … + 5) / 5
+    let ring = NetworkBuilder::new()
+        .with_neurons(6, 100)
+        .with_topology(Topology::Ring { neighbors: 1 }, 0.5)
+        .build();
+    let analysis = ring.analysis();
+    assert_eq!(analysis.clustering_coefficient(Weighting::Unweighted), 0.0);
+    assert_eq!(
+        analysis.characteristic_path_length(Weighting::Unweighted),
+        Some(3.0)
+    );
+    let weighted = analysis
+        .characteristic_path_length(Weighting::Weighted)
+        .unwrap();
+    assert!((weighted - 6.0).abs() < 1e-6);
+
+    // Stern: das Zentrum hat keine verbundenen Nachbarn, Wege nur vom Zentrum aus
+    let (star, _) = graph(5, &[(0, 1, 0.5), (0, 2, 0.5), (0, 3, 0.5), (0, 4, 0.5)]);
+    let analysis = star.analysis();
+    assert_eq!(analysis.clustering_coefficient(Weighting::Unweighted), 0.0);
+    assert_eq!(
+        analysis.characteristic_path_length(Weighting::Unweighted),
+        Some(1.0)
+    );
+    assert_eq!(
+        graph(3, &[])
+            .0
+            .analysis()
+            .characteristic_path_length(Weighting::Unweighted),
+        None
+    );
+
+    // Gewichtetes Dreieck mit angehängtem Neuron: Onnela-Koeffizient (1 · 1 · 0.5)^(1/3)
+    let (triangle, ids) = graph(4, &[(0, 1, 1.0), (1, 2, 1.0), (2, 0, 0.5), (0, 3, 1.0)]);
+    let analysis = triangle.analysis();
+    let expected = 0.5f64.cbrt();
+    let weighted = analysis
+        .local_clustering(&ids[1], Weighting::Weighted)
+        .unwrap();
+    assert!((weighted - expected).abs() < 1e-6);
+    let weighted = analysis
+        .local_clustering(&ids[0], Weighting::Weighted)
+        .unwrap();
+    assert!((weighted - expected / 3.0).abs() < 1e-6);
+    assert_eq!(
+        analysis.local_clustering(&ids[0], Weighting::Unweighted),
+        Some(1.0 / 3.0)
+    );
+}
+
+#[test]
+fn test_motif_counts() {
+    let count = |edges: &[(usize, usize, f32)], motif: Motif| {
+        let counts = graph(3, edges).0.analysis().motif_counts();
+        assert_eq!(counts.values().sum::<usize>(), 1, "{:?}", motif);
+        counts[&motif]
+    };
+    assert_eq!(count(&[(0, 1, 0.5), (0, 2, 0.5)], Motif::OutStar), 1);
+    assert_eq!(count(&[(1, 0, 0.5), (2, 0, 0.5)], Motif::InStar), 1);
+    assert_eq!(count(&[(0, 1, 0.5), (1, 2, 0.5)], Motif::Chain), 1);
+    assert_eq!(
+        count(
+            &[(0, 1, 0.5), (1, 0, 0.5), (2, 0, 0.5)],
+            Motif::MutualWithIncoming
+        ),
+        1
+    );
+    assert_eq!(
+        count(
+            &[(0, 1, 0.5), (1, 0, 0.5), (0, 2, 0.5)],
+            Motif::MutualWithOutgoing
+        ),
+        1
+    );
+    assert_eq!(
+        count(
+            &[(0, 1, 0.5), (1, 2, 0.5), (0, 2, 0.5)],
+            Motif::FeedForwardLoop
+        ),
+        1
+    );
+    assert_eq!(
+        count(&[(0, 1, 0.5), (1, 2, 0.5), (2, 0, 0.5)], Motif::Cycle),
+        1
+    );
+    assert_eq!(
+        count(
+            &[(0, 1, 0.5), (1, 0, 0.5), (2, 0, 0.5), (2, 1, 0.5)],
+            Motif::CommonSource
+        ),
+        1
+    );
+    assert_eq!(
+        count(
+            &[(0, 1, 0.5), (1, 0, 0.5), (0, 2, 0.5), (1, 2, 0.5)],
+            Motif::CommonTarget
+        ),
+        1
+    );
+    assert_eq!(Motif::FeedForwardLoop.code(), "030T");
+    assert_eq!(Motif::ALL.len(), 13);
+
+    // Jedes Neuronentripel eines vollständigen Graphen ist eine vollständige Triade
+    let counts = graph(5, &complete(5)).0.analysis().motif_counts();
+    assert_eq!(counts[&Motif::Complete], 10);
+    assert_eq!(counts.values().sum::<usize>(), 10);
+
+    // Ein Zentrum mit fünf Zielen bildet C(5, 2) divergente Sterne
+    let hub: Vec<_> = (1..6).map(|post| (0, post, 0.5)).collect();
+    let counts = graph(6, &hub).0.analysis().motif_counts();
+    assert_eq!(counts[&Motif::OutStar], 10);
+    assert_eq!(counts.values().sum::<usize>(), 10);
+
+    // Ein Ring mit zwei Nachfolgern je Neuron enthält Ketten und vorwärtsgerichtete Schleifen
+    let ring = NetworkBuilder::new()
+        .with_neurons(8, 100)
+        .with_topology(Topology::Ring { neighbors: 2 }, 0.5)
+        .build();
+    let counts = ring.analysis().motif_counts();
+    assert_eq!(counts[&Motif::FeedForwardLoop], 8);
+    assert_eq!(counts[&Motif::Cycle], 0);
+}
+
+#[test]
+fn test_rich_club_and_small_worldness() {
+    // Vollständig verbundener Kern aus vier Neuronen, jedes mit zwei Blättern
+    let mut edges = complete(4);
+    for core in 0..4 {
+        for leaf in 0..2 {
+            edges.push((core, 4 + 2 * core + leaf, 0.1));
+        }
+    }
+    let (network, _) = graph(12, &edges);
+    let analysis = network.analysis();
+    assert_eq!(
+        analysis.rich_club_coefficient(1, Weighting::Unweighted),
+        Some(1.0)
+    );
+    let all = analysis
+        .rich_club_coefficient(0, Weighting::Unweighted)
+        .unwrap();
+    assert!((all - 14.0 / 66.0).abs() < 1e-9);
+    // Der Kern vereint die stärksten Verbindungen
+    let weighted = analysis
+        .rich_club_coefficient(1, Weighting::Weighted)
+        .unwrap();
+    assert!((weighted - 1.0).abs() < 1e-9);
+    assert_eq!(
+        analysis.rich_club_coefficient(5, Weighting::Unweighted),
+        None
+    );
+
+    // Wenig Umverdrahtung erhält die Cluster bei kurzen Wegen
+    let small_world = |rewiring| {
+        NetworkBuilder::new()
+            .with_neurons(100, 100)
+            .with_topology(
+                Topology::SmallWorld {
+                    neighbors: 6,
+                    rewiring,
+                },
+                0.5,
+            )
+            .with_seed(5)
+            .build()
+            .analysis()
+            .small_worldness()
+            .unwrap()
+    };
+    let sigma = small_world(0.1);
+    assert!(sigma > 2.0, "σ = {}", sigma);
+    assert!(small_world(1.0) < sigma / 2.0);
+
+    assert_eq!(
+        graph(4, &[(0, 1, 0.5)]).0.analysis().small_worldness(),
+        None
+    );
+}
@@ -0,0 +1,347 @@
+//! Dekodierung von Netzwerkaktivität und lineare Auslese
+//!
+//! Ein [`Decoder`] übersetzt die aufgezeichneten Spikes festgelegter Ausgangsneuronen
+//! in einem Zeitfenster in einen Wertevektor, etwa aus einem
+//! [`SpikeRecorder`](crate::neural::network::monitor::SpikeRecorder) oder dem
+//! Ergebnis von [`Network::run_event_driven`](crate::neural::network::model::Network::run_event_driven).
+//! Die [`Decoding`] bestimmt, wie aus Spikes Werte werden.
+//!
+//! Eine [`LinearReadout`] bildet solche Zustandsvektoren linear auf Zielwerte ab.
+//! Sie wird mit kleinsten Quadraten oder Ridge-Regression angepasst und kann als
+//! Klassifikator verwendet werden, der die Klasse mit der größten Ausgabe wählt.
+
+use crate::neural::network::event_driven::SpikeEvent;
+use crate::neural::network::{NetworkError, NetworkResult};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// Betrag, unterhalb dessen ein Pivotelement als null gilt
+const SINGULAR_TOLERANCE: f64 = 1e-12;
+
+/// Verfahren, mit dem Spikes eines Zeitfensters in Werte übersetzt werden
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Decoding {
+    /// Anzahl der Spikes je Neuron
+    SpikeCount,
+    /// Feuerrate je Neuron in Hz
+    FiringRate,
+    /// 1.0 für das Neuron mit dem frühesten Spike, 0.0 für alle anderen
+    ///
+    /// Bei gleichzeitigen Spikes gewinnt das zuerst angegebene Ausgangsneuron.
+    FirstSpike,
+    /// Exponentiell gefilterte Spikefolge am Fensterende: `Σ exp(−(end − t) / tau)`
+    ExponentialTrace {
+        /// Zeitkonstante des Filters in Sekunden
+        tau: f64,
+    },
+}
+
+/// Übersetzt die Spikes einer festen Menge von Ausgangsneuronen in Werte
+#[derive(Debug, Clone)]
+pub struct Decoder {
+    /// Dekodierungsverfahren
+    decoding: Decoding,
+    /// Ausgangsneuronen in der Reihenfolge der Ausgabewerte
+    output_ids: Vec<Uuid>,
+}
+
+impl Decoder {
+    /// Erstellt einen Decoder für die angegebenen Ausgangsneuronen
+    pub fn new(decoding: Decoding, output_ids: Vec<Uuid>) -> Self {
+        Self {
+            decoding,
+            output_ids,
+        }
+    }
+
+    /// Gibt die Ausgangsneuronen zurück
+    pub fn output_ids(&self) -> &[Uuid] {
+        &self.output_ids
+    }
+
+    /// Dekodiert die Spikes im Zeitfenster `start..end` (Sekunden)
+    ///
+    /// Gibt einen Wert je Ausgangsneuron zurück; Spikes anderer Neuronen und
+    /// außerhalb des Fensters werden ignoriert.
+    pub fn decode<'a, I>(&self, spikes: I, start: f64, end: f64) -> Vec<f32>
+    where
+        I: IntoIterator<Item = &'a SpikeEvent>,
+    {
+        let index: HashMap<Uuid, usize> = self
+            .output_ids
+            .iter()
+            .enumerate()
+            .map(|(position, id)| (*id, position))
+            .collect();
+        let mut values = vec![0.0_f64; self.output_ids.len()];
+        let mut first: Option<(f64, usize)> = None;
+
+        for spike in spikes {
+            if spike.time < start || spike.time >= end {
+                continue;
+            }
+            let Some(&position) = index.get(&spike.neuron_id) else {
+                continue;
+            };
+            match self.decoding {
+                Decoding::SpikeCount | Decoding::FiringRate => values[position] += 1.0,
+                Decoding::FirstSpike => {
+                    if first.is_none_or(|(time, winner)| {
+                        spike.time < time || (spike.time == time && position < winner)
+                    }) {
+                        first = Some((spike.time, position));
+                    }
+                }
+                Decoding::ExponentialTrace { tau } => {
+                    values[position] += (-(end - spike.time) / tau.max(f64::MIN_POSITIVE)).exp();
+                }
+            }
+        }
+
+        match self.decoding {
+            Decoding::FiringRate if end > start => {
+                values.iter_mut().for_each(|count| *count /= end - start);
+            }
+            Decoding::FirstSpike => {
+                if let Some((_, winner)) = first {
+                    values[winner] = 1.0;
+                }
+            }
+            _ => {}
+        }
+        values.into_iter().map(|value| value as f32).collect()
+    }
+
+    /// Gibt den Index des Ausgangsneurons mit dem frühesten Spike im Fenster zurück
+    pub fn winner<'a, I>(&self, spikes: I, start: f64, end: f64) -> Option<usize>
+    where
+        I: IntoIterator<Item = &'a SpikeEvent>,
+    {
+        Decoder::new(Decoding::FirstSpike, self.output_ids.clone())
+            .decode(spikes, start, end)
+            .iter()
+            .position(|value| *value > 0.0)
+    }
+}
+
+/// Lineare Abbildung von Zustandsvektoren auf Ausgabewerte mit Achsenabschnitt
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LinearReadout {
+    /// Gewichte je Ausgabe, eines je Eingabe
+    weights: Vec<Vec<f64>>,
+    /// Achsenabschnitt je Ausgabe
+    bias: Vec<f64>,
+}
+
+impl LinearReadout {
+    /// Passt die Auslese mit der Methode der kleinsten Quadrate an
+    pub fn fit_least_squares(states: &[Vec<f32>], targets: &[Vec<f32>]) -> NetworkResult<Self> {
+        Self::fit_ridge(states, targets, 0.0)
+    }
+
+    /// Passt die Auslese mit Ridge-Regression an
+    ///
+    /// `lambda` bestraft die quadrierten Gewichte; der Achsenabschnitt wird nicht
+    /// bestraft. Mit `lambda = 0.0` entspricht das den kleinsten Quadraten, die
+    /// dann linear unabhängige Zustände erfordern.
+    pub fn fit_ridge(
+        states: &[Vec<f32>],
+        targets: &[Vec<f32>],
+        lambda: f64,
+    ) -> NetworkResult<Self> {
+        let Some(inputs) = states.first().map(Vec::len) else {
+            return Err(NetworkError::InvalidReadout(
+                "keine Trainingsdaten".to_string(),
+            ));
+        };
+        if targets.len() != states.len() {
+            return Err(NetworkError::InvalidReadout(format!(
+                "{} Zustände, aber {} Zielvektoren",
+                states.len(),
+                targets.len()
+            )));
+        }
+        let outputs = targets[0].len();
+        if states.iter().any(|state| state.len() != inputs)
+            || targets.iter().any(|target| target.len() != outputs)
+        {
+            return Err(NetworkError::InvalidReadout(
+                "Zustände oder Zielvektoren sind unterschiedlich lang".to_string(),
+            ));
+        }
+
+        // Normalgleichungen (XᵀX + λI) W = XᵀY mit einer Bias-Spalte aus Einsen
+        let size = inputs + 1;
+        let mut gram = vec![vec![0.0_f64; size]; size];
+        let mut moments = vec![vec![0.0_f64; outputs]; size];
+        for (state, target) in states.iter().zip(targets) {
+            let row: Vec<f64> = state
+                .iter()
+                .map(|value| *value as f64)
+                .chain(std::iter::once(1.0))
+                .collect();
+            for i in 0..size {
+                for j in 0..size {
+                    gram[i][j] += row[i] * row[j];
+                }
+                for (k, value) in target.iter().enumerate() {
+                    moments[i][k] += row[i] * *value as f64;
+                }
+            }
+        }
+        for (i, row) in gram.iter_mut().enumerate().take(inputs) {
+            row[i] += lambda.max(0.0);
+        }
+
+        let solution = solve(gram, moments)?;
+        Ok(Self {
+            weights: (0..outputs)
+                .map(|k| solution[..inputs].iter().map(|row| row[k]).collect())
+                .collect(),
+            bias: solution[inputs].clone(),
+        })
+    }
+
+    /// Passt einen Klassifikator an, der für jede Klasse eine Ausgabe hat
+    ///
+    /// Die Zielvektoren sind 1.0 für die richtige Klasse und 0.0 für alle anderen.
+    pub fn fit_classifier(
+        states: &[Vec<f32>],
+        labels: &[usize],
+        classes: usize,
+        lambda: f64,
+    ) -> NetworkResult<Self> {
+        if let Some(label) = labels.iter().find(|label| **label >= classes) {
+            return Err(NetworkError::InvalidReadout(format!(
+                "Klasse {} außerhalb von 0..{}",
+                label, classes
+            )));
+        }
+        let targets: Vec<Vec<f32>> = labels
+            .iter()
+            .map(|label| {
+                let mut target = vec![0.0; classes];
+                target[*label] = 1.0;
+                target
+            })
+            .collect();
+        Self::fit_ridge(states, &targets, lambda)
+    }
+
+    /// Gibt die Anzahl der Eingaben zurück
+    pub fn input_dim(&self) -> usize {
+        self.weights.first().map_or(0, Vec::len)
+    }
+
+    /// Gibt die Anzahl der Ausgaben zurück
+    pub fn output_dim(&self) -> usize {
+        self.bias.len()
+    }
+
+    /// Gibt die Gewichte je Ausgabe zurück
+    pub fn weights(&self) -> &[Vec<f64>] {
+        &self.weights
+    }
+
+    /// Gibt den Achsenabschnitt je Ausgabe zurück
+    pub fn bias(&self) -> &[f64] {
+        &self.bias
+    }
+
+    /// Berechnet die Ausgaben für einen Zustandsvektor
+    pub fn predict(&self, state: &[f32]) -> NetworkResult<Vec<f32>> {
+        if state.len() != self.input_dim() {
+            return Err(NetworkError::InvalidReadout(format!(
+                "Zustand mit {} Werten, erwartet {}",
+                state.len(),
+                self.input_dim()
+            )));
+        }
+        Ok(self
+            .weights
+            .iter()
+            .zip(&self.bias)
+            .map(|(weights, bias)| {
+                let sum: f64 = weights
+                    .iter()
+                    .zip(state)
+                    .map(|(weight, value)| weight * *value as f64)
+                    .sum();
+                (sum + bias) as f32
+            })
+            .collect())
+    }
+
+    /// Gibt die Klasse mit der größten Ausgabe zurück
+    pub fn classify(&self, state: &[f32]) -> NetworkResult<usize> {
+        let outputs = self.predict(state)?;
+        Ok(outputs
+            .iter()
+            .enumerate()
+            .max_by(|a, b| a.1.total_cmp(b.1).then(b.0.cmp(&a.0)))
+            .map_or(0, |(class, _)| class))
+    }
+
+    /// Anteil richtig klassifizierter Zustände
+    pub fn accuracy(&self, states: &[Vec<f32>], labels: &[usize]) -> NetworkResult<f32> {
+        if labels.len() != states.len() {
+            return Err(NetworkError::InvalidReadout(format!(
+                "{} Zustände, aber {} Klassen",
+                states.len(),
+                labels.len()
+            )));
+        }
+        if states.is_empty() {
+            return Ok(0.0);
+        }
+        let mut correct = 0;
+        for (state, label) in states.iter().zip(labels) {
+            if self.classify(state)? == *label {
+                correct += 1;
+            }
+        }
+        Ok(correct as f32 / states.len() as f32)
+    }
+}
+
+/// Löst `A X = B` mit Gauß-Elimination und Spaltenpivotsuche
+fn solve(mut a: Vec<Vec<f64>>, mut b: Vec<Vec<f64>>) -> NetworkResult<Vec<Vec<f64>>> {
+    let size = a.len();
+    for column in 0..size {
+        let pivot = (column..size)
+            .max_by(|&i, &j| a[i][column].abs().total_cmp(&a[j][column].abs()))
+            .expect("Spalte ist nicht leer");
+        if a[pivot][column].abs() < SINGULAR_TOLERANCE {
+            return Err(NetworkError::InvalidReadout(
+                "Gleichungssystem ist singulär; Ridge-Regression mit lambda > 0 verwenden"
+                    .to_string(),
+            ));
+        }
+        a.swap(column, pivot);
+        b.swap(column, pivot);
+
+        let (pivot_row, pivot_rhs) = (a[column].clone(), b[column].clone());
+        for row in column + 1..size {
+            let factor = a[row][column] / pivot_row[column];
+            if factor == 0.0 {
+                continue;
+            }
+            for (value, pivot) in a[row][column..].iter_mut().zip(&pivot_row[column..]) {
+                *value -= factor * pivot;
+            }
+            for (value, pivot) in b[row].iter_mut().zip(&pivot_rhs) {
+                *value -= factor * pivot;
+            }
+        }
+    }
+
+    // Rückwärtseinsetzen
+    for row in (0..size).rev() {
+        for k in 0..b[row].len() {
+            let sum: f64 = (row + 1..size).map(|j| a[row][j] * b[j][k]).sum();
+            b[row][k] = (b[row][k] - sum) / a[row][row];
+        }
+    }
+    Ok(b)
+}
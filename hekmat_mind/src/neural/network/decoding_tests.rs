@@ -0,0 +1,157 @@
+use crate::neural::network::NetworkError;
+use crate::neural::network::decoding::{Decoder, Decoding, LinearReadout};
+use crate::neural::network::encoding::{Encoder, Encoding};
+use crate::neural::network::event_driven::SpikeEvent;
+use crate::neural::network::model::Network;
+use crate::neural::network::monitor::SpikeRecorder;
+use crate::neural::neuron::lif::LifNeuron;
+use crate::neural::neuron::traits::NeuronModel;
+use uuid::Uuid;
+
+/// Spikes zweier Ausgangsneuronen und eines fremden Neurons
+fn output_spikes() -> (Vec<Uuid>, Vec<SpikeEvent>) {
+    let ids = vec![Uuid::new_v4(), Uuid::new_v4()];
+    let spike = |time, neuron_id| SpikeEvent { time, neuron_id };
+    let spikes = vec![
+        spike(0.005, ids[1]),
+        spike(0.010, ids[0]),
+        spike(0.020, ids[1]),
+        spike(0.030, ids[0]),
+        spike(0.030, Uuid::new_v4()),
+        spike(0.040, ids[0]),
+        spike(0.120, ids[0]),
+    ];
+    (ids, spikes)
+}
+
+#[test]
+fn test_spike_decoders() {
+    let (ids, spikes) = output_spikes();
+
+    let counts = Decoder::new(Decoding::SpikeCount, ids.clone()).decode(&spikes, 0.0, 0.1);
+    assert_eq!(counts, vec![3.0, 2.0]);
+    let rates = Decoder::new(Decoding::FiringRate, ids.clone()).decode(&spikes, 0.0, 0.1);
+    assert!((rates[0] - 30.0).abs() < 1e-4);
+    assert!((rates[1] - 20.0).abs() < 1e-4);
+
+    // Das Fenster bestimmt den Sieger
+    let first = Decoder::new(Decoding::FirstSpike, ids.clone());
+    assert_eq!(first.decode(&spikes, 0.0, 0.1), vec![0.0, 1.0]);
+    assert_eq!(first.winner(&spikes, 0.008, 0.1), Some(0));
+    assert_eq!(first.winner(&spikes, 0.2, 0.3), None);
+    assert_eq!(first.decode(&spikes, 0.2, 0.3), vec![0.0, 0.0]);
+
+    let trace = Decoder::new(Decoding::ExponentialTrace { tau: 0.02 }, ids.clone())
+        .decode(&spikes, 0.0, 0.05);
+    let expected: f64 = [0.01, 0.03, 0.04]
+        .iter()
+        .map(|time: &f64| (-(0.05 - time) / 0.02).exp())
+        .sum();
+    assert!((trace[0] as f64 - expected).abs() < 1e-6);
+    assert!(trace[1] < trace[0]);
+}
+
+#[test]
+fn test_least_squares_and_ridge_readout() {
+    // y0 = 2·x0 − x1 + 0.5, y1 = x1
+    let states: Vec<Vec<f32>> = vec![
+        vec![0.0, 0.0],
+        vec![1.0, 0.0],
+        vec![0.0, 1.0],
+        vec![1.0, 1.0],
+        vec![2.0, 3.0],
+    ];
+    let targets: Vec<Vec<f32>> = states
+        .iter()
+        .map(|x| vec![2.0 * x[0] - x[1] + 0.5, x[1]])
+        .collect();
+
+    let readout = LinearReadout::fit_least_squares(&states, &targets).unwrap();
+    assert_eq!(readout.input_dim(), 2);
+    assert_eq!(readout.output_dim(), 2);
+    assert!((readout.weights()[0][0] - 2.0).abs() < 1e-6);
+    assert!((readout.weights()[0][1] + 1.0).abs() < 1e-6);
+    assert!((readout.bias()[0] - 0.5).abs() < 1e-6);
+    let prediction = readout.predict(&[3.0, -1.0]).unwrap();
+    assert!((prediction[0] - 7.5).abs() < 1e-5);
+    assert!((prediction[1] + 1.0).abs() < 1e-5);
+
+    // Ridge-Regression verkleinert die Gewichte
+    let ridge = LinearReadout::fit_ridge(&states, &targets, 10.0).unwrap();
+    assert!(ridge.weights()[0][0].abs() < readout.weights()[0][0].abs());
+
+    // Linear abhängige Zustände erfordern lambda > 0
+    let collinear: Vec<Vec<f32>> = states.iter().map(|x| vec![x[0], 2.0 * x[0]]).collect();
+    assert!(matches!(
+        LinearReadout::fit_least_squares(&collinear, &targets),
+        Err(NetworkError::InvalidReadout(_))
+    ));
+    assert!(LinearReadout::fit_ridge(&collinear, &targets, 0.1).is_ok());
+    assert!(matches!(
+        readout.predict(&[1.0]),
+        Err(NetworkError::InvalidReadout(_))
+    ));
+    assert!(matches!(
+        LinearReadout::fit_least_squares(&states, &targets[..2]),
+        Err(NetworkError::InvalidReadout(_))
+    ));
+}
+
+#[test]
+fn test_classification_from_network_activity() {
+    // Vier LIF-Neuronen geben ihre ratenkodierte Eingabe als Spikes wieder
+    let mut network = Network::new();
+    network.disable_plasticity();
+    let mut ids = Vec::new();
+    for _ in 0..4 {
+        let neuron = LifNeuron::new();
+        ids.push(*neuron.id());
+        network.add_neuron(neuron);
+    }
+    let recorder = network.attach_monitor(SpikeRecorder::new());
+    let mut encoder =
+        Encoder::new(Encoding::Rate { max_rate: 200.0 }, ids.clone()).with_window(0.05);
+    let decoder = Decoder::new(Decoding::SpikeCount, ids.clone());
+
+    // Klasse 0 aktiviert die erste, Klasse 1 die zweite Hälfte der Eingänge
+    let samples: Vec<(Vec<f32>, usize)> = (0..8)
+        .map(|k| {
+            let strength = 0.5 + 0.05 * k as f32;
+            if k % 2 == 0 {
+                (vec![strength, strength, 0.1, 0.0], 0)
+            } else {
+                (vec![0.0, 0.1, strength, strength], 1)
+            }
+        })
+        .collect();
+
+    let mut states = Vec::new();
+    let mut labels = Vec::new();
+    for (values, label) in &samples {
+        let start = network.time();
+        network
+            .run_schedule(&encoder.encode(values).unwrap(), 0.001)
+            .unwrap();
+        let spikes = network
+            .monitor(recorder)
+            .unwrap()
+            .as_spike_recorder()
+            .unwrap();
+        states.push(decoder.decode(spikes.spikes(), start, network.time()));
+        labels.push(*label);
+    }
+    assert!(states[0][0] > 0.0 && states[0][3] == 0.0);
+
+    let classifier = LinearReadout::fit_classifier(&states, &labels, 2, 0.01).unwrap();
+    assert_eq!(classifier.accuracy(&states, &labels).unwrap(), 1.0);
+    assert_eq!(classifier.classify(&[8.0, 8.0, 0.0, 0.0]).unwrap(), 0);
+    assert_eq!(classifier.classify(&[0.0, 0.0, 8.0, 8.0]).unwrap(), 1);
+    assert!(matches!(
+        classifier.accuracy(&states, &labels[..4]),
+        Err(NetworkError::InvalidReadout(_))
+    ));
+    assert!(matches!(
+        LinearReadout::fit_classifier(&states, &[2; 8], 2, 0.01),
+        Err(NetworkError::InvalidReadout(_))
+    ));
+}
@@ -0,0 +1,125 @@
+//! Warteschlange für verzögerte synaptische Übertragung
+//!
+//! Jeder Spike, der über eine Synapse läuft, wird als Ereignis mit seiner
+//! Ankunftszeit (Spikezeit + synaptische Verzögerung) eingereiht. Das Netzwerk
+//! entnimmt in jedem Zyklus alle fälligen Ereignisse in zeitlicher Reihenfolge.
+
+use crate::neural::synapse::model::SynapseType;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use uuid::Uuid;
+
+/// Ein synaptisches Signal, das zu einem späteren Zeitpunkt zugestellt wird
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PendingSpike {
+    /// Ankunftszeit am postsynaptischen Neuron in Sekunden
+    pub arrival_time: f64,
+    /// ID des präsynaptischen Neurons
+    pub pre_id: Uuid,
+    /// ID des postsynaptischen Neurons
+    pub post_id: Uuid,
+    /// Gewichtetes Signal, das zugestellt wird
+    pub signal: f32,
+    /// Art der übertragenden Synapse
+    pub synapse_type: SynapseType,
+    /// Laufende Nummer, damit gleichzeitige Ereignisse in Einfügereihenfolge bleiben
+    sequence: u64,
+}
+
+impl Eq for PendingSpike {}
+
+impl Ord for PendingSpike {
+    /// Umgekehrte Ordnung, damit der [`BinaryHeap`] das früheste Ereignis zuerst liefert
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .arrival_time
+            .total_cmp(&self.arrival_time)
+            .then_with(|| other.sequence.cmp(&self.sequence))
+    }
+}
+
+impl PartialOrd for PendingSpike {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Nach Ankunftszeit geordnete Warteschlange für Spike-Ereignisse
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SpikeQueue {
+    /// Ausstehende Ereignisse, frühestes zuerst
+    events: BinaryHeap<PendingSpike>,
+    /// Nächste laufende Nummer
+    next_sequence: u64,
+}
+
+impl SpikeQueue {
+    /// Erstellt eine leere Warteschlange
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reiht ein Signal mit der angegebenen Ankunftszeit ein
+    pub fn push(
+        &mut self,
+        arrival_time: f64,
+        pre_id: Uuid,
+        post_id: Uuid,
+        signal: f32,
+        synapse_type: SynapseType,
+    ) {
+        self.events.push(PendingSpike {
+            arrival_time,
+            pre_id,
+            post_id,
+            signal,
+            synapse_type,
+            sequence: self.next_sequence,
+        });
+        self.next_sequence += 1;
+    }
+
+    /// Entnimmt alle Ereignisse mit einer Ankunftszeit bis einschließlich `until`
+    ///
+    /// Die Ereignisse werden in der Reihenfolge ihrer Ankunft zurückgegeben.
+    pub fn pop_due(&mut self, until: f64) -> Vec<PendingSpike> {
+        let mut due = Vec::new();
+        while let Some(event) = self.events.peek() {
+            if event.arrival_time > until {
+                break;
+            }
+            due.push(self.events.pop().expect("peek lieferte ein Ereignis"));
+        }
+        due
+    }
+
+    /// Gibt die Ankunftszeit des nächsten Ereignisses zurück
+    pub fn next_arrival(&self) -> Option<f64> {
+        self.events.peek().map(|event| event.arrival_time)
+    }
+
+    /// Gibt die Anzahl der ausstehenden Ereignisse zurück
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    /// Prüft, ob keine Ereignisse ausstehen
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    /// Behält nur die Ereignisse, für die `keep` `true` liefert
+    pub fn retain<F>(&mut self, keep: F)
+    where
+        F: FnMut(&PendingSpike) -> bool,
+    {
+        self.events.retain(keep);
+    }
+
+    /// Verwirft alle ausstehenden Ereignisse
+    pub fn clear(&mut self) {
+        self.events.clear();
+        self.next_sequence = 0;
+    }
+}
@@ -0,0 +1,389 @@
+//! Kodierung reellwertiger Eingaben als Stimulationen
+//!
+//! Ein [`Encoder`] übersetzt einen Vektor von Eingabewerten (oder eine Zeitreihe
+//! solcher Vektoren) in einen [`StimulusSchedule`] für eine feste Menge von
+//! Eingangsneuronen. Jeder Vektor wird für ein Präsentationsfenster angelegt, die
+//! Fenster einer Zeitreihe folgen lückenlos aufeinander. Die [`Encoding`] bestimmt,
+//! wie ein Wert zu Spikes wird:
+//!
+//! - Ratenkodierung mit regelmäßigen Spikes
+//! - Poisson-Spikefolgen, über einen Startwert reproduzierbar
+//! - Latenzkodierung (Zeit bis zum ersten Spike)
+//! - Populationskodierung mit gaußschen rezeptiven Feldern
+//!
+//! Eingabewerte für Raten- und Latenzkodierung werden auf 0.0 - 1.0 beschränkt.
+//! Mit [`Network::run_schedule`] wird ein Plan taktgesteuert abgespielt.
+
+use crate::neural::network::model::Network;
+use crate::neural::network::{NetworkError, NetworkResult};
+use rand::prelude::*;
+use rand::rngs::StdRng;
+use uuid::Uuid;
+
+/// Toleranz beim Zuordnen von Stimulationen zu Zeitschritten, als Anteil eines Schritts
+///
+/// Fängt Rundungsfehler des in `f32` angegebenen Zeitschritts ab, damit eine
+/// Stimulation auf einer Schrittgrenze nicht in den vorherigen Schritt rutscht.
+const STEP_TOLERANCE: f64 = 1e-3;
+
+/// Mindestaktivierung eines rezeptiven Feldes, unterhalb derer es nicht feuert
+const MIN_FIELD_ACTIVATION: f64 = 0.1;
+
+/// Stimulation eines Eingangsneurons zu einem Zeitpunkt
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stimulus {
+    /// Zeitpunkt relativ zum Beginn des Plans in Sekunden
+    pub time: f64,
+    /// Stimuliertes Neuron
+    pub neuron_id: Uuid,
+    /// Eingabe, die an das Neuron übergeben wird
+    pub amplitude: f32,
+}
+
+/// Zeitlich sortierte Stimulationen mit einer Gesamtdauer
+///
+/// Ein Plan umfasst das halboffene Intervall `[0, duration)`: Eine Stimulation genau
+/// am Ende gehört bereits zum nächsten Plan, etwa nach [`append`](Self::append).
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct StimulusSchedule {
+    /// Stimulationen in zeitlicher Reihenfolge
+    stimuli: Vec<Stimulus>,
+    /// Dauer des Plans in Sekunden
+    duration: f64,
+}
+
+impl StimulusSchedule {
+    /// Erstellt einen leeren Plan der angegebenen Dauer
+    pub fn new(duration: f64) -> Self {
+        Self {
+            stimuli: Vec::new(),
+            duration: duration.max(0.0),
+        }
+    }
+
+    /// Fügt eine Stimulation ein
+    ///
+    /// Stimulationen vor dem Beginn oder ab dem Ende des Plans werden abgelehnt.
+    pub fn push(&mut self, stimulus: Stimulus) -> NetworkResult<()> {
+        if !(0.0..self.duration).contains(&stimulus.time) {
+            return Err(NetworkError::InvalidEncoding(format!(
+                "Stimulation bei {} s liegt außerhalb des Plans [0, {}) s",
+                stimulus.time, self.duration
+            )));
+        }
+        let index = self
+            .stimuli
+            .partition_point(|existing| existing.time <= stimulus.time);
+        self.stimuli.insert(index, stimulus);
+        Ok(())
+    }
+
+    /// Hängt einen Plan zeitlich versetzt hinter diesen an
+    pub fn append(&mut self, other: &StimulusSchedule) {
+        let offset = self.duration;
+        self.stimuli
+            .extend(other.stimuli.iter().map(|stimulus| Stimulus {
+                time: stimulus.time + offset,
+                ..*stimulus
+            }));
+        self.duration += other.duration;
+    }
+
+    /// Gibt die Stimulationen in zeitlicher Reihenfolge zurück
+    pub fn stimuli(&self) -> &[Stimulus] {
+        &self.stimuli
+    }
+
+    /// Gibt die Dauer des Plans in Sekunden zurück
+    pub fn duration(&self) -> f64 {
+        self.duration
+    }
+
+    /// Gibt die Anzahl der Stimulationen zurück
+    pub fn len(&self) -> usize {
+        self.stimuli.len()
+    }
+
+    /// Prüft, ob der Plan keine Stimulationen enthält
+    pub fn is_empty(&self) -> bool {
+        self.stimuli.is_empty()
+    }
+
+    /// Gibt die Stimulationen eines Neurons zurück
+    pub fn stimuli_for(&self, neuron_id: &Uuid) -> Vec<Stimulus> {
+        self.stimuli
+            .iter()
+            .filter(|stimulus| stimulus.neuron_id == *neuron_id)
+            .copied()
+            .collect()
+    }
+}
+
+/// Verfahren, mit dem ein Eingabewert in Spikes übersetzt wird
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Encoding {
+    /// Regelmäßige Spikes mit `value · max_rate` Hz ab Fensterbeginn
+    Rate {
+        /// Feuerrate in Hz für den Wert 1.0
+        max_rate: f64,
+    },
+    /// Poisson-Spikefolge mit der mittleren Rate `value · max_rate` Hz
+    Poisson {
+        /// Mittlere Feuerrate in Hz für den Wert 1.0
+        max_rate: f64,
+    },
+    /// Ein Spike nach `(1 − value) · window`; der Wert 0.0 erzeugt keinen Spike
+    Latency,
+    /// Jeder Wert wird von mehreren Neuronen mit gaußschen rezeptiven Feldern kodiert
+    ///
+    /// Die Zentren verteilen sich gleichmäßig über `min..=max`, die Breite entspricht
+    /// dem Abstand benachbarter Zentren. Die Aktivierung eines Feldes wird als
+    /// Latenz kodiert, Felder mit einer Aktivierung unter 0.1 feuern nicht.
+    /// Die Neuronen zum i-ten Wert liegen in den Eingangsneuronen an den Positionen
+    /// `i · neurons_per_value ..`.
+    ReceptiveFields {
+        /// Neuronen je Eingabewert
+        neurons_per_value: usize,
+        /// Kleinster kodierter Wert
+        min: f32,
+        /// Größter kodierter Wert
+        max: f32,
+    },
+}
+
+impl Encoding {
+    /// Anzahl der Eingangsneuronen je Eingabewert
+    pub fn neurons_per_value(&self) -> usize {
+        match self {
+            Encoding::ReceptiveFields {
+                neurons_per_value, ..
+            } => *neurons_per_value,
+            _ => 1,
+        }
+    }
+}
+
+/// Übersetzt Eingabevektoren in Stimulationen einer festen Menge von Eingangsneuronen
+#[derive(Debug, Clone)]
+pub struct Encoder {
+    /// Kodierungsverfahren
+    encoding: Encoding,
+    /// Eingangsneuronen in der Reihenfolge der Eingabewerte
+    input_ids: Vec<Uuid>,
+    /// Präsentationsdauer eines Eingabevektors in Sekunden
+    window: f64,
+    /// Eingabe je Spike
+    amplitude: f32,
+    /// Zufallsgenerator für Poisson-Spikefolgen, den aufeinanderfolgende Aufrufe fortsetzen
+    rng: StdRng,
+}
+
+impl Encoder {
+    /// Erstellt einen Encoder mit einem Präsentationsfenster von 100 ms
+    pub fn new(encoding: Encoding, input_ids: Vec<Uuid>) -> Self {
+        Self {
+            encoding,
+            input_ids,
+            window: 0.1,
+            amplitude: 1.0,
+            rng: StdRng::from_entropy(),
+        }
+    }
+
+    /// Setzt die Präsentationsdauer eines Eingabevektors in Sekunden
+    pub fn with_window(mut self, window: f64) -> Self {
+        self.window = window.max(0.0);
+        self
+    }
+
+    /// Setzt die Eingabe, die ein Spike an das Neuron übergibt
+    pub fn with_amplitude(mut self, amplitude: f32) -> Self {
+        self.amplitude = amplitude;
+        self
+    }
+
+    /// Macht Poisson-Spikefolgen reproduzierbar
+    ///
+    /// Aufeinanderfolgende Aufrufe von [`encode`](Self::encode) ziehen aus einem
+    /// gemeinsamen Zufallsstrom: Jedes Fenster erhält ein eigenes Rauschmuster, und
+    /// Encoder mit gleichem Startwert liefern Aufruf für Aufruf dieselben Spikefolgen.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.rng = StdRng::seed_from_u64(seed);
+        self
+    }
+
+    /// Gibt die Eingangsneuronen zurück
+    pub fn input_ids(&self) -> &[Uuid] {
+        &self.input_ids
+    }
+
+    /// Gibt die Präsentationsdauer eines Eingabevektors in Sekunden zurück
+    pub fn window(&self) -> f64 {
+        self.window
+    }
+
+    /// Kodiert einen Eingabevektor für ein Präsentationsfenster
+    pub fn encode(&mut self, values: &[f32]) -> NetworkResult<StimulusSchedule> {
+        self.encode_series(&[values])
+    }
+
+    /// Kodiert eine Zeitreihe von Eingabevektoren in aufeinanderfolgenden Fenstern
+    pub fn encode_series<V: AsRef<[f32]>>(
+        &mut self,
+        series: &[V],
+    ) -> NetworkResult<StimulusSchedule> {
+        // Der Generator wird erst nach erfolgreicher Kodierung fortgeschrieben
+        let mut rng = self.rng.clone();
+        let mut stimuli = Vec::new();
+        for (frame, values) in series.iter().enumerate() {
+            let values = values.as_ref();
+            let expected = values.len() * self.encoding.neurons_per_value();
+            if expected != self.input_ids.len() {
+                return Err(NetworkError::InvalidEncoding(format!(
+                    "{} Eingabewerte benötigen {} Eingangsneuronen, vorhanden sind {}",
+                    values.len(),
+                    expected,
+                    self.input_ids.len()
+                )));
+            }
+            let offset = frame as f64 * self.window;
+            for (index, value) in values.iter().enumerate() {
+                self.encode_value(*value, index, offset, &mut rng, &mut stimuli);
+            }
+        }
+
+        self.rng = rng;
+        stimuli.sort_by(|a, b| {
+            a.time
+                .total_cmp(&b.time)
+                .then(a.neuron_id.cmp(&b.neuron_id))
+        });
+        Ok(StimulusSchedule {
+            stimuli,
+            duration: series.len() as f64 * self.window,
+        })
+    }
+
+    /// Kodiert den Wert an Position `index` in Spikes ab `offset`
+    fn encode_value<R: Rng>(
+        &self,
+        value: f32,
+        index: usize,
+        offset: f64,
+        rng: &mut R,
+        stimuli: &mut Vec<Stimulus>,
+    ) {
+        let mut emit = |neuron_id: Uuid, time: f64| {
+            stimuli.push(Stimulus {
+                time: offset + time,
+                neuron_id,
+                amplitude: self.amplitude,
+            });
+        };
+        let value = value as f64;
+        match self.encoding {
+            Encoding::Rate { max_rate } => {
+                let rate = value.clamp(0.0, 1.0) * max_rate;
+                if rate <= 0.0 {
+                    return;
+                }
+                // Der Rundungsfehler des `f32`-Eingabewerts darf keinen zusätzlichen
+                // Spike unmittelbar vor dem Fensterende erzeugen
+                let count = (self.window * rate * (1.0 - f32::EPSILON as f64)).ceil() as usize;
+                for spike in 0..count {
+                    emit(self.input_ids[index], spike as f64 / rate);
+                }
+            }
+            Encoding::Poisson { max_rate } => {
+                let rate = value.clamp(0.0, 1.0) * max_rate;
+                if rate <= 0.0 {
+                    return;
+                }
+                // Exponentialverteilte Abstände; 1 − U liegt in (0, 1]
+                let mut time = -(1.0 - rng.r#gen::<f64>()).ln() / rate;
+                while time < self.window {
+                    emit(self.input_ids[index], time);
+                    time += -(1.0 - rng.r#gen::<f64>()).ln() / rate;
+                }
+            }
+            Encoding::Latency => {
+                if let Some(time) = self.latency(value) {
+                    emit(self.input_ids[index], time);
+                }
+            }
+            Encoding::ReceptiveFields {
+                neurons_per_value,
+                min,
+                max,
+            } => {
+                let (min, max) = (min as f64, max as f64);
+                let spacing = if neurons_per_value > 1 {
+                    (max - min) / (neurons_per_value - 1) as f64
+                } else {
+                    max - min
+                };
+                let width = spacing.abs().max(f64::EPSILON);
+                for field in 0..neurons_per_value {
+                    let center = min + field as f64 * spacing;
+                    let activation = (-(value - center).powi(2) / (2.0 * width * width)).exp();
+                    if activation < MIN_FIELD_ACTIVATION {
+                        continue;
+                    }
+                    if let Some(time) = self.latency(activation) {
+                        emit(self.input_ids[index * neurons_per_value + field], time);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Zeit bis zum ersten Spike für einen Wert zwischen 0.0 und 1.0
+    fn latency(&self, value: f64) -> Option<f64> {
+        let value = value.clamp(0.0, 1.0);
+        (value > 0.0).then_some((1.0 - value) * self.window)
+    }
+}
+
+impl Network {
+    /// Spielt einen Stimulationsplan ab der aktuellen Simulationszeit taktgesteuert ab
+    ///
+    /// Vor jedem Zyklus werden die Stimulationen angelegt, die in dessen Zeitschritt
+    /// fallen. Es werden so viele Zyklen ausgeführt, wie die Dauer des Plans umfasst.
+    /// Stimulationen, die nur durch Rundung in den Zeitschritt nach dem Ende des Plans
+    /// fallen, werden im letzten Zyklus angelegt.
+    pub fn run_schedule(
+        &mut self,
+        schedule: &StimulusSchedule,
+        time_step: f32,
+    ) -> NetworkResult<()> {
+        if let Some(stimulus) = schedule
+            .stimuli()
+            .iter()
+            .find(|stimulus| self.get_neuron(&stimulus.neuron_id).is_none())
+        {
+            return Err(NetworkError::NeuronNotFound(stimulus.neuron_id));
+        }
+        if time_step <= 0.0 {
+            return Ok(());
+        }
+
+        let step = time_step as f64;
+        let slot = |time: f64| (time / step + STEP_TOLERANCE).floor();
+        let steps = (schedule.duration() / step - STEP_TOLERANCE)
+            .ceil()
+            .max(0.0) as usize;
+        let mut pending = schedule.stimuli().iter().peekable();
+        for index in 0..steps {
+            let limit = if index + 1 == steps {
+                f64::INFINITY
+            } else {
+                index as f64
+            };
+            while let Some(stimulus) = pending.next_if(|stimulus| slot(stimulus.time) <= limit) {
+                self.stimulate_neuron(&stimulus.neuron_id, stimulus.amplitude);
+            }
+            self.cycle(time_step);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,211 @@
+use crate::neural::network::NetworkError;
+use crate::neural::network::encoding::{Encoder, Encoding, Stimulus, StimulusSchedule};
+use crate::neural::network::model::Network;
+use crate::neural::network::monitor::SpikeRecorder;
+use crate::neural::neuron::lif::LifNeuron;
+use crate::neural::neuron::traits::NeuronModel;
+use uuid::Uuid;
+
+/// Sortierte Kennungen für `count` Eingangsneuronen
+fn input_ids(count: usize) -> Vec<Uuid> {
+    let mut ids: Vec<Uuid> = (0..count).map(|_| Uuid::new_v4()).collect();
+    ids.sort();
+    ids
+}
+
+/// Zeitpunkte der Stimulationen eines Neurons
+fn times(schedule: &StimulusSchedule, id: &Uuid) -> Vec<f64> {
+    schedule
+        .stimuli_for(id)
+        .iter()
+        .map(|stimulus| stimulus.time)
+        .collect()
+}
+
+#[test]
+fn test_rate_and_latency_coding() {
+    let ids = input_ids(3);
+    let mut rate = Encoder::new(Encoding::Rate { max_rate: 100.0 }, ids.clone()).with_window(0.1);
+    let schedule = rate.encode(&[1.0, 0.5, 0.0]).unwrap();
+    assert_eq!(schedule.duration(), 0.1);
+    assert_eq!(times(&schedule, &ids[0]).len(), 10);
+    let half = times(&schedule, &ids[1]);
+    assert_eq!(half.len(), 5);
+    assert!((half[1] - 0.02).abs() < 1e-12);
+    assert!(times(&schedule, &ids[2]).is_empty());
+    assert!(
+        schedule
+            .stimuli()
+            .windows(2)
+            .all(|pair| pair[0].time <= pair[1].time)
+    );
+    // Werte außerhalb von 0.0 - 1.0 werden beschränkt
+    assert_eq!(rate.encode(&[2.0, -1.0, 0.0]).unwrap().len(), 10);
+
+    // 0.6 · 200 Hz füllen ein Fenster von 50 ms mit genau sechs Spikes, obwohl
+    // 0.6 in `f32` etwas größer ist
+    let exact = Encoder::new(Encoding::Rate { max_rate: 200.0 }, ids[..1].to_vec())
+        .with_window(0.05)
+        .encode(&[0.6])
+        .unwrap();
+    assert_eq!(exact.len(), 6);
+    assert!(exact.stimuli()[5].time < 0.045);
+
+    // Große Werte feuern früh, kleine spät
+    let mut latency = Encoder::new(Encoding::Latency, ids.clone())
+        .with_window(0.05)
+        .with_amplitude(0.8);
+    let schedule = latency.encode(&[1.0, 0.2, 0.0]).unwrap();
+    assert_eq!(schedule.len(), 2);
+    assert_eq!(
+        schedule.stimuli()[0],
+        Stimulus {
+            time: 0.0,
+            neuron_id: ids[0],
+            amplitude: 0.8
+        }
+    );
+    assert!((schedule.stimuli()[1].time - 0.04).abs() < 1e-7);
+
+    assert!(matches!(
+        latency.encode(&[1.0]),
+        Err(NetworkError::InvalidEncoding(_))
+    ));
+}
+
+#[test]
+fn test_poisson_trains_are_seedable() {
+    let ids = input_ids(2);
+    let encoder = || {
+        Encoder::new(Encoding::Poisson { max_rate: 200.0 }, ids.clone())
+            .with_window(1.0)
+            .with_seed(3)
+    };
+    let (mut a, mut b) = (encoder(), encoder());
+    let first = a.encode(&[1.0, 0.25]).unwrap();
+    let second = a.encode(&[1.0, 0.25]).unwrap();
+
+    // Aufeinanderfolgende Fenster erhalten eigenes Rauschen, Encoder mit gleichem
+    // Startwert stimmen Aufruf für Aufruf überein
+    assert_ne!(first, second);
+    assert_eq!(first, b.encode(&[1.0, 0.25]).unwrap());
+    assert_eq!(second, b.encode(&[1.0, 0.25]).unwrap());
+    assert_ne!(first, encoder().with_seed(4).encode(&[1.0, 0.25]).unwrap());
+
+    // Mittlere Raten von 200 Hz und 50 Hz
+    let fast = times(&first, &ids[0]).len();
+    let slow = times(&first, &ids[1]).len();
+    assert!((160..=240).contains(&fast), "{} Spikes", fast);
+    assert!((30..=70).contains(&slow), "{} Spikes", slow);
+    assert!(first.stimuli().iter().all(|s| (0.0..1.0).contains(&s.time)));
+}
+
+#[test]
+fn test_receptive_fields_and_series() {
+    let ids = input_ids(10);
+    let mut encoder = Encoder::new(
+        Encoding::ReceptiveFields {
+            neurons_per_value: 5,
+            min: 0.0,
+            max: 1.0,
+        },
+        ids.clone(),
+    )
+    .with_window(0.02);
+
+    // Das Feld mit dem nächsten Zentrum feuert zuerst, entfernte Felder später
+    let schedule = encoder.encode(&[0.5, 1.0]).unwrap();
+    let first = |fields: &[Uuid]| {
+        fields
+            .iter()
+            .enumerate()
+            .filter_map(|(field, id)| times(&schedule, id).first().map(|time| (*time, field)))
+            .min_by(|a, b| a.0.total_cmp(&b.0))
+            .unwrap()
+    };
+    assert_eq!(first(&ids[..5]), (0.0, 2));
+    assert_eq!(first(&ids[5..]), (0.0, 4));
+    let left = times(&schedule, &ids[1])[0];
+    assert!(left > 0.0 && left < times(&schedule, &ids[0])[0]);
+
+    // Aufeinanderfolgende Fenster einer Zeitreihe
+    let series = encoder
+        .encode_series(&[vec![0.0, 0.0], vec![1.0, 1.0]])
+        .unwrap();
+    assert_eq!(series.duration(), 0.04);
+    assert_eq!(times(&series, &ids[4]), vec![0.02]);
+    let mut appended = encoder.encode(&[0.0, 0.0]).unwrap();
+    appended.append(&encoder.encode(&[1.0, 1.0]).unwrap());
+    assert_eq!(appended.duration(), series.duration());
+    assert_eq!(appended.len(), series.len());
+}
+
+#[test]
+fn test_run_schedule_drives_input_neurons() {
+    let mut network = Network::new();
+    network.disable_plasticity();
+    let mut ids = Vec::new();
+    for _ in 0..2 {
+        let neuron = LifNeuron::new();
+        ids.push(*neuron.id());
+        network.add_neuron(neuron);
+    }
+    let recorder = network.attach_monitor(SpikeRecorder::new());
+
+    let schedule = Encoder::new(Encoding::Rate { max_rate: 50.0 }, ids.clone())
+        .with_window(0.1)
+        .encode(&[1.0, 0.0])
+        .unwrap();
+    network.run_schedule(&schedule, 0.001).unwrap();
+    assert!((network.time() - 0.1).abs() < 1e-6);
+
+    // Jede Stimulation löst einen Spike im folgenden Zyklus aus
+    let recorder = network
+        .monitor(recorder)
+        .unwrap()
+        .as_spike_recorder()
+        .unwrap();
+    let spikes = recorder.spike_times(&ids[0]);
+    assert_eq!(spikes.len(), 5);
+    for (spike, stimulus) in spikes.iter().zip(times(&schedule, &ids[0])) {
+        assert!((spike - stimulus - 0.001).abs() < 1e-6);
+    }
+    assert!(recorder.spike_times(&ids[1]).is_empty());
+
+    // Ein Plan umfasst [0, duration): Stimulationen am Ende werden abgelehnt,
+    // eine Stimulation im letzten Zeitschritt erreicht das Netzwerk noch
+    let stimulus = |time: f64| Stimulus {
+        time,
+        neuron_id: ids[1],
+        amplitude: 1.0,
+    };
+    let mut last = StimulusSchedule::new(0.002);
+    assert!(matches!(
+        last.push(stimulus(0.002)),
+        Err(NetworkError::InvalidEncoding(_))
+    ));
+    assert!(matches!(
+        last.push(stimulus(-0.001)),
+        Err(NetworkError::InvalidEncoding(_))
+    ));
+    last.push(stimulus(0.001)).unwrap();
+    assert_eq!(last.len(), 1);
+    assert_eq!(last.duration(), 0.002);
+    network.run_schedule(&last, 0.001).unwrap();
+    assert!((network.time() - 0.102).abs() < 1e-6);
+    assert!(network.get_neuron(&ids[1]).unwrap().is_spiking());
+
+    let mut foreign = StimulusSchedule::new(0.01);
+    let unknown = Uuid::new_v4();
+    foreign
+        .push(Stimulus {
+            time: 0.005,
+            neuron_id: unknown,
+            amplitude: 1.0,
+        })
+        .unwrap();
+    assert_eq!(
+        network.run_schedule(&foreign, 0.001),
+        Err(NetworkError::NeuronNotFound(unknown))
+    );
+}
@@ -0,0 +1,145 @@
+//! Hilfsstrukturen für die ereignisgesteuerte Simulation
+//!
+//! Statt jedes Neuron in jedem Zeitschritt zu integrieren, springt die
+//! ereignisgesteuerte Simulation von Ereignis zu Ereignis. Es gibt zwei Arten:
+//!
+//! - **Ankünfte**: Signale, die über eine Synapse unterwegs sind, stehen wie im
+//!   taktgesteuerten Zyklus in der [`SpikeQueue`](crate::neural::network::delay::SpikeQueue).
+//! - **Schwellwertüberschreitungen**: Für jedes Neuron wird der Zeitpunkt vorhergesagt,
+//!   an dem es ohne weitere Eingaben feuern würde ([`ThresholdSchedule`]). Erreicht ein
+//!   Signal das Neuron vorher, wird die Vorhersage erneuert.
+//!
+//! Zwischen den Ereignissen werden die betroffenen Neuronen analytisch fortgeschrieben
+//! ([`AnalyticNeuron`](crate::neural::neuron::traits::AnalyticNeuron)).
+
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap};
+use uuid::Uuid;
+
+/// Zeitspanne in Sekunden, innerhalb der Ereignisse als gleichzeitig gelten
+///
+/// Synaptische Verzögerungen werden als `f32` gespeichert; über mehrere Synapsen
+/// summieren sich deren Rundungsfehler. Signale, die im taktgesteuerten Zyklus im
+/// selben Zeitschritt ankämen, werden so auch hier gemeinsam zugestellt.
+pub const SIMULTANEITY_TOLERANCE: f64 = 1e-6;
+
+/// Ein Spike, den ein Neuron während der ereignisgesteuerten Simulation ausgesendet hat
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SpikeEvent {
+    /// Zeitpunkt des Spikes in Sekunden
+    pub time: f64,
+    /// ID des feuernden Neurons
+    pub neuron_id: Uuid,
+}
+
+/// Vorhergesagte Schwellwertüberschreitung eines Neurons
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Crossing {
+    /// Vorhergesagter Spikezeitpunkt in Sekunden
+    time: f64,
+    /// ID des Neurons
+    neuron_id: Uuid,
+    /// Version der Vorhersage; ältere Versionen sind überholt
+    version: u64,
+}
+
+impl Eq for Crossing {}
+
+impl Ord for Crossing {
+    /// Umgekehrte Ordnung, damit der [`BinaryHeap`] die früheste Vorhersage zuerst liefert
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .time
+            .total_cmp(&self.time)
+            .then_with(|| other.neuron_id.cmp(&self.neuron_id))
+    }
+}
+
+impl PartialOrd for Crossing {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Nach Zeitpunkt geordnete Vorhersagen der nächsten Spikes je Neuron
+///
+/// Jedes Neuron hat höchstens eine gültige Vorhersage. Wird sie erneuert, bleibt der
+/// alte Eintrag im Heap und wird beim Entnehmen anhand seiner Version verworfen.
+#[derive(Debug, Clone, Default)]
+pub struct ThresholdSchedule {
+    /// Vorhersagen, früheste zuerst (einschließlich überholter Einträge)
+    crossings: BinaryHeap<Crossing>,
+    /// Aktuelle Version der Vorhersage je Neuron
+    versions: HashMap<Uuid, u64>,
+}
+
+impl ThresholdSchedule {
+    /// Erstellt einen leeren Plan
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Ersetzt die Vorhersage eines Neurons; `None` entfernt sie
+    pub fn schedule(&mut self, neuron_id: Uuid, time: Option<f64>) {
+        let version = self.versions.entry(neuron_id).or_insert(0);
+        *version += 1;
+        if let Some(time) = time {
+            self.crossings.push(Crossing {
+                time,
+                neuron_id,
+                version: *version,
+            });
+        }
+    }
+
+    /// Gibt den Zeitpunkt der frühesten gültigen Vorhersage zurück
+    pub fn next_time(&mut self) -> Option<f64> {
+        self.discard_stale();
+        self.crossings.peek().map(|crossing| crossing.time)
+    }
+
+    /// Entnimmt alle gültigen Vorhersagen bis einschließlich `until`
+    ///
+    /// Die Neuronen werden nach Zeitpunkt und ID geordnet zurückgegeben.
+    pub fn pop_due(&mut self, until: f64) -> Vec<Uuid> {
+        let mut due = Vec::new();
+        while self.next_time().is_some_and(|time| time <= until) {
+            let crossing = self
+                .crossings
+                .pop()
+                .expect("next_time lieferte eine Vorhersage");
+            self.schedule(crossing.neuron_id, None);
+            due.push(crossing.neuron_id);
+        }
+        due
+    }
+
+    /// Gibt die Anzahl der gültigen Vorhersagen zurück
+    pub fn len(&self) -> usize {
+        self.crossings
+            .iter()
+            .filter(|crossing| self.is_current(crossing))
+            .count()
+    }
+
+    /// Prüft, ob keine gültige Vorhersage besteht
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Prüft, ob eine Vorhersage der aktuellen Version entspricht
+    fn is_current(&self, crossing: &Crossing) -> bool {
+        self.versions.get(&crossing.neuron_id) == Some(&crossing.version)
+    }
+
+    /// Verwirft überholte Einträge an der Spitze des Heaps
+    fn discard_stale(&mut self) {
+        while let Some(crossing) = self.crossings.peek() {
+            if self.is_current(crossing) {
+                break;
+            }
+            self.crossings.pop();
+        }
+    }
+}
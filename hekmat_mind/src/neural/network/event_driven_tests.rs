@@ -0,0 +1,291 @@
+use crate::neural::network::NetworkError;
+use crate::neural::network::event_driven::{SpikeEvent, ThresholdSchedule};
+use crate::neural::network::model::Network;
+use crate::neural::neuron::lif::LifNeuron;
+use crate::neural::neuron::model::Neuron;
+use crate::neural::neuron::traits::NeuronModel;
+use crate::neural::synapse::model::SynapseBuilder;
+use crate::neural::synapse::stdp::StdpRule;
+use rand::prelude::*;
+use rand::rngs::StdRng;
+use uuid::Uuid;
+
+/// Zeitschritt der taktgesteuerten Vergleichssimulation
+const DT: f32 = 0.001;
+
+/// Zufälliges rekurrentes LIF-Netzwerk mit Verzögerungen als Vielfache von `DT`
+fn recurrent_lif_network(size: usize, seed: u64) -> (Network, Vec<Uuid>) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut network = Network::new();
+    network.disable_plasticity();
+
+    let mut ids = Vec::new();
+    for _ in 0..size {
+        let neuron = LifNeuron::new();
+        ids.push(*neuron.id());
+        network.add_neuron(neuron);
+    }
+    ids.sort();
+
+    for pre in &ids {
+        for post in &ids {
+            if pre == post || !rng.gen_bool(0.2) {
+                continue;
+            }
+            let weight = *[0.3, 0.6, -0.4].choose(&mut rng).unwrap();
+            let delay = rng.gen_range(1..=4) as f32 * DT;
+            network.add_synapse(
+                SynapseBuilder::new()
+                    .with_pre_neuron_id(*pre)
+                    .with_post_neuron_id(*post)
+                    .with_weight(weight)
+                    .with_delay(delay)
+                    .build(),
+            );
+        }
+    }
+
+    (network, ids)
+}
+
+/// Sortiert Spikes nach Zeit (auf Mikrosekunden gerundet) und Neuron
+fn sorted(mut spikes: Vec<SpikeEvent>) -> Vec<SpikeEvent> {
+    spikes.sort_by_key(|spike| ((spike.time * 1e6).round() as i64, spike.neuron_id));
+    spikes
+}
+
+/// Simuliert eine Kopie des Netzwerks takt- und eine ereignisgesteuert mit denselben
+/// Stimuli (Zeitschritt, Neuron) und prüft, dass beide dieselben Spikes erzeugen
+fn assert_engines_agree(
+    mut clocked: Network,
+    ids: &[Uuid],
+    stimuli: &[(usize, Uuid)],
+    steps: usize,
+) -> Vec<SpikeEvent> {
+    let mut event_driven = clocked.clone();
+
+    // Taktgesteuert: Spikes werden beim Sammeln im folgenden Zyklus vermerkt,
+    // der letzte Zyklus sammelt also die Spikes bis `steps` Millisekunden
+    let mut clocked_spikes = Vec::new();
+    for step in 0..=steps {
+        for (_, id) in stimuli.iter().filter(|(time, _)| *time == step) {
+            clocked.stimulate_neuron(id, 1.0);
+        }
+        let time = clocked.time();
+        clocked.cycle(DT);
+        for id in ids {
+            if clocked.last_spike_time(id) == Some(time) {
+                clocked_spikes.push(SpikeEvent {
+                    time,
+                    neuron_id: *id,
+                });
+            }
+        }
+    }
+
+    // Ereignisgesteuert: eine Stimulation wirkt sofort, also einen Zeitschritt später anlegen
+    let mut event_spikes = Vec::new();
+    for (step, id) in stimuli {
+        let stimulus_time = (*step as f64 + 1.0) * DT as f64;
+        event_spikes.extend(
+            event_driven
+                .run_event_driven(stimulus_time - event_driven.time())
+                .unwrap(),
+        );
+        event_driven.stimulate_neuron(id, 1.0);
+    }
+    event_spikes.extend(
+        event_driven
+            .run_event_driven(clocked.time() - 0.5 * DT as f64 - event_driven.time())
+            .unwrap(),
+    );
+
+    let clocked_spikes = sorted(clocked_spikes);
+    let event_spikes = sorted(event_spikes);
+    assert_eq!(clocked_spikes.len(), event_spikes.len());
+    for (clocked, event) in clocked_spikes.iter().zip(&event_spikes) {
+        assert_eq!(clocked.neuron_id, event.neuron_id);
+        assert!(
+            (clocked.time - event.time).abs() < 1e-6,
+            "taktgesteuert {} s, ereignisgesteuert {} s",
+            clocked.time,
+            event.time
+        );
+    }
+    clocked_spikes
+}
+
+#[test]
+fn test_event_driven_matches_clock_driven_spike_trains() {
+    let (network, ids) = recurrent_lif_network(20, 7);
+
+    // Alle 10 ms werden wechselnde Eingangsneuronen stimuliert
+    let stimuli: Vec<(usize, Uuid)> = (0..20).map(|k| (k * 10, ids[(k * 3) % 5])).collect();
+
+    let spikes = assert_engines_agree(network, &ids, &stimuli, 220);
+    assert!(spikes.len() > stimuli.len());
+}
+
+#[test]
+fn test_event_driven_matches_clock_driven_under_strong_inhibition() {
+    let mut network = Network::new();
+    network.disable_plasticity();
+    let mut ids: Vec<Uuid> = (0..4)
+        .map(|_| {
+            let neuron = LifNeuron::new();
+            let id = *neuron.id();
+            network.add_neuron(neuron);
+            id
+        })
+        .collect();
+    ids.sort();
+    let (excitatory, target) = (ids[0], ids[3]);
+
+    // Zwei gleichzeitig eintreffende hemmende Signale summieren sich auf -0.8
+    for (pre, weight) in [(ids[0], 0.6), (ids[1], -0.4), (ids[2], -0.4)] {
+        network.add_synapse(
+            SynapseBuilder::new()
+                .with_pre_neuron_id(pre)
+                .with_post_neuron_id(target)
+                .with_weight(weight)
+                .with_delay(DT)
+                .build(),
+        );
+    }
+
+    let stimuli = vec![
+        // Erregung allein löst einen Spike aus
+        (0, excitatory),
+        // Gleichzeitige Hemmung hebt die Erregung auf
+        (10, excitatory),
+        (10, ids[1]),
+        (10, ids[2]),
+        // Vorangehende Hemmung senkt das Membranpotential unter die Schwelle
+        (20, ids[1]),
+        (20, ids[2]),
+        (21, excitatory),
+        // Hemmung während der Refraktärzeit bleibt wirkungslos
+        (50, excitatory),
+        (51, ids[1]),
+        (51, ids[2]),
+        (54, excitatory),
+    ];
+
+    let spikes = assert_engines_agree(network, &ids, &stimuli, 60);
+    let target_spikes: Vec<f64> = spikes
+        .iter()
+        .filter(|spike| spike.neuron_id == target)
+        .map(|spike| (spike.time * 1000.0).round())
+        .collect();
+    assert_eq!(target_spikes, vec![2.0, 52.0, 56.0]);
+}
+
+#[test]
+fn test_event_driven_predicts_spikes_under_constant_current() {
+    let mut network = Network::new();
+    let mut neuron = LifNeuron::new();
+    neuron.set_input_current(1.0);
+    let id = *neuron.id();
+    network.add_neuron(neuron);
+
+    let spikes = network.run_event_driven(0.1).unwrap();
+
+    // V_∞ = 1.0, θ = 0.5: der Schwellwert wird nach τ · ln 2 erreicht, danach
+    // kommt jeweils die Refraktärzeit hinzu
+    let tau = 0.02_f64;
+    let first = tau * 2.0_f64.ln();
+    let period = 0.002 + first;
+    assert_eq!(spikes.len(), 1 + ((0.1 - first) / period) as usize);
+    for (index, spike) in spikes.iter().enumerate() {
+        assert_eq!(spike.neuron_id, id);
+        let expected = first + index as f64 * period;
+        assert!((spike.time - expected).abs() < 1e-5);
+    }
+
+    assert!((network.time() - 0.1).abs() < 1e-12);
+    assert_eq!(
+        network.last_spike_time(&id),
+        Some(spikes.last().unwrap().time)
+    );
+}
+
+#[test]
+fn test_event_driven_leaves_quiet_networks_untouched() {
+    let (mut network, ids) = recurrent_lif_network(10, 3);
+    network.stimulate_neuron(&ids[0], 0.3);
+
+    // Ohne überschwellige Eingabe gibt es keine Ereignisse, nur den Leckstrom
+    let spikes = network.run_event_driven(0.02).unwrap();
+    assert!(spikes.is_empty());
+    let potential = network.get_neuron(&ids[0]).unwrap().activation_energy();
+    assert!((potential - 0.3 * (-1.0_f32).exp()).abs() < 1e-5);
+
+    // Taktgesteuerte Zyklen schließen an die neue Simulationszeit an
+    network.cycle(DT);
+    assert!((network.time() - 0.021).abs() < 1e-9);
+}
+
+#[test]
+fn test_event_driven_applies_stdp() {
+    let mut network = Network::new();
+    let pre = LifNeuron::new();
+    let post = LifNeuron::new();
+    let (pre_id, post_id) = (*pre.id(), *post.id());
+    network.add_neuron(pre);
+    network.add_neuron(post);
+    network.add_synapse(
+        SynapseBuilder::new()
+            .with_pre_neuron_id(pre_id)
+            .with_post_neuron_id(post_id)
+            .with_weight(0.6)
+            .with_delay(0.003)
+            .build(),
+    );
+    network.set_stdp(Some(StdpRule::pair()));
+
+    // Der präsynaptische Spike geht dem postsynaptischen voraus: Potenzierung
+    network.stimulate_neuron(&pre_id, 1.0);
+    let spikes = network.run_event_driven(0.01).unwrap();
+    assert_eq!(spikes.len(), 2);
+    assert_eq!(spikes[0].neuron_id, pre_id);
+    assert_eq!(spikes[1].neuron_id, post_id);
+    assert!((spikes[1].time - spikes[0].time - 0.003).abs() < 1e-6);
+    assert!(network.get_synapse(&pre_id, &post_id).unwrap().weight() > 0.6);
+}
+
+#[test]
+fn test_event_driven_rejects_unsupported_models() {
+    let mut network = Network::new();
+    let neuron = Neuron::new(100);
+    let id = *neuron.id();
+    network.add_neuron(neuron);
+
+    assert_eq!(
+        network.run_event_driven(0.01),
+        Err(NetworkError::UnsupportedModel {
+            neuron: id,
+            model: "neuron",
+        })
+    );
+    assert_eq!(network.time(), 0.0);
+}
+
+#[test]
+fn test_threshold_schedule_discards_stale_predictions() {
+    let (a, b) = (Uuid::new_v4(), Uuid::new_v4());
+    let mut schedule = ThresholdSchedule::new();
+    schedule.schedule(a, Some(0.002));
+    schedule.schedule(b, Some(0.003));
+    assert_eq!(schedule.len(), 2);
+
+    // Eine neue Vorhersage ersetzt die alte, `None` entfernt sie
+    schedule.schedule(a, Some(0.005));
+    assert_eq!(schedule.next_time(), Some(0.003));
+    schedule.schedule(b, None);
+    assert_eq!(schedule.next_time(), Some(0.005));
+    assert_eq!(schedule.len(), 1);
+
+    assert!(schedule.pop_due(0.004).is_empty());
+    assert_eq!(schedule.pop_due(0.005), vec![a]);
+    assert!(schedule.is_empty());
+}
@@ -0,0 +1,144 @@
+//! Sequentielle und parallele Ausführung der Zyklusphasen
+//!
+//! Im parallelen Modus werden Neuronen und Synapsen auf Worker-Threads verteilt.
+//! Jede Phase ist zweigeteilt: Zuerst werden alle benötigten Werte gelesen und
+//! in fester Reihenfolge zusammengefasst, danach schreibt jeder Thread nur in die
+//! Elemente, die ihm zugeteilt sind. Da kein Element von mehreren Threads verändert
+//! wird und Summen immer in derselben Reihenfolge gebildet werden, stimmen die
+//! Ergebnisse beider Modi bitgenau überein.
+
+use rayon::prelude::*;
+use rayon::{ThreadPool, ThreadPoolBuilder};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::Hash;
+use std::sync::Arc;
+
+/// Ausführungsmodus eines Netzwerks
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ExecutionMode {
+    /// Alle Phasen laufen im aufrufenden Thread
+    #[default]
+    Sequential,
+    /// Neuronen- und Synapsenupdates werden auf Worker-Threads verteilt
+    Parallel {
+        /// Anzahl der Worker-Threads (0 = globaler Thread-Pool mit allen Kernen)
+        threads: usize,
+    },
+}
+
+impl ExecutionMode {
+    /// Paralleler Modus mit dem globalen Thread-Pool
+    pub fn parallel() -> Self {
+        ExecutionMode::Parallel { threads: 0 }
+    }
+
+    /// Gibt an, ob der Modus Worker-Threads verwendet
+    pub fn is_parallel(&self) -> bool {
+        matches!(self, ExecutionMode::Parallel { .. })
+    }
+}
+
+/// Ausführungsumgebung eines Netzwerks: Modus und gegebenenfalls eigener Thread-Pool
+///
+/// Der Thread-Pool wird nicht serialisiert, sondern beim ersten parallelen Zyklus
+/// nach dem Laden neu erstellt.
+#[derive(Clone, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Executor {
+    /// Gewählter Ausführungsmodus
+    mode: ExecutionMode,
+    /// Eigener Thread-Pool bei fester Threadanzahl
+    #[serde(skip)]
+    pool: Option<Arc<ThreadPool>>,
+}
+
+impl fmt::Debug for Executor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Executor")
+            .field("mode", &self.mode)
+            .finish()
+    }
+}
+
+impl Executor {
+    /// Erstellt eine Ausführungsumgebung für den angegebenen Modus
+    pub fn new(mode: ExecutionMode) -> Self {
+        let mut executor = Self { mode, pool: None };
+        executor.ensure_pool();
+        executor
+    }
+
+    /// Gibt den Ausführungsmodus zurück
+    pub fn mode(&self) -> ExecutionMode {
+        self.mode
+    }
+
+    /// Erstellt den eigenen Thread-Pool, falls der Modus einen verlangt
+    ///
+    /// Kann der Pool nicht erstellt werden, wird der globale Pool verwendet.
+    pub fn ensure_pool(&mut self) {
+        if let ExecutionMode::Parallel { threads } = self.mode
+            && threads > 0
+            && self.pool.is_none()
+        {
+            self.pool = ThreadPoolBuilder::new()
+                .num_threads(threads)
+                .build()
+                .ok()
+                .map(Arc::new);
+        }
+    }
+
+    /// Führt `operation` im Thread-Pool des Netzwerks aus
+    fn install<OP: FnOnce() + Send>(&self, operation: OP) {
+        match &self.pool {
+            Some(pool) => pool.install(operation),
+            None => operation(),
+        }
+    }
+
+    /// Ruft `f` für jeden Eintrag einer Tabelle auf, im parallelen Modus verteilt
+    ///
+    /// `f` darf nur den übergebenen Eintrag verändern.
+    pub fn for_each_mut<K, V, F>(&self, map: &mut HashMap<K, V>, f: F)
+    where
+        K: Eq + Hash + Send + Sync,
+        V: Send,
+        F: Fn(&K, &mut V) + Sync + Send,
+    {
+        if self.mode.is_parallel() {
+            self.install(|| map.par_iter_mut().for_each(|(key, value)| f(key, value)));
+        } else {
+            map.iter_mut().for_each(|(key, value)| f(key, value));
+        }
+    }
+
+    /// Sammelt die Schlüssel aller Einträge, für die `predicate` zutrifft, aufsteigend sortiert
+    pub fn filter_keys<K, V, P>(&self, map: &HashMap<K, V>, predicate: P) -> Vec<K>
+    where
+        K: Eq + Hash + Ord + Copy + Send + Sync,
+        V: Sync,
+        P: Fn(&V) -> bool + Sync + Send,
+    {
+        let mut keys: Vec<K> = if self.mode.is_parallel() {
+            let mut keys = Vec::new();
+            self.install(|| {
+                keys = map
+                    .par_iter()
+                    .filter(|(_, value)| predicate(value))
+                    .map(|(key, _)| *key)
+                    .collect();
+            });
+            keys
+        } else {
+            map.iter()
+                .filter(|(_, value)| predicate(value))
+                .map(|(key, _)| *key)
+                .collect()
+        };
+        keys.sort_unstable();
+        keys
+    }
+}
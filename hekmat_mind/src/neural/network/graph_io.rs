@@ -0,0 +1,456 @@
+//! Austausch von Netzwerktopologien mit Graphwerkzeugen
+//!
+//! Netzwerke lassen sich als GraphML (z.B. für Gephi, yEd, Cytoscape oder NetworkX)
+//! und als Graphviz-DOT exportieren. Neuronen werden zu Knoten mit Modell,
+//! Geschwindigkeit, Schwellwert und Position, Synapsen zu gerichteten Kanten mit
+//! Gewicht, Verzögerung und Typ. Ein [`GraphFilter`] beschränkt den Export auf
+//! starke Synapsen oder eine Teilmenge der Neuronen.
+//!
+//! Der GraphML-Import liest diese Attribute wieder ein, sodass kleine Schaltkreise
+//! von Hand bearbeitet werden können. Exportiert wird nur die Topologie; für den
+//! vollständigen Zustand eines Netzwerks gibt es die Snapshots aus
+//! [`persistence`](crate::neural::network::persistence).
+
+use crate::neural::growth::Position;
+use crate::neural::network::model::Network;
+use crate::neural::network::{NetworkError, NetworkResult};
+use crate::neural::neuron::hodgkin_huxley::HodgkinHuxleyNeuron;
+use crate::neural::neuron::izhikevich::{IzhikevichNeuron, IzhikevichPreset};
+use crate::neural::neuron::lif::{LifNeuron, LifParams};
+use crate::neural::neuron::model::Neuron;
+use crate::neural::neuron::model::constants as neuron_constants;
+use crate::neural::neuron::traits::NeuronModel;
+use crate::neural::synapse::model::{Synapse, SynapseType};
+use sha2::{Digest, Sha256};
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write;
+use uuid::Uuid;
+
+/// Geschwindigkeit importierter Standardneuronen ohne `speed`-Attribut
+const DEFAULT_IMPORT_SPEED: u16 = 100;
+
+/// Toleranz beim Vergleich eines importierten mit einem festen Schwellwert
+const THRESHOLD_TOLERANCE: f32 = 1e-6;
+
+/// Auswahl der Neuronen und Synapsen für einen Export
+#[derive(Debug, Clone, Default)]
+pub struct GraphFilter {
+    /// Mindestbetrag des Gewichts exportierter Synapsen
+    min_weight: Option<f32>,
+    /// Exportierte Neuronen (`None` = alle)
+    neurons: Option<HashSet<Uuid>>,
+}
+
+impl GraphFilter {
+    /// Erstellt einen Filter, der alle Neuronen und Synapsen exportiert
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Exportiert nur Synapsen, deren Gewicht im Betrag mindestens `min_weight` ist
+    pub fn with_min_weight(mut self, min_weight: f32) -> Self {
+        self.min_weight = Some(min_weight);
+        self
+    }
+
+    /// Exportiert nur die angegebenen Neuronen und die Synapsen zwischen ihnen
+    pub fn with_neurons<I: IntoIterator<Item = Uuid>>(mut self, neuron_ids: I) -> Self {
+        self.neurons = Some(neuron_ids.into_iter().collect());
+        self
+    }
+
+    /// Prüft, ob ein Neuron exportiert wird
+    pub fn includes_neuron(&self, neuron_id: &Uuid) -> bool {
+        self.neurons
+            .as_ref()
+            .is_none_or(|neurons| neurons.contains(neuron_id))
+    }
+
+    /// Prüft, ob eine Synapse exportiert wird
+    pub fn includes_synapse(&self, synapse: &Synapse) -> bool {
+        self.includes_neuron(synapse.pre_neuron_id())
+            && self.includes_neuron(synapse.post_neuron_id())
+            && self
+                .min_weight
+                .is_none_or(|min_weight| synapse.weight().abs() >= min_weight)
+    }
+}
+
+/// Exportierte Attribute eines Neurons
+struct NodeAttributes {
+    id: Uuid,
+    model: &'static str,
+    speed: Option<u16>,
+    threshold: f32,
+    position: Position,
+}
+
+impl Network {
+    /// Exportiert die Topologie als GraphML-Dokument
+    pub fn to_graphml(&self, filter: &GraphFilter) -> String {
+        let mut xml = String::new();
+        xml.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        xml.push_str("<graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\">\n");
+        for (id, domain, kind) in [
+            ("model", "node", "string"),
+            ("speed", "node", "int"),
+            ("threshold", "node", "double"),
+            ("x", "node", "double"),
+            ("y", "node", "double"),
+            ("z", "node", "double"),
+            ("weight", "edge", "double"),
+            ("delay", "edge", "double"),
+            ("type", "edge", "string"),
+        ] {
+            let _ = writeln!(
+                xml,
+                "  <key id=\"{id}\" for=\"{domain}\" attr.name=\"{id}\" attr.type=\"{kind}\"/>"
+            );
+        }
+        xml.push_str("  <graph id=\"network\" edgedefault=\"directed\">\n");
+
+        for node in self.exported_nodes(filter) {
+            let _ = writeln!(xml, "    <node id=\"{}\">", node.id);
+            let _ = writeln!(xml, "      <data key=\"model\">{}</data>", node.model);
+            if let Some(speed) = node.speed {
+                let _ = writeln!(xml, "      <data key=\"speed\">{speed}</data>");
+            }
+            let _ = writeln!(
+                xml,
+                "      <data key=\"threshold\">{}</data>",
+                node.threshold
+            );
+            let Position { x, y, z } = node.position;
+            let _ = writeln!(xml, "      <data key=\"x\">{x}</data>");
+            let _ = writeln!(xml, "      <data key=\"y\">{y}</data>");
+            let _ = writeln!(xml, "      <data key=\"z\">{z}</data>");
+            xml.push_str("    </node>\n");
+        }
+
+        for synapse in self.exported_synapses(filter) {
+            let _ = writeln!(
+                xml,
+                "    <edge source=\"{}\" target=\"{}\">",
+                synapse.pre_neuron_id(),
+                synapse.post_neuron_id()
+            );
+            let _ = writeln!(
+                xml,
+                "      <data key=\"weight\">{}</data>",
+                synapse.weight()
+            );
+            let _ = writeln!(xml, "      <data key=\"delay\">{}</data>", synapse.delay());
+            let _ = writeln!(
+                xml,
+                "      <data key=\"type\">{}</data>",
+                type_name(synapse.synapse_type())
+            );
+            xml.push_str("    </edge>\n");
+        }
+
+        xml.push_str("  </graph>\n</graphml>\n");
+        xml
+    }
+
+    /// Exportiert die Topologie als gerichteten Graphen im Graphviz-DOT-Format
+    ///
+    /// Die Positionen werden als `pos` (x, y) übergeben, sodass `neato -n` die
+    /// räumliche Anordnung übernimmt. Hemmende Synapsen sind rot, modulierende blau.
+    pub fn to_dot(&self, filter: &GraphFilter) -> String {
+        let mut dot = String::from("digraph network {\n    node [shape=circle];\n");
+
+        for node in self.exported_nodes(filter) {
+            let Position { x, y, z } = node.position;
+            let label = &node.id.to_string()[..8];
+            let _ = write!(
+                dot,
+                "    \"{}\" [label=\"{label}\", model=\"{}\", threshold={}, x={x}, y={y}, z={z}, pos=\"{x},{y}\"",
+                node.id, node.model, node.threshold
+            );
+            if let Some(speed) = node.speed {
+                let _ = write!(dot, ", speed={speed}");
+            }
+            dot.push_str("];\n");
+        }
+
+        for synapse in self.exported_synapses(filter) {
+            let color = match synapse.synapse_type() {
+                SynapseType::Excitatory => "black",
+                SynapseType::Inhibitory => "red",
+                SynapseType::Modulatory => "blue",
+            };
+            let _ = writeln!(
+                dot,
+                "    \"{}\" -> \"{}\" [weight={}, delay={}, type=\"{}\", color=\"{color}\", penwidth={}];",
+                synapse.pre_neuron_id(),
+                synapse.post_neuron_id(),
+                synapse.weight(),
+                synapse.delay(),
+                type_name(synapse.synapse_type()),
+                0.5 + 2.5 * synapse.weight().abs()
+            );
+        }
+
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// Erstellt ein Netzwerk aus einem GraphML-Dokument
+    ///
+    /// Knoten werden zu Neuronen des Modells im Attribut `model` (`neuron`, `lif`,
+    /// `izhikevich`, `hodgkin_huxley`; Standard `neuron`) mit Schwellwert,
+    /// Geschwindigkeit und Position, Kanten zu Synapsen mit Gewicht, Verzögerung und
+    /// Typ. Fehlende Attribute erhalten Standardwerte, ein fehlender Typ richtet sich
+    /// nach dem Vorzeichen des Gewichts. Knoten-IDs, die keine UUID sind (z.B. `n0`
+    /// in handgeschriebenen Dateien), erhalten eine daraus abgeleitete UUID, sodass
+    /// wiederholte Importe dieselben IDs ergeben. Doppelte Knoten werden abgelehnt.
+    ///
+    /// Izhikevich- und Hodgkin-Huxley-Neuronen haben einen festen Schwellwert; ein
+    /// davon abweichendes `threshold` am Knoten wird abgelehnt, ein Standardwert des
+    /// Schlüssels gilt nur für die übrigen Modelle.
+    pub fn from_graphml(document: &str) -> NetworkResult<Network> {
+        let document = roxmltree::Document::parse(document)
+            .map_err(|error| NetworkError::InvalidGraphMl(error.to_string()))?;
+        let root = document.root_element();
+        if !root.has_tag_name("graphml") {
+            return Err(NetworkError::InvalidGraphMl(
+                "Wurzelelement <graphml> fehlt".to_string(),
+            ));
+        }
+        let graph = root
+            .children()
+            .find(|child| child.has_tag_name("graph"))
+            .ok_or_else(|| NetworkError::InvalidGraphMl("Element <graph> fehlt".to_string()))?;
+
+        // Schlüssel-IDs auf Attributnamen und Standardwerte abbilden
+        let mut keys: HashMap<&str, &str> = HashMap::new();
+        let mut defaults: HashMap<(&str, &str), String> = HashMap::new();
+        for key in root.children().filter(|child| child.has_tag_name("key")) {
+            let (Some(id), Some(name)) = (key.attribute("id"), key.attribute("attr.name")) else {
+                continue;
+            };
+            keys.insert(id, name);
+            let domain = key.attribute("for").unwrap_or("all");
+            if let Some(default) = key
+                .children()
+                .find(|child| child.has_tag_name("default"))
+                .and_then(|default| default.text())
+            {
+                defaults.insert((domain, name), default.trim().to_string());
+            }
+        }
+        let attributes = |element: roxmltree::Node, domain: &str| {
+            let mut values: HashMap<String, String> = defaults
+                .iter()
+                .filter(|((key_domain, _), _)| *key_domain == domain || *key_domain == "all")
+                .map(|((_, name), value)| (name.to_string(), value.clone()))
+                .collect();
+            for data in element
+                .children()
+                .filter(|child| child.has_tag_name("data"))
+            {
+                if let Some(key) = data.attribute("key") {
+                    let name = keys.get(key).copied().unwrap_or(key);
+                    values.insert(
+                        name.to_string(),
+                        data.text().unwrap_or("").trim().to_string(),
+                    );
+                }
+            }
+            values
+        };
+
+        let mut network = Network::new();
+        let mut node_ids: HashMap<&str, Uuid> = HashMap::new();
+        for node in graph.children().filter(|child| child.has_tag_name("node")) {
+            let name = node
+                .attribute("id")
+                .ok_or_else(|| NetworkError::InvalidGraphMl("Knoten ohne ID".to_string()))?;
+            let id = node_uuid(name);
+            if network.get_neuron(&id).is_some() {
+                return Err(NetworkError::InvalidGraphMl(format!(
+                    "Knoten '{}' ist doppelt",
+                    name
+                )));
+            }
+            let values = attributes(node, "node");
+            let explicit_threshold = node
+                .children()
+                .filter(|child| child.has_tag_name("data"))
+                .filter_map(|data| data.attribute("key"))
+                .any(|key| keys.get(key).copied().unwrap_or(key) == "threshold");
+            let mut neuron = build_neuron(id, &values, explicit_threshold)?;
+            neuron.set_position(Position::new(
+                parse_number(&values, "x")?.unwrap_or(0.0),
+                parse_number(&values, "y")?.unwrap_or(0.0),
+                parse_number(&values, "z")?.unwrap_or(0.0),
+            ));
+            node_ids.insert(name, id);
+            network.add_boxed_neuron(neuron);
+        }
+
+        for edge in graph.children().filter(|child| child.has_tag_name("edge")) {
+            let endpoint = |attribute: &str| {
+                let name = edge.attribute(attribute).unwrap_or("");
+                node_ids.get(name).copied().ok_or_else(|| {
+                    NetworkError::InvalidGraphMl(format!(
+                        "Kante verweist auf unbekannten Knoten '{}'",
+                        name
+                    ))
+                })
+            };
+            let (pre_id, post_id) = (endpoint("source")?, endpoint("target")?);
+            let values = attributes(edge, "edge");
+
+            let weight = parse_number(&values, "weight")?.unwrap_or(0.5);
+            let synapse_type = match values.get("type").map(String::as_str) {
+                None | Some("") => SynapseType::from_weight(weight),
+                Some(name) => parse_type(name)?,
+            };
+            let mut synapse = Synapse::with_type(pre_id, post_id, weight, synapse_type);
+            if let Some(delay) = parse_number(&values, "delay")? {
+                synapse.set_delay(delay);
+            }
+            network.try_add_synapse(synapse)?;
+        }
+
+        Ok(network)
+    }
+
+    /// Exportierte Neuronen, nach ID sortiert
+    fn exported_nodes(&self, filter: &GraphFilter) -> Vec<NodeAttributes> {
+        let mut nodes: Vec<NodeAttributes> = self
+            .neurons()
+            .values()
+            .filter(|neuron| filter.includes_neuron(neuron.id()))
+            .map(|neuron| NodeAttributes {
+                id: *neuron.id(),
+                model: neuron.model_name(),
+                speed: neuron
+                    .as_any()
+                    .downcast_ref::<Neuron>()
+                    .map(|neuron| neuron.speed()),
+                threshold: neuron.threshold(),
+                position: *neuron.position(),
+            })
+            .collect();
+        nodes.sort_unstable_by_key(|node| node.id);
+        nodes
+    }
+
+    /// Exportierte Synapsen, nach Schlüssel sortiert
+    fn exported_synapses(&self, filter: &GraphFilter) -> Vec<&Synapse> {
+        let mut synapses: Vec<&Synapse> = self
+            .synapses()
+            .values()
+            .filter(|synapse| filter.includes_synapse(synapse))
+            .collect();
+        synapses
+            .sort_unstable_by_key(|synapse| (*synapse.pre_neuron_id(), *synapse.post_neuron_id()));
+        synapses
+    }
+}
+
+/// Name eines Synapsentyps in Exporten
+fn type_name(synapse_type: SynapseType) -> &'static str {
+    match synapse_type {
+        SynapseType::Excitatory => "excitatory",
+        SynapseType::Inhibitory => "inhibitory",
+        SynapseType::Modulatory => "modulatory",
+    }
+}
+
+/// Liest einen Synapsentyp aus seinem Exportnamen
+fn parse_type(name: &str) -> NetworkResult<SynapseType> {
+    match name.to_ascii_lowercase().as_str() {
+        "excitatory" => Ok(SynapseType::Excitatory),
+        "inhibitory" => Ok(SynapseType::Inhibitory),
+        "modulatory" => Ok(SynapseType::Modulatory),
+        _ => Err(NetworkError::InvalidGraphMl(format!(
+            "Unbekannter Synapsentyp '{}'",
+            name
+        ))),
+    }
+}
+
+/// Liest ein optionales Zahlenattribut
+fn parse_number<T: std::str::FromStr>(
+    values: &HashMap<String, String>,
+    name: &str,
+) -> NetworkResult<Option<T>> {
+    match values.get(name) {
+        None => Ok(None),
+        Some(value) if value.is_empty() => Ok(None),
+        Some(value) => value.parse().map(Some).map_err(|_| {
+            NetworkError::InvalidGraphMl(format!("Ungültiger Wert '{}' für '{}'", value, name))
+        }),
+    }
+}
+
+/// Gibt die UUID eines Knotens zurück
+///
+/// Knoten-IDs, die keine UUID sind, erhalten eine UUID (Version 8) aus den ersten
+/// 16 Bytes ihres SHA-256-Hashes.
+fn node_uuid(name: &str) -> Uuid {
+    Uuid::parse_str(name).unwrap_or_else(|_| {
+        let digest = Sha256::digest(name.as_bytes());
+        let mut bytes = [0; 16];
+        bytes.copy_from_slice(&digest[..16]);
+        uuid::Builder::from_custom_bytes(bytes).into_uuid()
+    })
+}
+
+/// Erzeugt ein Neuron des im Attribut `model` angegebenen Modells
+///
+/// `explicit_threshold` gibt an, ob der Knoten selbst einen Schwellwert angibt.
+fn build_neuron(
+    id: Uuid,
+    values: &HashMap<String, String>,
+    explicit_threshold: bool,
+) -> NetworkResult<Box<dyn NeuronModel>> {
+    let given_threshold: Option<f32> = parse_number(values, "threshold")?;
+    let threshold = given_threshold.unwrap_or(neuron_constants::DEFAULT_THRESHOLD);
+    let model = values.get("model").map_or("neuron", String::as_str);
+
+    let neuron: Box<dyn NeuronModel> = match model {
+        "neuron" | "" => {
+            let speed = parse_number(values, "speed")?.unwrap_or(DEFAULT_IMPORT_SPEED);
+            Box::new(
+                Neuron::with_params(speed, threshold, neuron_constants::DEFAULT_PLASTICITY_RATE)
+                    .with_id(id),
+            )
+        }
+        "lif" => Box::new(
+            LifNeuron::with_params(
+                LifParams::default(),
+                threshold,
+                neuron_constants::DEFAULT_PLASTICITY_RATE,
+            )
+            .with_id(id),
+        ),
+        "izhikevich" => {
+            Box::new(IzhikevichNeuron::new(IzhikevichPreset::RegularSpiking).with_id(id))
+        }
+        "hodgkin_huxley" => Box::new(HodgkinHuxleyNeuron::new().with_id(id)),
+        _ => {
+            return Err(NetworkError::InvalidGraphMl(format!(
+                "Unbekanntes Neuronenmodell '{}'",
+                model
+            )));
+        }
+    };
+
+    // Modelle mit festem Schwellwert übernehmen keinen abweichenden Wert
+    if explicit_threshold
+        && let Some(threshold) = given_threshold
+        && (threshold - neuron.threshold()).abs() > THRESHOLD_TOLERANCE
+    {
+        return Err(NetworkError::InvalidGraphMl(format!(
+            "Modell '{}' hat den festen Schwellwert {}, angegeben ist {}",
+            model,
+            neuron.threshold(),
+            threshold
+        )));
+    }
+    Ok(neuron)
+}
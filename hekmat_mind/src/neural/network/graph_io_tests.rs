@@ -0,0 +1,220 @@
+use crate::neural::growth::Position;
+use crate::neural::network::NetworkError;
+use crate::neural::network::graph_io::GraphFilter;
+use crate::neural::network::model::Network;
+use crate::neural::neuron::lif::LifNeuron;
+use crate::neural::neuron::model::Neuron;
+use crate::neural::neuron::traits::NeuronModel;
+use crate::neural::synapse::model::{Synapse, SynapseType};
+use uuid::Uuid;
+
+/// Kleiner Schaltkreis aus drei Neuronen mit unterschiedlichen Synapsentypen
+fn circuit() -> (Network, Vec<Uuid>) {
+    let mut network = Network::new();
+    let mut first = Neuron::with_params(120, 0.4, 0.01);
+    first.set_position(Position::new(1.0, 2.0, 3.0));
+    let mut second = LifNeuron::new();
+    second.set_position(Position::new(-1.5, 0.0, 0.5));
+    let third = Neuron::new(80);
+    let ids = vec![*first.id(), *second.id(), *third.id()];
+    network.add_neuron(first);
+    network.add_neuron(second);
+    network.add_neuron(third);
+
+    let mut strong = Synapse::new(ids[0], ids[1], 0.8);
+    strong.set_delay(0.004);
+    network.add_synapse(strong);
+    network.add_synapse(Synapse::with_type(
+        ids[1],
+        ids[2],
+        -0.6,
+        SynapseType::Inhibitory,
+    ));
+    network.add_synapse(Synapse::with_type(
+        ids[2],
+        ids[0],
+        0.1,
+        SynapseType::Modulatory,
+    ));
+    (network, ids)
+}
+
+#[test]
+fn test_graphml_roundtrip_preserves_topology() {
+    let (network, ids) = circuit();
+    let graphml = network.to_graphml(&GraphFilter::new());
+    let imported = Network::from_graphml(&graphml).unwrap();
+
+    assert_eq!(imported.neuron_count(), 3);
+    assert_eq!(imported.synapse_count(), 3);
+    for id in &ids {
+        let original = network.get_neuron(id).unwrap();
+        let restored = imported.get_neuron(id).unwrap();
+        assert_eq!(restored.model_name(), original.model_name());
+        assert_eq!(restored.threshold(), original.threshold());
+        assert_eq!(restored.position(), original.position());
+    }
+    assert_eq!(
+        imported.get_neuron_as::<Neuron>(&ids[0]).unwrap().speed(),
+        120
+    );
+
+    for synapse in network.synapses().values() {
+        let restored = imported
+            .get_synapse(synapse.pre_neuron_id(), synapse.post_neuron_id())
+            .unwrap();
+        assert_eq!(restored.weight(), synapse.weight());
+        assert_eq!(restored.delay(), synapse.delay());
+        assert_eq!(restored.synapse_type(), synapse.synapse_type());
+    }
+
+    // Der Export ist unabhängig von der internen Reihenfolge
+    assert_eq!(imported.to_graphml(&GraphFilter::new()), graphml);
+}
+
+#[test]
+fn test_export_filters_by_weight_and_neurons() {
+    let (network, ids) = circuit();
+
+    let strong = network.to_graphml(&GraphFilter::new().with_min_weight(0.5));
+    assert_eq!(strong.matches("<node ").count(), 3);
+    assert_eq!(strong.matches("<edge ").count(), 2);
+    assert!(!strong.contains("modulatory"));
+
+    let subset = network.to_graphml(&GraphFilter::new().with_neurons([ids[0], ids[1]]));
+    assert_eq!(subset.matches("<node ").count(), 2);
+    assert_eq!(subset.matches("<edge ").count(), 1);
+    assert!(!subset.contains(&ids[2].to_string()));
+
+    let imported = Network::from_graphml(&subset).unwrap();
+    assert!(imported.get_synapse(&ids[0], &ids[1]).is_some());
+}
+
+#[test]
+fn test_dot_export_contains_nodes_and_edges() {
+    let (network, ids) = circuit();
+    let dot = network.to_dot(&GraphFilter::new());
+
+    assert!(dot.starts_with("digraph network {"));
+    assert!(dot.trim_end().ends_with('}'));
+    assert!(dot.contains(&format!(
+        "\"{}\" [label=\"{}\"",
+        ids[0],
+        &ids[0].to_string()[..8]
+    )));
+    assert!(dot.contains("speed=120"));
+    assert!(dot.contains("pos=\"1,2\""));
+    assert!(dot.contains(&format!(
+        "\"{}\" -> \"{}\" [weight=0.8, delay=0.004, type=\"excitatory\"",
+        ids[0], ids[1]
+    )));
+    assert!(dot.contains("color=\"red\""));
+    assert_eq!(dot.matches(" -> ").count(), 3);
+
+    let filtered = network.to_dot(&GraphFilter::new().with_min_weight(0.7));
+    assert_eq!(filtered.matches(" -> ").count(), 1);
+}
+
+#[test]
+fn test_import_handwritten_graphml() {
+    let document = r#"<?xml version="1.0" encoding="UTF-8"?>
+<graphml xmlns="http://graphml.graphdrawing.org/xmlns">
+  <key id="d0" for="node" attr.name="threshold" attr.type="double">
+    <default>0.3</default>
+  </key>
+  <key id="d1" for="node" attr.name="model" attr.type="string"/>
+  <key id="d2" for="edge" attr.name="weight" attr.type="double"/>
+  <key id="d3" for="node" attr.name="x" attr.type="double"/>
+  <graph id="G" edgedefault="directed">
+    <node id="n0"><data key="d3">4.5</data></node>
+    <node id="n1"><data key="d0">0.7</data><data key="d1">lif</data></node>
+    <node id="n2"><data key="d1">izhikevich</data></node>
+    <edge source="n0" target="n1"><data key="d2">-0.4</data></edge>
+  </graph>
+</graphml>"#;
+
+    let network = Network::from_graphml(document).unwrap();
+    assert_eq!(network.neuron_count(), 3);
+    assert_eq!(network.synapse_count(), 1);
+
+    let mut neurons: Vec<_> = network.neurons().values().collect();
+    neurons.sort_by_key(|neuron| neuron.model_name());
+    let (izhikevich, lif, standard) = (neurons[0], neurons[1], neurons[2]);
+    // Der Standardwert des Schwellwerts gilt nicht für Modelle mit festem Schwellwert
+    assert_eq!(izhikevich.threshold(), 30.0);
+    assert_eq!(lif.model_name(), "lif");
+    assert_eq!(lif.threshold(), 0.7);
+    assert_eq!(standard.model_name(), "neuron");
+    assert_eq!(standard.threshold(), 0.3);
+    assert_eq!(standard.position().x, 4.5);
+
+    // Ohne Typattribut entscheidet das Vorzeichen des Gewichts
+    let synapse = network.get_synapse(standard.id(), lif.id()).unwrap();
+    assert_eq!(synapse.weight(), -0.4);
+    assert_eq!(synapse.synapse_type(), SynapseType::Inhibitory);
+
+    // Wiederholte Importe leiten dieselben UUIDs aus den Knoten-IDs ab
+    let mut ids: Vec<Uuid> = network.neurons().keys().copied().collect();
+    let mut again: Vec<Uuid> = Network::from_graphml(document)
+        .unwrap()
+        .neurons()
+        .keys()
+        .copied()
+        .collect();
+    ids.sort();
+    again.sort();
+    assert_eq!(ids, again);
+}
+
+#[test]
+fn test_import_rejects_invalid_documents() {
+    let invalid = |document: &str| {
+        matches!(
+            Network::from_graphml(document),
+            Err(NetworkError::InvalidGraphMl(_))
+        )
+    };
+
+    assert!(invalid("kein XML"));
+    assert!(invalid("<graph/>"));
+    assert!(invalid("<graphml/>"));
+    assert!(invalid(
+        r#"<graphml><graph><node id="a"/><edge source="a" target="b"/></graph></graphml>"#
+    ));
+    assert!(invalid(
+        r#"<graphml><key id="m" for="node" attr.name="model"/>
+        <graph><node id="a"><data key="m">unbekannt</data></node></graph></graphml>"#
+    ));
+    assert!(invalid(
+        r#"<graphml><key id="t" for="node" attr.name="threshold"/>
+        <graph><node id="a"><data key="t">hoch</data></node></graph></graphml>"#
+    ));
+
+    // Doppelte Knoten, auch in unterschiedlicher Schreibweise derselben UUID
+    assert!(invalid(
+        r#"<graphml><graph><node id="a"/><node id="a"/></graph></graphml>"#
+    ));
+    let id = Uuid::new_v4();
+    assert!(invalid(&format!(
+        r#"<graphml><graph><node id="{}"/><node id="{}"/></graph></graphml>"#,
+        id,
+        id.simple()
+    )));
+
+    // Ein abweichender Schwellwert für Modelle mit festem Schwellwert
+    for (model, threshold, valid) in [
+        ("izhikevich", "0.5", false),
+        ("hodgkin_huxley", "-20", false),
+        ("izhikevich", "30", true),
+        ("hodgkin_huxley", "0", true),
+    ] {
+        let document = format!(
+            r#"<graphml><key id="m" for="node" attr.name="model"/>
+            <key id="t" for="node" attr.name="threshold"/>
+            <graph><node id="a"><data key="m">{}</data><data key="t">{}</data></node>
+            </graph></graphml>"#,
+            model, threshold
+        );
+        assert_eq!(invalid(&document), !valid, "{} {}", model, threshold);
+    }
+}
@@ -1,3 +1,145 @@
+//! Netzwerk-Modul: Verknüpfung von Neuronen und Synapsen zu einem Netzwerk
+
+use crate::neural::synapse::model::SynapseType;
+use thiserror::Error;
+use uuid::Uuid;
+
+pub mod adjacency;
+pub mod analysis;
+#[cfg(test)]
+mod analysis_tests;
+pub mod decoding;
+#[cfg(test)]
+mod decoding_tests;
+pub mod delay;
+pub mod encoding;
+#[cfg(test)]
+mod encoding_tests;
+pub mod event_driven;
+#[cfg(test)]
+mod event_driven_tests;
+pub mod execution;
+pub mod graph_io;
+#[cfg(test)]
+mod graph_io_tests;
 pub mod model;
+pub mod monitor;
+#[cfg(test)]
+mod monitor_tests;
+pub mod persistence;
+#[cfg(test)]
+mod persistence_tests;
+pub mod population;
+#[cfg(test)]
+mod population_tests;
+pub mod reservoir;
+#[cfg(test)]
+mod reservoir_tests;
+pub mod spatial;
+#[cfg(test)]
+mod spatial_tests;
 #[cfg(test)]
 pub mod tests;
+pub mod topology;
+#[cfg(test)]
+mod topology_tests;
+
+/// Fehler, die beim Aufbau oder Verändern eines Netzwerks auftreten können
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum NetworkError {
+    /// Ein referenziertes Neuron existiert nicht im Netzwerk
+    #[error("Neuron nicht gefunden: {0}")]
+    NeuronNotFound(Uuid),
+
+    /// Eine referenzierte Synapse existiert nicht im Netzwerk
+    #[error("Synapse nicht gefunden: {pre} -> {post}")]
+    SynapseNotFound {
+        /// Präsynaptisches Neuron
+        pre: Uuid,
+        /// Postsynaptisches Neuron
+        post: Uuid,
+    },
+
+    /// Zwischen den Neuronen besteht bereits eine Synapse
+    #[error("Synapse existiert bereits: {pre} -> {post}")]
+    SynapseExists {
+        /// Präsynaptisches Neuron
+        pre: Uuid,
+        /// Postsynaptisches Neuron
+        post: Uuid,
+    },
+
+    /// Eine Synapse würde das Dale'sche Prinzip verletzen
+    #[error(
+        "Dale'sches Prinzip verletzt: Neuron {neuron} ist {existing:?}, neue Synapse ist {requested:?}"
+    )]
+    DalesLawViolation {
+        /// Präsynaptisches Neuron
+        neuron: Uuid,
+        /// Typ der bestehenden ausgehenden Synapsen
+        existing: SynapseType,
+        /// Typ der abgelehnten Synapse
+        requested: SynapseType,
+    },
+
+    /// Ein Neuronenmodell unterstützt die angeforderte Simulationsart nicht
+    #[error("Neuron {neuron} ({model}) lässt sich nicht ereignisgesteuert simulieren")]
+    UnsupportedModel {
+        /// Betroffenes Neuron
+        neuron: Uuid,
+        /// Name des Modells
+        model: &'static str,
+    },
+
+    /// Lesen oder Schreiben eines Snapshots ist fehlgeschlagen
+    #[error("Ein-/Ausgabefehler: {0}")]
+    Io(String),
+
+    /// Ein Snapshot konnte nicht kodiert oder dekodiert werden
+    #[error("Snapshot konnte nicht kodiert oder dekodiert werden: {0}")]
+    Serialization(String),
+
+    /// Die Daten sind kein gültiger Netzwerk-Snapshot
+    #[error("Ungültiger Snapshot: {0}")]
+    InvalidSnapshot(String),
+
+    /// Eine Population mit diesem Namen existiert nicht
+    #[error("Population nicht gefunden: {0}")]
+    UnknownPopulation(String),
+
+    /// Eine Population mit diesem Namen existiert bereits
+    #[error("Population existiert bereits: {0}")]
+    DuplicatePopulation(String),
+
+    /// Eine Projektion lässt sich mit ihrer Verbindungsregel nicht erzeugen
+    #[error("Ungültige Projektion: {0}")]
+    InvalidProjection(String),
+
+    /// Ein GraphML-Dokument konnte nicht gelesen werden
+    #[error("Ungültiges GraphML-Dokument: {0}")]
+    InvalidGraphMl(String),
+
+    /// Eingabewerte passen nicht zur Kodierung oder zu den Eingangsneuronen
+    #[error("Ungültige Kodierung: {0}")]
+    InvalidEncoding(String),
+
+    /// Eine lineare Auslese lässt sich mit den Daten nicht anpassen oder anwenden
+    #[error("Ungültige Auslese: {0}")]
+    InvalidReadout(String),
+
+    /// Ein Reservoir lässt sich mit dieser Konfiguration nicht erstellen
+    #[error("Ungültiges Reservoir: {0}")]
+    InvalidReservoir(String),
+
+    /// Der Snapshot stammt von einer neueren, unbekannten Formatversion
+    #[error("Snapshot-Version {found} wird nicht unterstützt (höchstens {supported})")]
+    UnsupportedVersion {
+        /// Version des Snapshots
+        found: u32,
+        /// Höchste unterstützte Version
+        supported: u32,
+    },
+}
+
+/// Ergebnis einer Netzwerkoperation
+pub type NetworkResult<T> = Result<T, NetworkError>;
@@ -1,33 +1,161 @@
 use rand::prelude::*;
 use rand::rngs::StdRng;
 use serde::{Deserialize, Serialize};
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use uuid::Uuid;
 
-use crate::neural::neuron::model::{Neuron, NeuronState};
-use crate::neural::synapse::model::Synapse;
+use crate::neural::growth::Position;
+use crate::neural::network::adjacency::SynapseMap;
+use crate::neural::network::delay::SpikeQueue;
+use crate::neural::network::event_driven::{SIMULTANEITY_TOLERANCE, SpikeEvent, ThresholdSchedule};
+use crate::neural::network::execution::{ExecutionMode, Executor};
+use crate::neural::network::monitor::{Monitor, MonitorId};
+use crate::neural::network::population::{Population, Projection};
+use crate::neural::network::spatial::{DistanceKernel, Placement};
+use crate::neural::network::topology::Topology;
+use crate::neural::network::{NetworkError, NetworkResult};
+use crate::neural::neuron::hodgkin_huxley::{HhIntegrator, HodgkinHuxleyNeuron};
+use crate::neural::neuron::izhikevich::{IzhikevichNeuron, IzhikevichPreset};
+use crate::neural::neuron::lif::{LifNeuron, LifParams};
+use crate::neural::neuron::model::Neuron;
+use crate::neural::neuron::model::constants as neuron_constants;
+use crate::neural::neuron::traits::{NeuronFactory, NeuronModel};
+use crate::neural::synapse::model::constants as synapse_constants;
+use crate::neural::synapse::model::{Synapse, SynapseType};
+use crate::neural::synapse::plasticity::{
+    ActivityAverage, HebbianRule, PlasticityRule, SynapticActivity,
+    constants as plasticity_constants,
+};
+use crate::neural::synapse::stdp::{SpikeTrace, StdpRule};
+
+/// Toleranz beim Vergleich von Ankunftszeiten, um Rundungsfehler der Zeitsumme abzufangen
+const ARRIVAL_TOLERANCE: f64 = 1e-9;
+
+/// Startwert, mit dem deterministische Verbindungen ohne [`NetworkBuilder::with_seed`]
+/// ausgewählt werden
+pub const DETERMINISTIC_CONNECTION_SEED: u64 = 42;
+
+/// Lernregel für alle Synapsen zwischen zwei Neuronengruppen
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct ProjectionRule {
+    /// Präsynaptische Neuronen der Projektion
+    pre: HashSet<Uuid>,
+    /// Postsynaptische Neuronen der Projektion
+    post: HashSet<Uuid>,
+    /// Lernregel der Projektion
+    rule: Box<dyn PlasticityRule>,
+}
+
+impl ProjectionRule {
+    /// Prüft, ob eine Synapse zur Projektion gehört
+    fn contains(&self, pre_id: &Uuid, post_id: &Uuid) -> bool {
+        self.pre.contains(pre_id) && self.post.contains(post_id)
+    }
+}
+
+/// Reproduzierbarer Zufallsstrom eines Netzwerks, der Snapshots übersteht
+///
+/// Gespeichert werden nur Startwert und Zahl der bisherigen Ziehungen; jede Ziehung
+/// erhält einen eigenen, aus beiden abgeleiteten Generator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+struct RandomStream {
+    /// Startwert des Stroms
+    seed: u64,
+    /// Anzahl der bisherigen Ziehungen
+    draws: u64,
+}
+
+impl RandomStream {
+    /// Gibt den Generator der nächsten Ziehung zurück
+    fn next_rng(&mut self) -> StdRng {
+        // Fibonacci-Hashing verteilt aufeinanderfolgende Zähler über den Startwertraum
+        let rng = StdRng::seed_from_u64(self.seed ^ self.draws.wrapping_mul(0x9E37_79B9_7F4A_7C15));
+        self.draws += 1;
+        rng
+    }
+}
+
+/// Standard-Lernregel neuer Netzwerke
+fn default_plasticity_rule() -> Option<Box<dyn PlasticityRule>> {
+    Some(Box::new(HebbianRule::default()))
+}
 
 /// Repräsentiert ein neuronales Netzwerk, bestehend aus Neuronen und synaptischen Verbindungen
+///
+/// Die Neuronen werden über das [`NeuronModel`]-Trait verwaltet, sodass
+/// unterschiedliche Zellmodelle im selben Netzwerk nebeneinander existieren können.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Network {
     /// Neuronen, indiziert nach ihrer UUID
-    neurons: HashMap<Uuid, Neuron>,
+    neurons: HashMap<Uuid, Box<dyn NeuronModel>>,
+
+    /// Synapsen, indiziert nach (präsynaptische Neuron-ID, postsynaptische Neuron-ID),
+    /// mit ausgehenden und eingehenden Adjazenzlisten je Neuron
+    synapses: SynapseMap,
+
+    /// Aktuelle Simulationszeit in Sekunden
+    #[serde(default)]
+    time: f64,
+
+    /// Signale, die wegen der synaptischen Verzögerung noch unterwegs sind
+    #[serde(default)]
+    spike_queue: SpikeQueue,
+
+    /// Erzwingt, dass jedes Neuron nur erregende oder nur hemmende Synapsen aussendet
+    #[serde(default)]
+    enforce_dales_law: bool,
+
+    /// Im letzten Zyklus eingetroffene modulierende Signale je Zielneuron
+    #[serde(default)]
+    modulation: HashMap<Uuid, f32>,
+
+    /// Zeitpunkt des letzten Spikes je Neuron in Sekunden
+    #[serde(default)]
+    last_spike_times: HashMap<Uuid, f64>,
+
+    /// Neuronen, die beim letzten Sammeln gefeuert haben (zur Erkennung von Spikebeginnen)
+    #[serde(default)]
+    spiking_neurons: HashSet<Uuid>,
+
+    /// Optionale STDP-Regel, ersetzt das Hebbsche Lernen im Zyklus
+    #[serde(default)]
+    stdp: Option<StdpRule>,
+
+    /// Spikespuren je Neuron für die STDP-Regel
+    #[serde(default)]
+    spike_traces: HashMap<Uuid, SpikeTrace>,
+
+    /// Netzwerkweite ratenbasierte Lernregel (`None` deaktiviert das Lernen)
+    #[serde(default = "default_plasticity_rule")]
+    plasticity_rule: Option<Box<dyn PlasticityRule>>,
+
+    /// Lernregeln einzelner Projektionen, die die netzwerkweite Regel ersetzen
+    #[serde(default)]
+    projection_rules: Vec<ProjectionRule>,
 
-    /// Synapsen, indiziert nach (präsynaptische Neuron-ID, postsynaptische Neuron-ID)
-    synapses: HashMap<(Uuid, Uuid), Synapse>,
+    /// Gleitende Aktivitätsmittelwerte je Neuron für die Lernregeln
+    #[serde(default)]
+    activity_averages: HashMap<Uuid, ActivityAverage>,
 
-    /// Zwischenspeicher für Signale, die während eines Zyklus übertragen werden
-    pending_signals: HashMap<Uuid, f32>,
+    /// Sequentielle oder parallele Ausführung der Zyklusphasen
+    #[serde(default)]
+    executor: Executor,
 
-    /// Für Testfälle benötigt: Zyklusverfolgung pro Neuron
-    cycle_counter: HashMap<Uuid, u32>,
+    /// Benannte Neuronengruppen in der Reihenfolge ihrer Erstellung
+    #[serde(default)]
+    populations: Vec<Population>,
 
-    /// Test-spezifische Flags für verschiedene Testszenarien
-    activity_cycle_test_mode: bool,
-    inhibitory_test_mode: bool,
+    /// Angehängte Monitore; nicht Teil eines Snapshots
+    #[serde(skip)]
+    monitors: Vec<(MonitorId, Monitor)>,
 
-    /// Flags und Zähler für spezifische Testkontexte
-    test_cycle_count: u32,
+    /// Kennung für den nächsten angehängten Monitor
+    #[serde(skip)]
+    next_monitor_id: usize,
+
+    /// Zufallsstrom für neue Populationen und Projektionen ohne eigenen Startwert
+    #[serde(default)]
+    rng: Option<RandomStream>,
 }
 
 impl Default for Network {
@@ -41,45 +169,417 @@ impl Network {
     pub fn new() -> Self {
         Self {
             neurons: HashMap::new(),
-            synapses: HashMap::new(),
-            pending_signals: HashMap::new(),
-            cycle_counter: HashMap::new(),
-            activity_cycle_test_mode: false,
-            inhibitory_test_mode: false,
-            test_cycle_count: 0,
+            synapses: SynapseMap::new(),
+            time: 0.0,
+            spike_queue: SpikeQueue::new(),
+            enforce_dales_law: false,
+            modulation: HashMap::new(),
+            last_spike_times: HashMap::new(),
+            spiking_neurons: HashSet::new(),
+            stdp: None,
+            spike_traces: HashMap::new(),
+            plasticity_rule: default_plasticity_rule(),
+            projection_rules: Vec::new(),
+            activity_averages: HashMap::new(),
+            executor: Executor::default(),
+            populations: Vec::new(),
+            monitors: Vec::new(),
+            next_monitor_id: 0,
+            rng: None,
         }
     }
 
-    /// Aktiviert den Testmodus für Aktivitätszyklen
-    pub fn enable_activity_cycle_test(&mut self) {
-        self.activity_cycle_test_mode = true;
-        self.test_cycle_count = 0;
+    /// Macht die Zufallsentscheidungen des Netzwerks reproduzierbar
+    ///
+    /// Die IDs der Neuronen aus [`Network::add_population`] und die Verbindungen einer
+    /// [`Projection`] ohne eigenen Startwert werden danach aus einem Zufallsgenerator mit
+    /// diesem Startwert gezogen. Ein mit [`NetworkBuilder::with_seed`] gebautes Netzwerk
+    /// erhält seinen Startwert vom Builder. Der Stand des Zufallsstroms ist Teil eines
+    /// Snapshots, sodass ein geladenes Netzwerk dieselben IDs und Verbindungen zieht
+    /// wie eines, das ohne Unterbrechung weiterläuft.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.rng = Some(RandomStream { seed, draws: 0 });
+    }
+
+    /// Gibt den Generator für die nächste Ziehung aus dem Zufallsstrom des Netzwerks zurück
+    ///
+    /// Ohne Startwert wird beim ersten Bedarf einer aus dem Systemzufall gezogen.
+    fn next_rng(&mut self) -> StdRng {
+        self.rng
+            .get_or_insert_with(|| RandomStream {
+                seed: rand::random(),
+                draws: 0,
+            })
+            .next_rng()
     }
 
-    /// Aktiviert den Testmodus für inhibitorische Synapsen
-    pub fn enable_inhibitory_test(&mut self) {
-        self.inhibitory_test_mode = true;
-        self.test_cycle_count = 0;
+    /// Fügt ein Neuron eines beliebigen Modells zum Netzwerk hinzu
+    pub fn add_neuron<N: NeuronModel + 'static>(&mut self, neuron: N) {
+        self.add_boxed_neuron(Box::new(neuron));
     }
 
-    /// Fügt ein Neuron zum Netzwerk hinzu
-    pub fn add_neuron(&mut self, neuron: Neuron) {
+    /// Fügt ein bereits geboxtes Neuron zum Netzwerk hinzu
+    pub fn add_boxed_neuron(&mut self, neuron: Box<dyn NeuronModel>) {
         let id = *neuron.id();
         self.neurons.insert(id, neuron);
-        self.cycle_counter.insert(id, 0);
     }
 
     /// Fügt eine Synapse zum Netzwerk hinzu
+    ///
+    /// Ungültige Synapsen (fehlende Neuronen, Verletzung des Dale'schen Prinzips)
+    /// werden stillschweigend verworfen. [`Network::try_add_synapse`] meldet den Grund.
     pub fn add_synapse(&mut self, synapse: Synapse) {
+        let _ = self.try_add_synapse(synapse);
+    }
+
+    /// Fügt eine Synapse zum Netzwerk hinzu und meldet, warum sie abgelehnt wurde
+    ///
+    /// Eine bestehende Synapse zwischen denselben Neuronen wird ersetzt.
+    pub fn try_add_synapse(&mut self, synapse: Synapse) -> NetworkResult<()> {
         let pre_id = *synapse.pre_neuron_id();
         let post_id = *synapse.post_neuron_id();
 
         // Prüfe, ob beide Neuronen existieren
-        if !self.neurons.contains_key(&pre_id) || !self.neurons.contains_key(&post_id) {
-            return; // Synapse wird nicht hinzugefügt, wenn Neuronen fehlen
+        for id in [pre_id, post_id] {
+            if !self.neurons.contains_key(&id) {
+                return Err(NetworkError::NeuronNotFound(id));
+            }
+        }
+
+        if self.enforce_dales_law && synapse.synapse_type() != SynapseType::Modulatory {
+            let existing = self.outgoing_polarity(&pre_id, Some(&post_id));
+            if let Some(existing) = existing
+                && existing != synapse.synapse_type()
+            {
+                return Err(NetworkError::DalesLawViolation {
+                    neuron: pre_id,
+                    existing,
+                    requested: synapse.synapse_type(),
+                });
+            }
+        }
+
+        self.synapses.insert(synapse);
+        Ok(())
+    }
+
+    /// Aktiviert oder deaktiviert das Dale'sche Prinzip
+    ///
+    /// Ist es aktiv, darf ein Neuron nur erregende oder nur hemmende Synapsen
+    /// aussenden; modulierende Synapsen sind davon ausgenommen. Beim Aktivieren
+    /// werden die bestehenden Synapsen geprüft.
+    pub fn set_dales_law(&mut self, enabled: bool) -> NetworkResult<()> {
+        if enabled {
+            for synapse in self.synapses.values() {
+                if synapse.synapse_type() == SynapseType::Modulatory {
+                    continue;
+                }
+                let pre_id = synapse.pre_neuron_id();
+                if let Some(existing) = self.outgoing_polarity(pre_id, None)
+                    && existing != synapse.synapse_type()
+                {
+                    return Err(NetworkError::DalesLawViolation {
+                        neuron: *pre_id,
+                        existing,
+                        requested: synapse.synapse_type(),
+                    });
+                }
+            }
+        }
+
+        self.enforce_dales_law = enabled;
+        Ok(())
+    }
+
+    /// Gibt an, ob das Dale'sche Prinzip erzwungen wird
+    pub fn dales_law_enforced(&self) -> bool {
+        self.enforce_dales_law
+    }
+
+    /// Gibt die Wirkung (erregend oder hemmend) der ausgehenden Synapsen eines Neurons zurück
+    ///
+    /// Maßgeblich ist die erste nicht-modulierende Synapse; ohne solche Synapsen `None`.
+    pub fn neuron_polarity(&self, neuron_id: &Uuid) -> Option<SynapseType> {
+        self.outgoing_polarity(neuron_id, None)
+    }
+
+    /// Ermittelt die Polarität eines Neurons, optional ohne die Synapse zu `excluded_post`
+    fn outgoing_polarity(
+        &self,
+        pre_id: &Uuid,
+        excluded_post: Option<&Uuid>,
+    ) -> Option<SynapseType> {
+        self.synapses
+            .outgoing(pre_id)
+            .filter(|synapse| Some(synapse.post_neuron_id()) != excluded_post)
+            .map(|synapse| synapse.synapse_type())
+            .find(|synapse_type| *synapse_type != SynapseType::Modulatory)
+    }
+
+    /// Legt fest, ob die Zyklusphasen sequentiell oder auf Worker-Threads laufen
+    ///
+    /// Beide Modi liefern bitgenau dieselben Ergebnisse.
+    pub fn set_execution_mode(&mut self, mode: ExecutionMode) {
+        self.executor = Executor::new(mode);
+    }
+
+    /// Gibt den Ausführungsmodus zurück
+    pub fn execution_mode(&self) -> ExecutionMode {
+        self.executor.mode()
+    }
+
+    /// Gibt die Summe der im letzten Zyklus eingetroffenen modulierenden Signale zurück
+    pub fn modulation(&self, neuron_id: &Uuid) -> f32 {
+        self.modulation.get(neuron_id).copied().unwrap_or(0.0)
+    }
+
+    /// Legt die STDP-Regel fest oder entfernt sie mit `None`
+    ///
+    /// Mit einer STDP-Regel lernen erregende Synapsen im Zyklus aus dem zeitlichen
+    /// Abstand der Spikes statt aus gleichzeitiger Aktivität.
+    pub fn set_stdp(&mut self, rule: Option<StdpRule>) {
+        self.stdp = rule;
+        self.spike_traces.clear();
+    }
+
+    /// Gibt die aktive STDP-Regel zurück
+    pub fn stdp(&self) -> Option<&StdpRule> {
+        self.stdp.as_ref()
+    }
+
+    /// Legt die netzwerkweite Lernregel fest
+    ///
+    /// Neue Netzwerke lernen mit einer [`HebbianRule`] und der Standard-Lernrate.
+    pub fn set_plasticity_rule<R: PlasticityRule + 'static>(&mut self, rule: R) {
+        self.plasticity_rule = Some(Box::new(rule));
+    }
+
+    /// Deaktiviert die netzwerkweite Lernregel; Projektionsregeln bleiben aktiv
+    pub fn disable_plasticity(&mut self) {
+        self.plasticity_rule = None;
+    }
+
+    /// Gibt die netzwerkweite Lernregel zurück
+    pub fn plasticity_rule(&self) -> Option<&dyn PlasticityRule> {
+        self.plasticity_rule.as_deref()
+    }
+
+    /// Registriert eine Lernregel für alle Synapsen von `pre_ids` nach `post_ids`
+    ///
+    /// Die Regel ersetzt für diese Synapsen die netzwerkweite Regel. Gehört eine
+    /// Synapse zu mehreren Projektionen, gilt die zuletzt registrierte.
+    pub fn add_projection_rule<R, I, J>(&mut self, pre_ids: I, post_ids: J, rule: R)
+    where
+        R: PlasticityRule + 'static,
+        I: IntoIterator<Item = Uuid>,
+        J: IntoIterator<Item = Uuid>,
+    {
+        self.projection_rules.push(ProjectionRule {
+            pre: pre_ids.into_iter().collect(),
+            post: post_ids.into_iter().collect(),
+            rule: Box::new(rule),
+        });
+    }
+
+    /// Gibt die Lernregel zurück, die für eine Synapse gilt
+    pub fn plasticity_rule_for(
+        &self,
+        pre_id: &Uuid,
+        post_id: &Uuid,
+    ) -> Option<&dyn PlasticityRule> {
+        self.projection_rules
+            .iter()
+            .rev()
+            .find(|projection| projection.contains(pre_id, post_id))
+            .map(|projection| projection.rule.as_ref())
+            .or(self.plasticity_rule.as_deref())
+    }
+
+    /// Legt eine Population aus `count` neuen Neuronen an
+    ///
+    /// Die Fabrik erhält die ID jedes zu erzeugenden Neurons, sodass alle Neuronen der
+    /// Population mit denselben Parametern erstellt werden können. Die IDs stammen aus
+    /// dem Zufallsgenerator des Netzwerks (siehe [`Network::set_seed`]).
+    pub fn add_population<F>(
+        &mut self,
+        name: impl Into<String>,
+        count: usize,
+        factory: F,
+    ) -> NetworkResult<&Population>
+    where
+        F: Fn(Uuid) -> Box<dyn NeuronModel>,
+    {
+        let name = name.into();
+        if self.population(&name).is_some() {
+            return Err(NetworkError::DuplicatePopulation(name));
         }
 
-        self.synapses.insert((pre_id, post_id), synapse);
+        let mut rng = self.next_rng();
+        let mut neuron_ids = Vec::with_capacity(count);
+        for _ in 0..count {
+            let id = uuid::Builder::from_random_bytes(rng.r#gen()).into_uuid();
+            let neuron = factory(id);
+            neuron_ids.push(*neuron.id());
+            self.add_boxed_neuron(neuron);
+        }
+        self.populations.push(Population::new(name, neuron_ids));
+        Ok(self
+            .populations
+            .last()
+            .expect("Population wurde hinzugefügt"))
+    }
+
+    /// Fasst bereits vorhandene Neuronen zu einer Population zusammen
+    pub fn define_population<I>(
+        &mut self,
+        name: impl Into<String>,
+        neuron_ids: I,
+    ) -> NetworkResult<&Population>
+    where
+        I: IntoIterator<Item = Uuid>,
+    {
+        let name = name.into();
+        if self.population(&name).is_some() {
+            return Err(NetworkError::DuplicatePopulation(name));
+        }
+        let neuron_ids: Vec<Uuid> = neuron_ids.into_iter().collect();
+        if let Some(missing) = neuron_ids.iter().find(|id| !self.neurons.contains_key(id)) {
+            return Err(NetworkError::NeuronNotFound(*missing));
+        }
+        self.populations.push(Population::new(name, neuron_ids));
+        Ok(self
+            .populations
+            .last()
+            .expect("Population wurde hinzugefügt"))
+    }
+
+    /// Gibt die Population mit dem angegebenen Namen zurück
+    pub fn population(&self, name: &str) -> Option<&Population> {
+        self.populations
+            .iter()
+            .find(|population| population.name() == name)
+    }
+
+    /// Gibt alle Populationen in der Reihenfolge ihrer Erstellung zurück
+    pub fn populations(&self) -> &[Population] {
+        &self.populations
+    }
+
+    /// Hängt einen Monitor an, der ab dem nächsten Zyklus aufzeichnet
+    pub fn attach_monitor(&mut self, monitor: impl Into<Monitor>) -> MonitorId {
+        let id = MonitorId(self.next_monitor_id);
+        self.next_monitor_id += 1;
+        self.monitors.push((id, monitor.into()));
+        id
+    }
+
+    /// Gibt einen angehängten Monitor zurück
+    pub fn monitor(&self, id: MonitorId) -> Option<&Monitor> {
+        self.monitors
+            .iter()
+            .find(|(monitor_id, _)| *monitor_id == id)
+            .map(|(_, monitor)| monitor)
+    }
+
+    /// Gibt einen angehängten Monitor veränderbar zurück, etwa zum Leeren
+    pub fn monitor_mut(&mut self, id: MonitorId) -> Option<&mut Monitor> {
+        self.monitors
+            .iter_mut()
+            .find(|(monitor_id, _)| *monitor_id == id)
+            .map(|(_, monitor)| monitor)
+    }
+
+    /// Entfernt einen Monitor und gibt ihn mit seinen Aufzeichnungen zurück
+    pub fn detach_monitor(&mut self, id: MonitorId) -> Option<Monitor> {
+        let index = self
+            .monitors
+            .iter()
+            .position(|(monitor_id, _)| *monitor_id == id)?;
+        Some(self.monitors.remove(index).1)
+    }
+
+    /// Aktualisiert alle angehängten Monitore mit den neu begonnenen Spikes
+    fn update_monitors(&mut self, spikes: &[SpikeEvent]) {
+        if self.monitors.is_empty() {
+            return;
+        }
+        // Monitore lesen das Netzwerk, werden dafür kurz herausgenommen
+        let mut monitors = std::mem::take(&mut self.monitors);
+        for (_, monitor) in &mut monitors {
+            monitor.update(self, spikes);
+        }
+        self.monitors = monitors;
+    }
+
+    /// Verbindet zwei Populationen nach der Regel der Projektion
+    ///
+    /// Besitzt die Projektion eine eigene Lernregel, wird sie für alle Synapsen zwischen
+    /// den beiden Populationen registriert (siehe [`Network::add_projection_rule`]).
+    /// Wird eine Synapse abgelehnt, etwa wegen des Dale'schen Prinzips, bleibt das
+    /// Netzwerk unverändert.
+    ///
+    /// # Returns
+    ///
+    /// Die Anzahl der erzeugten Synapsen
+    pub fn add_projection(&mut self, mut projection: Projection) -> NetworkResult<usize> {
+        let lookup = |name: &str| {
+            self.population(name)
+                .map(|population| population.neuron_ids().to_vec())
+                .ok_or_else(|| NetworkError::UnknownPopulation(name.to_string()))
+        };
+        let source = lookup(projection.source())?;
+        let target = lookup(projection.target())?;
+
+        let seed = match projection.seed() {
+            Some(seed) => seed,
+            None => self.next_rng().r#gen(),
+        };
+        let mut rng = StdRng::seed_from_u64(seed);
+        let synapses = projection.synapses(&source, &target, &mut rng)?;
+        let count = synapses.len();
+        // Ersetzte Synapsen je Schlüssel, um eine abgelehnte Projektion zurückzunehmen
+        let mut inserted = Vec::with_capacity(count);
+        for synapse in synapses {
+            let key = (*synapse.pre_neuron_id(), *synapse.post_neuron_id());
+            let replaced = self.synapses.get(&key).cloned();
+            if let Err(error) = self.try_add_synapse(synapse) {
+                for (key, replaced) in inserted.into_iter().rev() {
+                    match replaced {
+                        Some(synapse) => self.synapses.insert(synapse),
+                        None => self.synapses.remove(&key),
+                    };
+                }
+                return Err(error);
+            }
+            inserted.push((key, replaced));
+        }
+
+        if let Some(rule) = projection.take_plasticity_rule() {
+            self.projection_rules.push(ProjectionRule {
+                pre: source.into_iter().collect(),
+                post: target.into_iter().collect(),
+                rule,
+            });
+        }
+        Ok(count)
+    }
+
+    /// Gibt die gleitenden Aktivitätsmittelwerte eines Neurons zurück
+    pub fn activity_average(&self, neuron_id: &Uuid) -> ActivityAverage {
+        self.activity_averages
+            .get(neuron_id)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Gibt den Zeitpunkt des letzten Spikebeginns eines Neurons in Sekunden zurück
+    pub fn last_spike_time(&self, neuron_id: &Uuid) -> Option<f64> {
+        self.last_spike_times.get(neuron_id).copied()
+    }
+
+    /// Gibt die Spikespuren eines Neurons zurück (nur mit aktiver STDP-Regel)
+    pub fn spike_trace(&self, neuron_id: &Uuid) -> Option<&SpikeTrace> {
+        self.spike_traces.get(neuron_id)
     }
 
     /// Prüft, ob ein Neuron mit der angegebenen ID existiert
@@ -88,13 +588,33 @@ impl Network {
     }
 
     /// Holt ein Neuron anhand seiner ID (als geteilte Referenz)
-    pub fn get_neuron(&self, neuron_id: &Uuid) -> Option<&Neuron> {
-        self.neurons.get(neuron_id)
+    pub fn get_neuron(&self, neuron_id: &Uuid) -> Option<&dyn NeuronModel> {
+        self.neurons.get(neuron_id).map(|neuron| neuron.as_ref())
     }
 
     /// Holt ein Neuron anhand seiner ID (als veränderbare Referenz)
-    pub fn get_neuron_mut(&mut self, neuron_id: &Uuid) -> Option<&mut Neuron> {
-        self.neurons.get_mut(neuron_id)
+    pub fn get_neuron_mut(&mut self, neuron_id: &Uuid) -> Option<&mut dyn NeuronModel> {
+        match self.neurons.get_mut(neuron_id) {
+            Some(neuron) => Some(neuron.as_mut()),
+            None => None,
+        }
+    }
+
+    /// Holt ein Neuron als konkreten Modelltyp, falls es von diesem Typ ist
+    pub fn get_neuron_as<T: NeuronModel + 'static>(&self, neuron_id: &Uuid) -> Option<&T> {
+        self.neurons
+            .get(neuron_id)
+            .and_then(|neuron| neuron.as_any().downcast_ref::<T>())
+    }
+
+    /// Holt ein Neuron als veränderbaren konkreten Modelltyp, falls es von diesem Typ ist
+    pub fn get_neuron_as_mut<T: NeuronModel + 'static>(
+        &mut self,
+        neuron_id: &Uuid,
+    ) -> Option<&mut T> {
+        self.neurons
+            .get_mut(neuron_id)
+            .and_then(|neuron| neuron.as_any_mut().downcast_mut::<T>())
     }
 
     /// Prüft, ob eine Synapse zwischen den angegebenen Neuronen existiert
@@ -112,14 +632,169 @@ impl Network {
         self.synapses.get_mut(&(*pre_id, *post_id))
     }
 
+    /// Entfernt ein Neuron samt aller ein- und ausgehenden Synapsen
+    ///
+    /// Auch der Zustand, den das Netzwerk für das Neuron führt (Spikezeiten, Spuren,
+    /// Aktivitätsmittelwerte, unterwegs befindliche Signale), sowie seine Einträge in
+    /// Populationen und Projektionsregeln werden verworfen.
+    ///
+    /// # Returns
+    ///
+    /// Das entfernte Neuron oder `None`, wenn es nicht existiert
+    pub fn remove_neuron(&mut self, neuron_id: &Uuid) -> Option<Box<dyn NeuronModel>> {
+        let neuron = self.neurons.remove(neuron_id)?;
+        self.synapses.remove_neuron(neuron_id);
+        self.spike_queue
+            .retain(|event| event.pre_id != *neuron_id && event.post_id != *neuron_id);
+
+        self.modulation.remove(neuron_id);
+        self.last_spike_times.remove(neuron_id);
+        self.spiking_neurons.remove(neuron_id);
+        self.spike_traces.remove(neuron_id);
+        self.activity_averages.remove(neuron_id);
+        for projection in &mut self.projection_rules {
+            projection.pre.remove(neuron_id);
+            projection.post.remove(neuron_id);
+        }
+        for population in &mut self.populations {
+            population.remove(neuron_id);
+        }
+        Some(neuron)
+    }
+
+    /// Entfernt mehrere Neuronen samt ihrer Synapsen
+    ///
+    /// # Returns
+    ///
+    /// Die Anzahl der tatsächlich entfernten Neuronen
+    pub fn remove_neurons<I>(&mut self, neuron_ids: I) -> usize
+    where
+        I: IntoIterator<Item = Uuid>,
+    {
+        neuron_ids
+            .into_iter()
+            .filter(|id| self.remove_neuron(id).is_some())
+            .count()
+    }
+
+    /// Entfernt die Synapse zwischen zwei Neuronen
+    ///
+    /// Signale, die über diese Synapse noch unterwegs sind, werden verworfen.
+    ///
+    /// # Returns
+    ///
+    /// Die entfernte Synapse oder `None`, wenn sie nicht existiert
+    pub fn remove_synapse(&mut self, pre_id: &Uuid, post_id: &Uuid) -> Option<Synapse> {
+        let synapse = self.synapses.remove(&(*pre_id, *post_id))?;
+        self.discard_pending(&[(*pre_id, *post_id)]);
+        Some(synapse)
+    }
+
+    /// Entfernt mehrere Synapsen
+    ///
+    /// # Returns
+    ///
+    /// Die Anzahl der tatsächlich entfernten Synapsen
+    pub fn remove_synapses<I>(&mut self, keys: I) -> usize
+    where
+        I: IntoIterator<Item = (Uuid, Uuid)>,
+    {
+        let removed: Vec<(Uuid, Uuid)> = keys
+            .into_iter()
+            .filter(|key| self.synapses.remove(key).is_some())
+            .collect();
+        self.discard_pending(&removed);
+        removed.len()
+    }
+
+    /// Behält nur die Synapsen, für die `keep` `true` liefert
+    ///
+    /// Nützlich zum Beschneiden schwacher Verbindungen, z.B.
+    /// `network.retain_synapses(|synapse| synapse.weight().abs() >= 0.05)`.
+    ///
+    /// # Returns
+    ///
+    /// Die Anzahl der entfernten Synapsen
+    pub fn retain_synapses<F>(&mut self, keep: F) -> usize
+    where
+        F: FnMut(&Synapse) -> bool,
+    {
+        let removed: Vec<(Uuid, Uuid)> = self
+            .synapses
+            .retain(keep)
+            .iter()
+            .map(|synapse| (*synapse.pre_neuron_id(), *synapse.post_neuron_id()))
+            .collect();
+        self.discard_pending(&removed);
+        removed.len()
+    }
+
+    /// Verlegt eine Synapse auf ein anderes Neuronenpaar
+    ///
+    /// Gewicht, Typ, Verzögerung und Kurzzeitplastizität bleiben erhalten; Signale, die
+    /// über die alte Verbindung unterwegs sind, werden verworfen. Schlägt das Verlegen
+    /// fehl, bleibt das Netzwerk unverändert.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - Bisheriges Paar (präsynaptisch, postsynaptisch)
+    /// * `to` - Neues Paar (präsynaptisch, postsynaptisch)
+    pub fn rewire(&mut self, from: (Uuid, Uuid), to: (Uuid, Uuid)) -> NetworkResult<()> {
+        if !self.synapses.contains_key(&from) {
+            return Err(NetworkError::SynapseNotFound {
+                pre: from.0,
+                post: from.1,
+            });
+        }
+        if from == to {
+            return Ok(());
+        }
+        if self.synapses.contains_key(&to) {
+            return Err(NetworkError::SynapseExists {
+                pre: to.0,
+                post: to.1,
+            });
+        }
+        let original = self.synapses.remove(&from).expect("Synapse wurde geprüft");
+
+        let mut synapse = original.clone();
+        synapse.set_endpoints(to.0, to.1);
+        if let Err(error) = self.try_add_synapse(synapse) {
+            self.synapses.insert(original);
+            return Err(error);
+        }
+        self.discard_pending(&[from]);
+        Ok(())
+    }
+
+    /// Verwirft unterwegs befindliche Signale entfernter Synapsen
+    fn discard_pending(&mut self, keys: &[(Uuid, Uuid)]) {
+        if keys.is_empty() {
+            return;
+        }
+        let keys: HashSet<&(Uuid, Uuid)> = keys.iter().collect();
+        self.spike_queue
+            .retain(|event| !keys.contains(&(event.pre_id, event.post_id)));
+    }
+
     /// Gibt eine Referenz zu allen Neuronen zurück
-    pub fn neurons(&self) -> &HashMap<Uuid, Neuron> {
+    pub fn neurons(&self) -> &HashMap<Uuid, Box<dyn NeuronModel>> {
         &self.neurons
     }
 
     /// Gibt eine Referenz zu allen Synapsen zurück
     pub fn synapses(&self) -> &HashMap<(Uuid, Uuid), Synapse> {
-        &self.synapses
+        self.synapses.as_map()
+    }
+
+    /// Iteriert über die ausgehenden Synapsen eines Neurons
+    pub fn outgoing_synapses(&self, neuron_id: &Uuid) -> impl Iterator<Item = &Synapse> {
+        self.synapses.outgoing(neuron_id)
+    }
+
+    /// Iteriert über die eingehenden Synapsen eines Neurons
+    pub fn incoming_synapses(&self, neuron_id: &Uuid) -> impl Iterator<Item = &Synapse> {
+        self.synapses.incoming(neuron_id)
     }
 
     /// Gibt die Anzahl der Neuronen im Netzwerk zurück
@@ -132,6 +807,16 @@ impl Network {
         self.synapses.len()
     }
 
+    /// Gibt die aktuelle Simulationszeit in Sekunden zurück
+    pub fn time(&self) -> f64 {
+        self.time
+    }
+
+    /// Gibt die Anzahl der Signale zurück, die noch auf ihre Zustellung warten
+    pub fn pending_spike_count(&self) -> usize {
+        self.spike_queue.len()
+    }
+
     /// Stimuliert ein bestimmtes Neuron mit einem Eingangssignal
     pub fn stimulate_neuron(&mut self, neuron_id: &Uuid, input: f32) {
         if let Some(neuron) = self.neurons.get_mut(neuron_id) {
@@ -141,221 +826,443 @@ impl Network {
 
     /// Führt einen einzelnen Verarbeitungszyklus im Netzwerk aus
     ///
-    /// Diese Implementierung ist speziell für die Testfälle optimiert
+    /// Ein Zyklus schreibt das Netzwerk vom Zeitpunkt `t` um `time_step` Sekunden fort
+    /// und besteht aus vier Phasen in fester Reihenfolge:
+    ///
+    /// 1. **Sammeln**: Jedes Neuron, das zum Zeitpunkt `t` feuert
+    ///    ([`NeuronModel::is_spiking`]), sendet über seine ausgehenden Synapsen ein Signal,
+    ///    das sein Ziel zum Zeitpunkt `t + delay` erreicht.
+    /// 2. **Zustellen**: Alle Signale, die bis zum Ende des Zeitschritts (`t + time_step`)
+    ///    ankommen, werden zugestellt – hemmende vor erregenden, modulierende Signale
+    ///    wirken nur auf die Plastizität.
+    /// 3. **Integrieren**: Jedes Neuron schreibt seine Dynamik um `time_step` fort.
+    /// 4. **Plastizität**: Jede Synapse wird nach ihrer [`PlasticityRule`] angepasst
+    ///    (Projektionsregel oder netzwerkweite Regel), mit einer [`StdpRule`] stattdessen
+    ///    nach dem zeitlichen Abstand der Spikes.
+    ///
+    /// Ein Signal wird in dem Zeitschritt integriert, in dessen Intervall seine Ankunft
+    /// fällt. Bei einer Verzögerung von einem Zeitschritt reagiert das Ziel also im selben
+    /// Zyklus, in dem der Spike gesammelt wird, und ist danach bereits aktiv.
+    ///
+    /// Erregende und hemmende Signale werden je Zielneuron getrennt summiert und beide
+    /// Summen über [`NeuronModel::receive_input`] zugestellt, die hemmende zuerst. Wie
+    /// das Neuron darauf reagiert, legt allein sein Modell fest: Gleichzeitige Erregung
+    /// und Hemmung heben sich auf, eine bereits ausgelöste Aktivierung nimmt Hemmung
+    /// nicht zurück, und während der Refraktärzeit bleibt sie wie jede Eingabe wirkungslos.
+    ///
+    /// Das Sammeln berührt nur die ausgehenden Synapsen feuernder Neuronen. Integrieren
+    /// und Plastizität schreiben dagegen jede Synapse fort, weil Kurzzeitplastizität und
+    /// ratenbasierte Lernregeln auch ohne Spikes wirken; der Aufwand eines Zyklus wächst
+    /// daher mit der Gesamtzahl der Synapsen.
+    ///
+    /// Im parallelen [`ExecutionMode`] werden Neuronen- und Synapsenupdates auf
+    /// Worker-Threads verteilt; Spikes werden in der Reihenfolge der Neuron-IDs
+    /// gesammelt und Signale in Ankunftsreihenfolge summiert, sodass das Ergebnis
+    /// nicht von der Threadanzahl abhängt.
     pub fn cycle(&mut self, time_step: f32) {
-        // Wenn wir uns im Testmodus für Aktivitätszyklen befinden, verwalten wir die Zustände speziell
-        if self.activity_cycle_test_mode {
-            self.test_cycle_count += 1;
-
-            // Wir müssen aktive Neuronen identifizieren, bevor wir ihren Zustand ändern
-            let neuron_ids: Vec<_> = self.neurons.keys().cloned().collect();
-
-            // Spezielles Verhalten für den Aktivitätszyklen-Test
-            if self.test_cycle_count == 10 {
-                for neuron_id in &neuron_ids {
-                    if let Some(neuron) = self.neurons.get_mut(neuron_id) {
-                        // Beide Neuronen sollten refraktär sein
-                        if neuron.state() != NeuronState::Refractory {
-                            // Nutze die öffentliche API
-                            if neuron.state() == NeuronState::Inactive {
-                                neuron.receive_input(10.0); // Aktivieren
-                                neuron.cycle(); // Zu Active transitieren
-                            }
-                            if neuron.state() == NeuronState::Active {
-                                neuron.cycle(); // Von Active zu Refractory
-                            }
-                        }
-                    }
-                }
-            }
-            // Für den späteren Test sollten die Neuronen inaktiv sein
-            else if self.test_cycle_count == 30 {
-                for neuron_id in &neuron_ids {
-                    if let Some(neuron) = self.neurons.get_mut(neuron_id) {
-                        // Nutze reset() nur, wenn das Neuron nicht bereits inaktiv ist
-                        if neuron.state() != NeuronState::Inactive {
-                            neuron.reset();
-                        }
-                    }
-                }
-            }
+        self.executor.ensure_pool();
+        let spike_time = self.time;
+        let spike_onsets = self.collect_spikes();
+        self.deliver_spikes(time_step);
+        self.integrate(time_step);
+        self.update_activity_averages(time_step);
+        match self.stdp {
+            Some(rule) => self.apply_stdp(&rule, &spike_onsets, spike_time),
+            None => self.apply_plasticity_rules(),
         }
+        if !self.monitors.is_empty() {
+            let mut spikes: Vec<SpikeEvent> = spike_onsets
+                .iter()
+                .map(|id| SpikeEvent {
+                    time: spike_time,
+                    neuron_id: *id,
+                })
+                .collect();
+            spikes.sort_by_key(|spike| spike.neuron_id);
+            self.update_monitors(&spikes);
+        }
+    }
 
-        // Wenn wir uns im Testmodus für inhibitorische Synapsen befinden
-        if self.inhibitory_test_mode {
-            self.test_cycle_count += 1;
-
-            // Spezielle Logik für inhibitorische Synapsen
-            let inhibitory_synapse_ids: Vec<_> = self.synapses.keys().cloned().collect();
-
-            for synapse_id in inhibitory_synapse_ids {
-                if let Some(synapse) = self.synapses.get(&synapse_id).cloned() {
-                    // Wenn es eine inhibitorische Synapse ist (negatives Gewicht)
-                    if synapse.weight() < 0.0 {
-                        let pre_id = synapse.pre_neuron_id();
-                        let post_id = synapse.post_neuron_id();
-
-                        // Wenn präsynaptisches Neuron aktiv ist, unterdrücke das postsynaptische
-                        if let Some(pre_neuron) = self.neurons.get(pre_id) {
-                            if pre_neuron.state() == NeuronState::Active {
-                                if let Some(neuron) = self.neurons.get_mut(post_id) {
-                                    // Setze das Zielneuron explizit auf inaktiv
-                                    if self.test_cycle_count == 3 {
-                                        // Stellen wir sicher, dass es inaktiv bleibt
-                                        // trotz Stimulation
-                                        neuron.reset();
-
-                                        // Um sicherzustellen, dass es inaktiv bleibt
-                                        let input_energy = neuron.activation_energy();
-                                        if input_energy > 0.0 {
-                                            // "Neutralisiere" alle eingehende Energie
-                                            neuron.receive_input(-input_energy);
-                                        }
-                                    }
-                                }
-                            }
-                        }
-                    }
-                }
-            }
+    /// Phase 1: Reiht die Signale aller feuernden Neuronen mit ihrer Ankunftszeit ein
+    ///
+    /// Gibt die Neuronen zurück, deren Spike zum aktuellen Zeitpunkt beginnt, und
+    /// vermerkt für diese die Spikezeit.
+    fn collect_spikes(&mut self) -> Vec<Uuid> {
+        let spiking_ids = self
+            .executor
+            .filter_keys(&self.neurons, |neuron| neuron.is_spiking());
+
+        for neuron_id in &spiking_ids {
+            // Signal über alle ausgehenden Synapsen übertragen
+            let (time, queue) = (self.time, &mut self.spike_queue);
+            self.synapses.for_each_outgoing_mut(neuron_id, |synapse| {
+                let raw_signal = synapse.transmit(1.0);
+                queue.push(
+                    time + synapse.delay() as f64,
+                    *synapse.pre_neuron_id(),
+                    *synapse.post_neuron_id(),
+                    raw_signal,
+                    synapse.synapse_type(),
+                );
+            });
         }
 
-        // Standardverhalten für normale (nicht-Test) Fälle
-        // Signalübertragung vorbereiten
+        // Ein Spike beginnt, wenn das Neuron beim vorherigen Sammeln nicht gefeuert hat
+        let onsets: Vec<Uuid> = spiking_ids
+            .iter()
+            .filter(|id| !self.spiking_neurons.contains(id))
+            .copied()
+            .collect();
+        for id in &onsets {
+            self.last_spike_times.insert(*id, self.time);
+        }
+        self.spiking_neurons = spiking_ids.into_iter().collect();
+
+        onsets
+    }
+
+    /// Phase 2: Stellt alle Signale zu, die bis zum Ende des Zeitschritts ankommen
+    fn deliver_spikes(&mut self, time_step: f32) {
         let mut excitatory_signals = HashMap::new();
         let mut inhibitory_signals = HashMap::new();
 
-        // Sammle alle Signale von aktiven Neuronen
-        for neuron_id in self.neurons.keys().cloned().collect::<Vec<_>>() {
-            if let Some(neuron) = self.neurons.get_mut(&neuron_id) {
-                if neuron.state() == NeuronState::Active {
-                    // Erhöhe den Zykluszähler für aktive Neuronen
-                    if let Some(counter) = self.cycle_counter.get_mut(&neuron_id) {
-                        *counter += 1;
-                    }
+        // Fällige Signale je Ziel und Synapsentyp aufsummieren
+        self.modulation.clear();
+        let delivery_horizon = self.time + time_step as f64 + ARRIVAL_TOLERANCE;
+        for event in self.spike_queue.pop_due(delivery_horizon) {
+            let target = match event.synapse_type {
+                SynapseType::Excitatory => &mut excitatory_signals,
+                SynapseType::Inhibitory => &mut inhibitory_signals,
+                SynapseType::Modulatory => &mut self.modulation,
+            };
+            *target.entry(event.post_id).or_insert(0.0) += event.signal;
+        }
 
-                    // Finde alle ausgehenden Synapsen
-                    for ((pre_id, post_id), synapse) in self.synapses.iter_mut() {
-                        if pre_id == &neuron_id {
-                            // Signal durch die Synapse übertragen
-                            let raw_signal = synapse.transmit(1.0);
-
-                            // Je nach Vorzeichen des Signals in exzitatorische oder inhibitorische Map einfügen
-                            if raw_signal >= 0.0 {
-                                *excitatory_signals.entry(*post_id).or_insert(0.0) += raw_signal;
-                            } else {
-                                *inhibitory_signals.entry(*post_id).or_insert(0.0) += raw_signal;
-                            }
-                        }
-                    }
+        if excitatory_signals.is_empty() && inhibitory_signals.is_empty() {
+            return;
+        }
+
+        // Signale an die Zielneuronen übertragen, hemmende vor erregenden, damit
+        // gleichzeitige Hemmung auch bei Modellen mit sofortiger Schwellwertprüfung wirkt;
+        // wie ein aktives oder refraktäres Neuron auf Eingaben reagiert, entscheidet das Modell
+        self.executor
+            .for_each_mut(&mut self.neurons, |post_id, neuron| {
+                if let Some(signal) = inhibitory_signals.get(post_id) {
+                    neuron.receive_input(*signal);
                 }
-            }
+                if let Some(signal) = excitatory_signals.get(post_id) {
+                    neuron.receive_input(*signal);
+                }
+            });
+    }
+
+    /// Phase 3: Schreibt alle Neuronen und Synapsen um einen Zeitschritt fort
+    fn integrate(&mut self, time_step: f32) {
+        self.executor.for_each_mut(&mut self.neurons, |_, neuron| {
+            neuron.step(time_step);
+        });
+
+        // Aktualisiere die Synapsen
+        self.synapses.for_each_mut(&self.executor, |_, synapse| {
+            synapse.update(time_step);
+        });
+
+        self.time += time_step as f64;
+    }
+
+    /// Wendet einmalig Hebbsches Lernen mit der angegebenen Rate auf alle Synapsen an
+    ///
+    /// Unabhängig von den registrierten Lernregeln werden Synapsen zwischen gleichzeitig
+    /// feuernden Neuronen um `plasticity_rate` verstärkt.
+    pub fn apply_plasticity(&mut self, plasticity_rate: f32) {
+        let rule = HebbianRule::new(plasticity_rate);
+        for synapse in self.synapses.values_mut() {
+            apply_rule(
+                &rule,
+                synapse,
+                &self.neurons,
+                &self.activity_averages,
+                &self.modulation,
+            );
         }
+    }
 
-        // Signale an die Zielneuronen übertragen (exzitatorische und inhibitorische getrennt verarbeiten)
-        for (post_id, signal) in &excitatory_signals {
-            if let Some(neuron) = self.neurons.get_mut(post_id) {
-                // Nur exzitatorische Signale direkt übertragen
-                neuron.receive_input(*signal);
+    /// Aktualisiert die gleitenden Aktivitätsmittelwerte aller Neuronen
+    fn update_activity_averages(&mut self, time_step: f32) {
+        if self.activity_averages.len() != self.neurons.len() {
+            for id in self.neurons.keys() {
+                self.activity_averages.entry(*id).or_default();
             }
         }
 
-        // Inhibitorische Signale separat verarbeiten
-        for (post_id, inhibitory_signal) in &inhibitory_signals {
-            if let Some(neuron) = self.neurons.get_mut(post_id) {
-                // Bei inhibitorischen Signalen müssen wir den Neuronenzustand entsprechend anpassen
-                // Wenn ein inhibitorisches Signal stark genug ist, setzen wir den Zustand auf Inaktiv
-                if neuron.state() == NeuronState::Active && inhibitory_signal.abs() > 0.5 {
-                    // Starke Inhibition kann eine Aktivierung unterdrücken
-                    neuron.reset(); // Setze das Neuron zurück auf inaktiv
-                } else if neuron.state() == NeuronState::Inactive {
-                    // Bei inaktiven Neuronen verringern wir den Eingangswert, um Aktivierung zu erschweren
-                    neuron.receive_input(*inhibitory_signal);
+        let neurons = &self.neurons;
+        self.executor
+            .for_each_mut(&mut self.activity_averages, |id, average| {
+                let activity = match neurons.get(id) {
+                    Some(neuron) if neuron.is_spiking() => 1.0,
+                    _ => 0.0,
+                };
+                average.update(
+                    activity,
+                    time_step,
+                    plasticity_constants::DEFAULT_AVERAGING_TIME_CONSTANT,
+                );
+            });
+    }
+
+    /// Phase 4: Wendet auf jede Synapse die für sie geltende Lernregel an
+    fn apply_plasticity_rules(&mut self) {
+        let projection_rules = &self.projection_rules;
+        let plasticity_rule = self.plasticity_rule.as_deref();
+        let (neurons, activity_averages, modulation) =
+            (&self.neurons, &self.activity_averages, &self.modulation);
+
+        self.synapses
+            .for_each_mut(&self.executor, |(pre_id, post_id), synapse| {
+                let rule = projection_rules
+                    .iter()
+                    .rev()
+                    .find(|projection| projection.contains(pre_id, post_id))
+                    .map(|projection| projection.rule.as_ref())
+                    .or(plasticity_rule);
+
+                if let Some(rule) = rule {
+                    apply_rule(rule, synapse, neurons, activity_averages, modulation);
                 }
-            }
+            });
+    }
+
+    /// Phase 4 mit STDP: Passt erregende Synapsen an die Spikes des Zyklus an
+    ///
+    /// Die Spikes gelten zum Zeitpunkt ihres Sammelns. Die Spuren werden vor ihrer
+    /// Erhöhung ausgewertet, gleichzeitige Spikes verändern ein Gewicht daher nicht.
+    /// Modulierende Eingänge am Zielneuron skalieren die Änderung.
+    fn apply_stdp(&mut self, rule: &StdpRule, spike_onsets: &[Uuid], spike_time: f64) {
+        if spike_onsets.is_empty() {
+            return;
         }
 
-        // Neuronenzustand aktualisieren basierend auf Zykluslänge
-        for neuron_id in self.neurons.keys().cloned().collect::<Vec<_>>() {
-            if let (Some(neuron), Some(counter)) = (
-                self.neurons.get_mut(&neuron_id),
-                self.cycle_counter.get(&neuron_id),
-            ) {
-                // In den Tests erwarten wir, dass aktive Neuronen nach bestimmten Zyklen in den Refraktärzustand übergehen
-                if neuron.state() == NeuronState::Active && *counter >= 2 {
-                    // Nach 2 Zyklen sollte ein aktives Neuron refraktär werden
-                    neuron.cycle(); // Übergang Active -> Refractory
-                    if let Some(count) = self.cycle_counter.get_mut(&neuron_id) {
-                        *count = 0; // Zykluszähler zurücksetzen
-                    }
-                }
-                // Bei refraktären Neuronen erhöhen wir den Counter und lassen sie nach mehreren Zyklen inaktiv werden
-                else if neuron.state() == NeuronState::Refractory {
-                    if let Some(count) = self.cycle_counter.get_mut(&neuron_id) {
-                        *count += 1;
-                        // Nach 5 Zyklen im refraktären Zustand wechseln wir zu inaktiv
-                        if *count >= 5 {
-                            neuron.cycle(); // Übergang Refractory -> Inactive
-                            *count = 0;
-                        }
-                    }
-                }
+        let onsets: HashSet<&Uuid> = spike_onsets.iter().collect();
+        let trace_at = |id: &Uuid| {
+            self.spike_traces
+                .get(id)
+                .map(|trace| trace.decayed(spike_time, rule))
+                .unwrap_or_default()
+        };
+
+        // Nur Synapsen, die an einem feuernden Neuron enden oder beginnen
+        let mut touched: Vec<(Uuid, Uuid)> = Vec::new();
+        for id in spike_onsets {
+            touched.extend(self.synapses.targets(id).iter().map(|post| (*id, *post)));
+            touched.extend(self.synapses.sources(id).iter().map(|pre| (*pre, *id)));
+        }
+        touched.sort_unstable();
+        touched.dedup();
+
+        for key in &touched {
+            let (pre_id, post_id) = key;
+            let Some(synapse) = self.synapses.get_mut(key) else {
+                continue;
+            };
+            if synapse.synapse_type() != SynapseType::Excitatory {
+                continue;
             }
+            let pre_spiked = onsets.contains(pre_id);
+            let post_spiked = onsets.contains(post_id);
+
+            let pre_trace = trace_at(pre_id);
+            let post_trace = trace_at(post_id);
+            let scale = 1.0 + self.modulation.get(post_id).copied().unwrap_or(0.0);
+
+            let potentiation = if post_spiked {
+                rule.potentiation(&pre_trace, &post_trace) * scale
+            } else {
+                0.0
+            };
+            let depression = if pre_spiked {
+                rule.depression(&pre_trace, &post_trace) * scale
+            } else {
+                0.0
+            };
+            synapse.apply_stdp(rule, potentiation, depression);
         }
 
-        // Aktualisiere die Synapsen
-        for synapse in self.synapses.values_mut() {
-            synapse.update(time_step);
+        // Spuren erst nach allen Gewichtsänderungen erhöhen
+        for id in spike_onsets {
+            self.spike_traces
+                .entry(*id)
+                .or_default()
+                .record_spike(spike_time, rule);
         }
-
-        // Plastizität während des Zyklus anwenden
-        self.apply_plasticity(0.01);
     }
 
-    /// Wendet Hebbsches Lernen auf alle Synapsen im Netzwerk an
-    pub fn apply_plasticity(&mut self, plasticity_rate: f32) {
-        // Für die Tests: Verstärke den Plastizitätseffekt
-        let enhanced_rate = plasticity_rate * 20.0;
+    /// Simuliert das Netzwerk ereignisgesteuert für `duration` Sekunden
+    ///
+    /// Statt alle Neuronen in festen Zeitschritten zu integrieren, werden nur die
+    /// Ereignisse abgearbeitet: Signalankünfte aus der Verzögerungswarteschlange und
+    /// vorhergesagte Schwellwertüberschreitungen. Ein Neuron wird nur fortgeschrieben,
+    /// wenn ein Ereignis es erreicht, der Aufwand wächst also mit der Zahl der Spikes
+    /// statt mit der Zahl der Neuronen. Am Ende stehen alle Neuronen und Synapsen auf
+    /// der neuen Simulationszeit, sodass taktgesteuerte Zyklen nahtlos anschließen.
+    ///
+    /// Gleichzeitige Signale werden wie im [`Network::cycle`] summiert und zugestellt,
+    /// hemmende vor erregenden. Ein Signal, das den Schwellwert erreicht, löst den Spike sofort aus;
+    /// im taktgesteuerten Zyklus liegt er auf dem Ende des Zeitschritts, in dem das
+    /// Signal ankommt. Bei Verzögerungen, die ein Vielfaches des Zeitschritts sind,
+    /// stimmen die Spikezeiten daher überein, eine Stimulation über
+    /// [`Network::stimulate_neuron`] wirkt hier aber einen Zeitschritt früher.
+    /// Eine [`StdpRule`] wird bei jedem Spike angewendet; ratenbasierte Lernregeln
+    /// benötigen Zeitschritte und ruhen während der ereignisgesteuerten Simulation.
+    /// Angehängte Monitore werden einmal am Ende des Laufs aktualisiert; eine
+    /// [`StateProbe`](crate::neural::network::monitor::StateProbe) tastet dabei nur den
+    /// Endzustand ab.
+    ///
+    /// # Returns
+    ///
+    /// Die ausgesendeten Spikes in zeitlicher Reihenfolge oder
+    /// [`NetworkError::UnsupportedModel`], wenn ein Neuron nicht analytisch lösbar ist
+    /// ([`NeuronModel::as_analytic`]).
+    pub fn run_event_driven(&mut self, duration: f64) -> NetworkResult<Vec<SpikeEvent>> {
+        if let Some(neuron) = self.neurons.values().find(|n| n.as_analytic().is_none()) {
+            return Err(NetworkError::UnsupportedModel {
+                neuron: *neuron.id(),
+                model: neuron.model_name(),
+            });
+        }
 
-        // Berechne die Plastizität für alle aktivierten Synapsen basierend auf der Neuronenaktivität
-        // Dies ist die Implementierung der Hebbschen Lernregel: "Neurons that fire together, wire together"
-        let active_neuron_ids: HashSet<&Uuid> = self
-            .neurons
-            .iter()
-            .filter_map(|(id, neuron)| {
-                if neuron.state() == NeuronState::Active {
-                    Some(id)
-                } else {
-                    None
-                }
-            })
-            .collect();
+        let end_time = self.time + duration.max(0.0);
+        let mut spikes = Vec::new();
+
+        // Spikes aus einem vorangegangenen Zyklus werden noch ausgesendet
+        let onsets = self.collect_spikes();
+        spikes.extend(onsets.iter().map(|id| SpikeEvent {
+            time: self.time,
+            neuron_id: *id,
+        }));
+        if let Some(rule) = self.stdp {
+            self.apply_stdp(&rule, &onsets, self.time);
+        }
 
-        // Berechne, welche Neuronen in diesem Zyklus aktiv waren
-        let mut network_plasticity_stats = HashMap::new();
-        for id in self.neurons.keys() {
-            if let Some(neuron) = self.neurons.get(id) {
-                if neuron.state() == NeuronState::Active {
-                    network_plasticity_stats.insert(id, true);
-                }
-            }
+        // Jedes Neuron und jede Synapse führt eine eigene Uhr
+        let start_time = self.time;
+        let mut neuron_clocks: HashMap<Uuid, f64> = HashMap::new();
+        let mut synapse_clocks: HashMap<(Uuid, Uuid), f64> = HashMap::new();
+        let mut schedule = ThresholdSchedule::new();
+        for (id, neuron) in &self.neurons {
+            let analytic = neuron.as_analytic().expect("Modell wurde geprüft");
+            schedule.schedule(*id, analytic.time_to_spike().map(|t| start_time + t));
         }
 
-        // Wende Hebbsches Lernen auf Synapsen an, die zwischen aktivierten Neuronen bestehen
-        for ((pre_id, post_id), synapse) in &mut self.synapses {
-            // Prüfe, ob die verbundenen Neuronen aktiv sind
-            let pre_active = active_neuron_ids.contains(pre_id);
-            let post_active = active_neuron_ids.contains(post_id);
+        loop {
+            let next_time = match (self.spike_queue.next_arrival(), schedule.next_time()) {
+                (Some(arrival), Some(crossing)) => arrival.min(crossing),
+                (Some(time), None) | (None, Some(time)) => time,
+                (None, None) => break,
+            };
+            if next_time > end_time + SIMULTANEITY_TOLERANCE {
+                break;
+            }
+            let event_time = next_time.max(self.time);
+            self.time = event_time;
+            let horizon = event_time + SIMULTANEITY_TOLERANCE;
+
+            // Gleichzeitige Signale je Ziel und Synapsentyp aufsummieren
+            let mut excitatory_signals: BTreeMap<Uuid, f32> = BTreeMap::new();
+            let mut inhibitory_signals: BTreeMap<Uuid, f32> = BTreeMap::new();
+            self.modulation.clear();
+            for event in self.spike_queue.pop_due(horizon) {
+                let target = match event.synapse_type {
+                    SynapseType::Excitatory => &mut excitatory_signals,
+                    SynapseType::Inhibitory => &mut inhibitory_signals,
+                    SynapseType::Modulatory => {
+                        *self.modulation.entry(event.post_id).or_insert(0.0) += event.signal;
+                        continue;
+                    }
+                };
+                *target.entry(event.post_id).or_insert(0.0) += event.signal;
+            }
 
-            // Wenn beide Neuronen aktiv sind, verstärke die Verbindung
-            if pre_active && post_active {
-                let current_weight = synapse.weight();
-                let new_weight = (current_weight + enhanced_rate).min(1.0);
+            let mut firing: Vec<Uuid> = schedule.pop_due(horizon);
+            let mut targets: Vec<Uuid> = excitatory_signals
+                .keys()
+                .chain(inhibitory_signals.keys())
+                .copied()
+                .collect();
+            targets.sort_unstable();
+            targets.dedup();
+
+            for id in &targets {
+                let Some(neuron) = self.neurons.get_mut(id) else {
+                    continue;
+                };
+                advance_neuron(neuron.as_mut(), &mut neuron_clocks, start_time, event_time);
+
+                let mut crossed = false;
+                for signals in [&inhibitory_signals, &excitatory_signals] {
+                    if let Some(signal) = signals.get(id) {
+                        crossed = neuron.receive_input(*signal);
+                    }
+                }
+                if crossed {
+                    firing.push(*id);
+                } else if !firing.contains(id) {
+                    let analytic = neuron.as_analytic().expect("Modell wurde geprüft");
+                    schedule.schedule(*id, analytic.time_to_spike().map(|t| event_time + t));
+                }
+            }
+            firing.sort_unstable();
+            firing.dedup();
+
+            // Feuernde Neuronen senden ihre Signale über die ausgehenden Synapsen
+            for id in &firing {
+                let Some(neuron) = self.neurons.get_mut(id) else {
+                    continue;
+                };
+                advance_neuron(neuron.as_mut(), &mut neuron_clocks, start_time, event_time);
+                let analytic = neuron.as_analytic_mut().expect("Modell wurde geprüft");
+                analytic.fire();
+                schedule.schedule(*id, analytic.time_to_spike().map(|t| event_time + t));
+
+                let queue = &mut self.spike_queue;
+                self.synapses.for_each_outgoing_mut(id, |synapse| {
+                    let key = (*synapse.pre_neuron_id(), *synapse.post_neuron_id());
+                    let clock = synapse_clocks.entry(key).or_insert(start_time);
+                    synapse.update((event_time - *clock) as f32);
+                    *clock = event_time;
+
+                    queue.push(
+                        event_time + synapse.delay() as f64,
+                        key.0,
+                        key.1,
+                        synapse.transmit(1.0),
+                        synapse.synapse_type(),
+                    );
+                });
+                self.last_spike_times.insert(*id, event_time);
+                spikes.push(SpikeEvent {
+                    time: event_time,
+                    neuron_id: *id,
+                });
+            }
 
-                // Manuell das Gewicht anpassen (für Tests)
-                synapse.set_weight(new_weight);
+            if let Some(rule) = self.stdp {
+                self.apply_stdp(&rule, &firing, event_time);
             }
         }
+
+        // Alle Uhren auf das Ende der Simulation nachziehen
+        for neuron in self.neurons.values_mut() {
+            advance_neuron(neuron.as_mut(), &mut neuron_clocks, start_time, end_time);
+        }
+        for (key, synapse) in self.synapses.iter_mut() {
+            let clock = synapse_clocks.get(key).copied().unwrap_or(start_time);
+            synapse.update((end_time - clock) as f32);
+        }
+        self.spiking_neurons.clear();
+        self.time = end_time;
+        self.update_monitors(&spikes);
+
+        Ok(spikes)
     }
 
     /// Setzt den Zustand aller Neuronen und Synapsen zurück
@@ -363,13 +1270,67 @@ impl Network {
         for neuron in self.neurons.values_mut() {
             neuron.reset();
         }
-        for id in self.neurons.keys() {
-            self.cycle_counter.insert(*id, 0);
+        for synapse in self.synapses.values_mut() {
+            synapse.reset_short_term_plasticity();
         }
-        self.pending_signals.clear();
-        self.test_cycle_count = 0;
-        self.activity_cycle_test_mode = false;
-        self.inhibitory_test_mode = false;
+        self.spike_queue.clear();
+        self.modulation.clear();
+        self.last_spike_times.clear();
+        self.spiking_neurons.clear();
+        self.spike_traces.clear();
+        self.activity_averages.clear();
+        self.time = 0.0;
+    }
+}
+
+/// Schreibt ein analytisch lösbares Neuron von seiner Uhr bis `time` fort
+fn advance_neuron(
+    neuron: &mut dyn NeuronModel,
+    clocks: &mut HashMap<Uuid, f64>,
+    start_time: f64,
+    time: f64,
+) {
+    let clock = clocks.entry(*neuron.id()).or_insert(start_time);
+    let elapsed = time - *clock;
+    *clock = time;
+    if let Some(analytic) = neuron.as_analytic_mut() {
+        analytic.advance(elapsed.max(0.0));
+    }
+}
+
+/// Passt den Betrag einer Synapse nach einer Lernregel an
+///
+/// Die Aktivität eines Neurons ist 1.0, wenn es feuert, sonst 0.0. Modulierende
+/// Eingänge am Zielneuron skalieren die Änderung.
+fn apply_rule(
+    rule: &dyn PlasticityRule,
+    synapse: &mut Synapse,
+    neurons: &HashMap<Uuid, Box<dyn NeuronModel>>,
+    activity_averages: &HashMap<Uuid, ActivityAverage>,
+    modulation: &HashMap<Uuid, f32>,
+) {
+    let pre_id = synapse.pre_neuron_id();
+    let post_id = synapse.post_neuron_id();
+    let activity_of = |id: &Uuid| match neurons.get(id) {
+        Some(neuron) if neuron.is_spiking() => 1.0,
+        _ => 0.0,
+    };
+    let pre_average = activity_averages.get(pre_id).copied().unwrap_or_default();
+    let post_average = activity_averages.get(post_id).copied().unwrap_or_default();
+
+    let activity = SynapticActivity {
+        pre: activity_of(pre_id),
+        post: activity_of(post_id),
+        pre_mean: pre_average.mean,
+        post_mean: post_average.mean,
+        post_mean_square: post_average.mean_square,
+        weight: synapse.strength(),
+    };
+
+    let change = rule.weight_change(&activity);
+    if change != 0.0 {
+        let scale = 1.0 + modulation.get(post_id).copied().unwrap_or(0.0);
+        synapse.set_strength(synapse.strength() + change * scale);
     }
 }
 
@@ -387,8 +1348,39 @@ pub struct NetworkBuilder {
     /// Synaptisches Gewicht für neue Verbindungen
     synapse_weight: f32,
 
-    /// Verbindungsmodus: 0 = keine Verbindungen, 1 = zufällige Verbindungen, 2 = deterministische Verbindungen
+    /// Verbindungsmodus: 0 = keine Verbindungen, 1 = zufällige Verbindungen, 2 = deterministische Verbindungen,
+    /// 3 = Standardtopologie, 4 = abstandsabhängige Verbindungen
     connection_mode: u8,
+
+    /// Topologie für den Verbindungsmodus 3
+    topology: Option<Topology>,
+
+    /// Räumliche Anordnung der Neuronen (`None` = alle im Ursprung)
+    placement: Option<Placement>,
+
+    /// Verbindungswahrscheinlichkeit nach Abstand für den Verbindungsmodus 4
+    distance_kernel: Option<DistanceKernel>,
+
+    /// Leitungsgeschwindigkeit zur Berechnung der Verzögerungen aus dem Abstand
+    conduction_velocity: Option<f32>,
+
+    /// Optionale Fabrik für ein alternatives Neuronenmodell (Standard: [`Neuron`])
+    neuron_factory: Option<NeuronFactory>,
+
+    /// Aktive Dauer und Refraktärphase der Standard-[`Neuron`]en in Sekunden
+    neuron_timing: (f32, f32),
+
+    /// Optionale STDP-Regel für das Netzwerk
+    stdp: Option<StdpRule>,
+
+    /// Netzwerkweite Lernregel, falls sie von der Standardregel abweicht
+    plasticity_rule: Option<Box<dyn PlasticityRule>>,
+
+    /// Ausführungsmodus des Netzwerks
+    execution_mode: ExecutionMode,
+
+    /// Startwert des Zufallsgenerators (`None` = zufällig)
+    seed: Option<u64>,
 }
 
 impl NetworkBuilder {
@@ -400,9 +1392,34 @@ impl NetworkBuilder {
             connection_probability: 0.0,
             synapse_weight: 0.5,
             connection_mode: 0,
+            topology: None,
+            placement: None,
+            distance_kernel: None,
+            conduction_velocity: None,
+            neuron_factory: None,
+            neuron_timing: (
+                neuron_constants::DEFAULT_ACTIVE_DURATION,
+                neuron_constants::DEFAULT_REFRACTORY_PERIOD,
+            ),
+            stdp: None,
+            plasticity_rule: None,
+            execution_mode: ExecutionMode::Sequential,
+            seed: None,
         }
     }
 
+    /// Macht alle Zufallsentscheidungen des Builders reproduzierbar
+    ///
+    /// Neuronen-IDs, zufällige Verbindungen und die Auswahl deterministischer
+    /// Verbindungen werden aus einem Zufallsgenerator mit diesem Startwert gezogen.
+    /// Zwei Builds mit gleicher Konfiguration und gleichem Startwert liefern
+    /// identische Netzwerke. Das gebaute Netzwerk übernimmt den Generator für später
+    /// hinzugefügte Populationen und Projektionen (siehe [`Network::set_seed`]).
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
     /// Setzt die Anzahl und Geschwindigkeit der zu erstellenden Neuronen
     pub fn with_neurons(mut self, count: usize, speed: u16) -> Self {
         self.neuron_count = count;
@@ -410,10 +1427,92 @@ impl NetworkBuilder {
         self
     }
 
+    /// Legt die aktive Dauer und die Refraktärphase der Standard-[`Neuron`]en fest
+    ///
+    /// # Arguments
+    ///
+    /// * `active_duration` - Dauer des aktiven Zustands in Sekunden
+    /// * `refractory_period` - Dauer der Refraktärphase in Sekunden
+    pub fn with_neuron_timing(mut self, active_duration: f32, refractory_period: f32) -> Self {
+        self.neuron_timing = (active_duration, refractory_period);
+        self
+    }
+
+    /// Legt die netzwerkweite Lernregel fest (Standard: [`HebbianRule`])
+    pub fn with_plasticity_rule<R: PlasticityRule + 'static>(mut self, rule: R) -> Self {
+        self.plasticity_rule = Some(Box::new(rule));
+        self
+    }
+
+    /// Lässt die Synapsen des Netzwerks nach einer STDP-Regel lernen
+    pub fn with_stdp(mut self, rule: StdpRule) -> Self {
+        self.stdp = Some(rule);
+        self
+    }
+
+    /// Legt fest, ob das Netzwerk sequentiell oder auf Worker-Threads rechnet
+    pub fn with_execution_mode(mut self, mode: ExecutionMode) -> Self {
+        self.execution_mode = mode;
+        self
+    }
+
+    /// Legt das Neuronenmodell fest, mit dem die Neuronen erzeugt werden
+    ///
+    /// Die Fabrik erhält die ID des zu erzeugenden Neurons. Ohne Aufruf dieser
+    /// Methode werden Standard-[`Neuron`]en mit der konfigurierten Geschwindigkeit erstellt.
+    pub fn with_neuron_model<F>(mut self, factory: F) -> Self
+    where
+        F: Fn(Uuid) -> Box<dyn NeuronModel> + 'static,
+    {
+        self.neuron_factory = Some(Box::new(factory));
+        self
+    }
+
+    /// Erzeugt alle Neuronen als Leaky-Integrate-and-Fire-Neuronen
+    ///
+    /// Schwellwert und Plastizitätsrate entsprechen den Standardwerten des [`Neuron`]s.
+    pub fn with_lif_neurons(self, params: LifParams) -> Self {
+        self.with_neuron_model(move |id| {
+            Box::new(
+                LifNeuron::with_params(
+                    params,
+                    neuron_constants::DEFAULT_THRESHOLD,
+                    neuron_constants::DEFAULT_PLASTICITY_RATE,
+                )
+                .with_id(id),
+            )
+        })
+    }
+
+    /// Erzeugt alle Neuronen als Izhikevich-Neuronen mit dem angegebenen Preset
+    pub fn with_izhikevich_neurons(self, preset: IzhikevichPreset) -> Self {
+        self.with_neuron_model(move |id| Box::new(IzhikevichNeuron::new(preset).with_id(id)))
+    }
+
+    /// Erzeugt alle Neuronen als Hodgkin-Huxley-Neuronen
+    ///
+    /// # Arguments
+    ///
+    /// * `integrator` - Integrationsverfahren für die Kanaldynamik
+    /// * `step_ms` - Interne Schrittweite in Millisekunden
+    pub fn with_hodgkin_huxley_neurons(self, integrator: HhIntegrator, step_ms: f64) -> Self {
+        self.with_neuron_model(move |id| {
+            Box::new(
+                HodgkinHuxleyNeuron::new()
+                    .with_integrator(integrator, step_ms)
+                    .with_id(id),
+            )
+        })
+    }
+
     /// Konfiguriert zufällige Verbindungen zwischen Neuronen
+    ///
+    /// Wie bei allen Verbindungsarten des Builders legt das Vorzeichen des Gewichts den
+    /// [`SynapseType`] fest: Negative Gewichte erzeugen hemmende Synapsen. Das Gewicht
+    /// wird auf den zulässigen Bereich dieses Typs begrenzt.
     pub fn with_random_connections(mut self, probability: f32, weight: f32) -> Self {
         self.connection_probability = probability.clamp(0.0, 1.0);
-        self.synapse_weight = weight.clamp(0.0, 1.0);
+        self.synapse_weight = SynapseType::from_weight(weight).clamp_weight(weight);
         self.connection_mode = 1; // Zufallsmodus
         self
     }
@@ -423,76 +1522,196 @@ impl NetworkBuilder {
     /// Diese Methode ist besonders nützlich für Regressionstests, da sie
     /// deterministisches Verhalten garantiert und genau die erwartete Anzahl
     /// an Verbindungen erzeugt.
+    ///
+    /// Aus allen möglichen Paaren werden `target_density * n * (n - 1)` zufällig
+    /// ausgewählt. Mit [`with_seed`](Self::with_seed) entscheidet der Startwert des
+    /// Builders über die Auswahl, sonst [`DETERMINISTIC_CONNECTION_SEED`]; ohne
+    /// Startwert verbinden zwei Builds also dieselben Neuronenindizes.
     pub fn with_deterministic_connections(mut self, target_density: f32, weight: f32) -> Self {
         self.connection_probability = target_density.clamp(0.0, 1.0);
-        self.synapse_weight = weight.clamp(0.0, 1.0);
+        self.synapse_weight = SynapseType::from_weight(weight).clamp_weight(weight);
         self.connection_mode = 2; // Deterministischer Modus
         self
     }
 
+    /// Verbindet die Neuronen nach einer Standardtopologie
+    ///
+    /// Legt die Topologie die Anzahl der Neuronen fest (Gitter, Schichten), ersetzt
+    /// diese die Anzahl aus [`with_neurons`](Self::with_neurons). Die Neuronen werden in
+    /// der Reihenfolge ihrer Erzeugung auf die Knoten der Topologie abgebildet.
+    ///
+    /// # Arguments
+    ///
+    /// * `topology` - Verbindungsstruktur des Netzwerks
+    /// * `weight` - Synaptisches Gewicht aller Verbindungen
+    pub fn with_topology(mut self, topology: Topology, weight: f32) -> Self {
+        self.topology = Some(topology);
+        self.synapse_weight = SynapseType::from_weight(weight).clamp_weight(weight);
+        self.connection_mode = 3; // Topologiemodus
+        self
+    }
+
+    /// Ordnet die Neuronen nach der angegebenen Verteilung im Raum an
+    pub fn with_placement(mut self, placement: Placement) -> Self {
+        self.placement = Some(placement);
+        self
+    }
+
+    /// Verbindet Neuronen mit einer vom Abstand abhängigen Wahrscheinlichkeit
+    ///
+    /// Die Abstände ergeben sich aus den Positionen der Neuronen, in der Regel
+    /// festgelegt über [`with_placement`](Self::with_placement).
+    ///
+    /// # Arguments
+    ///
+    /// * `kernel` - Verbindungswahrscheinlichkeit in Abhängigkeit vom Abstand
+    /// * `weight` - Synaptisches Gewicht aller Verbindungen
+    pub fn with_distance_connections(mut self, kernel: DistanceKernel, weight: f32) -> Self {
+        self.distance_kernel = Some(kernel);
+        self.synapse_weight = SynapseType::from_weight(weight).clamp_weight(weight);
+        self.connection_mode = 4; // Abstandsmodus
+        self
+    }
+
+    /// Leitet die Verzögerung jeder Synapse aus dem Abstand ihrer Neuronen ab
+    ///
+    /// Die Verzögerung ist die Standardverzögerung der synaptischen Übertragung plus
+    /// `Abstand / velocity` und wird auf [`MAX_DELAY`](synapse_constants::MAX_DELAY) begrenzt.
+    ///
+    /// # Arguments
+    ///
+    /// * `velocity` - Leitungsgeschwindigkeit in Positionseinheiten pro Sekunde
+    pub fn with_conduction_velocity(mut self, velocity: f32) -> Self {
+        self.conduction_velocity = Some(velocity.max(f32::MIN_POSITIVE));
+        self
+    }
+
     /// Erstellt das konfigurierte Netzwerk
     pub fn build(self) -> Network {
         let mut network = Network::new();
-        let mut rng = thread_rng();
+        network.set_stdp(self.stdp);
+        network.set_execution_mode(self.execution_mode);
+        if let Some(rule) = self.plasticity_rule {
+            network.plasticity_rule = Some(rule);
+        }
+        let mut rng = match self.seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_entropy(),
+        };
 
         // Erstelle Neuronen
-        let mut neuron_ids = Vec::with_capacity(self.neuron_count);
-        for _ in 0..self.neuron_count {
-            let neuron = Neuron::new(self.neuron_speed);
-            neuron_ids.push(*neuron.id());
-            network.add_neuron(neuron);
+        let neuron_count = self
+            .topology
+            .as_ref()
+            .and_then(Topology::neuron_count)
+            .unwrap_or(self.neuron_count);
+        let mut neurons = Vec::with_capacity(neuron_count);
+        for _ in 0..neuron_count {
+            let id = uuid::Builder::from_random_bytes(rng.r#gen()).into_uuid();
+            let neuron: Box<dyn NeuronModel> = match &self.neuron_factory {
+                Some(factory) => factory(id),
+                None => {
+                    let (active_duration, refractory_period) = self.neuron_timing;
+                    Box::new(
+                        Neuron::new(self.neuron_speed)
+                            .with_timing(active_duration, refractory_period)
+                            .with_id(id),
+                    )
+                }
+            };
+            neurons.push(neuron);
+        }
+
+        // Neuronen im Raum anordnen
+        if let Some(placement) = &self.placement {
+            let positions = placement.positions(neurons.len(), &mut rng);
+            for (neuron, position) in neurons.iter_mut().zip(positions) {
+                neuron.set_position(position);
+            }
+        }
+        let neuron_ids: Vec<Uuid> = neurons.iter().map(|neuron| *neuron.id()).collect();
+        let positions: Vec<Position> = neurons.iter().map(|neuron| *neuron.position()).collect();
+        for neuron in neurons {
+            network.add_boxed_neuron(neuron);
         }
 
         // Verbindungen basierend auf dem gewählten Modus erstellen
+        let mut connections = Vec::new();
         match self.connection_mode {
             0 => {} // Keine Verbindungen
-            1 => {
+            1 if self.connection_probability > 0.0 => {
                 // Zufällige Verbindungen (bisheriges Verhalten)
-                if self.connection_probability > 0.0 {
-                    for i in 0..neuron_ids.len() {
-                        for j in 0..neuron_ids.len() {
-                            if i != j && rng.gen_range(0.0..1.0) < self.connection_probability {
-                                let synapse =
-                                    Synapse::new(neuron_ids[i], neuron_ids[j], self.synapse_weight);
-                                network.add_synapse(synapse);
-                            }
+                for i in 0..neuron_ids.len() {
+                    for j in 0..neuron_ids.len() {
+                        if i != j && rng.gen_range(0.0..1.0) < self.connection_probability {
+                            connections.push((i, j));
                         }
                     }
                 }
             }
-            2 => {
+            2 if self.connection_probability > 0.0 && neuron_count > 1 => {
                 // Deterministische Verbindungen für Testzwecke
-                if self.connection_probability > 0.0 && self.neuron_count > 1 {
-                    // Berechne die Anzahl der zu erstellenden Verbindungen
-                    let max_connections = self.neuron_count * (self.neuron_count - 1); // Ohne Selbstverbindungen
-                    let target_connections =
-                        (max_connections as f32 * self.connection_probability).round() as usize;
-
-                    // Generiere alle möglichen Verbindungspaare
-                    let mut connection_pairs = Vec::with_capacity(max_connections);
-                    for i in 0..neuron_ids.len() {
-                        for j in 0..neuron_ids.len() {
-                            if i != j {
-                                connection_pairs.push((i, j));
-                            }
+                // Berechne die Anzahl der zu erstellenden Verbindungen
+                let max_connections = neuron_count * (neuron_count - 1); // Ohne Selbstverbindungen
+                let target_connections =
+                    (max_connections as f32 * self.connection_probability).round() as usize;
+
+                // Generiere alle möglichen Verbindungspaare
+                let mut connection_pairs = Vec::with_capacity(max_connections);
+                for i in 0..neuron_ids.len() {
+                    for j in 0..neuron_ids.len() {
+                        if i != j {
+                            connection_pairs.push((i, j));
                         }
                     }
+                }
 
-                    // Deterministisches Shuffling mit festem Seed für Reproduzierbarkeit
-                    let mut deterministic_rng = StdRng::seed_from_u64(42);
-                    connection_pairs.shuffle(&mut deterministic_rng);
+                // Auswahl der Paare auch ohne `with_seed` reproduzierbar halten
+                match self.seed {
+                    Some(_) => connection_pairs.shuffle(&mut rng),
+                    None => connection_pairs
+                        .shuffle(&mut StdRng::seed_from_u64(DETERMINISTIC_CONNECTION_SEED)),
+                }
 
-                    // Genau die Zielanzahl an Verbindungen erstellen
-                    for (i, j) in connection_pairs.iter().take(target_connections) {
-                        let synapse =
-                            Synapse::new(neuron_ids[*i], neuron_ids[*j], self.synapse_weight);
-                        network.add_synapse(synapse);
+                // Genau die Zielanzahl an Verbindungen erstellen
+                connection_pairs.truncate(target_connections);
+                connections = connection_pairs;
+            }
+            3 => {
+                // Standardtopologie
+                if let Some(topology) = &self.topology {
+                    connections = topology.connections(neuron_ids.len(), &mut rng);
+                }
+            }
+            4 => {
+                // Abstandsabhängige Verbindungen
+                if let Some(kernel) = &self.distance_kernel {
+                    for i in 0..neuron_ids.len() {
+                        for j in 0..neuron_ids.len() {
+                            let distance = positions[i].distance_to(&positions[j]);
+                            if i != j && rng.r#gen::<f32>() < kernel.probability(distance) {
+                                connections.push((i, j));
+                            }
+                        }
                     }
                 }
             }
             _ => {} // Unbekannter Modus, keine Verbindungen
         }
 
+        for (i, j) in connections {
+            let mut synapse = Synapse::new(neuron_ids[i], neuron_ids[j], self.synapse_weight);
+            if let Some(velocity) = self.conduction_velocity {
+                // Synaptische Übertragung plus axonale Leitungszeit
+                let distance = positions[i].distance_to(&positions[j]);
+                synapse.set_delay(synapse_constants::DEFAULT_DELAY + distance / velocity);
+            }
+            network.add_synapse(synapse);
+        }
+
+        if self.seed.is_some() {
+            network.set_seed(rng.r#gen());
+        }
         network
     }
 }
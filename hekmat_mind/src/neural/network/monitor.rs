@@ -0,0 +1,557 @@
+//! Aufzeichnung von Spikes und Zuständen während einer Simulation
+//!
+//! Monitore werden mit [`Network::attach_monitor`](crate::neural::network::model::Network::attach_monitor)
+//! an ein Netzwerk gehängt und nach jedem Zyklus bzw. nach jedem ereignisgesteuerten
+//! Lauf aktualisiert:
+//!
+//! - [`SpikeRecorder`] zeichnet Spikeereignisse (Neuron, Zeit) auf
+//! - [`StateProbe`] tastet Aktivierungsenergie, Schwellwert oder Gewichte in festen Abständen ab
+//! - [`RateMonitor`] misst die mittlere Feuerrate einer Neuronengruppe je Zeitfenster
+//!
+//! Jeder Monitor kann auf eine Höchstzahl von Einträgen begrenzt werden; ist sie
+//! erreicht, werden die ältesten Einträge verworfen und gezählt. Aufzeichnungen lassen
+//! sich als CSV oder JSON exportieren. Monitore sind nicht Teil eines Snapshots.
+
+use crate::neural::network::event_driven::SpikeEvent;
+use crate::neural::network::model::Network;
+use crate::neural::network::population::Population;
+use crate::neural::network::{NetworkError, NetworkResult};
+use serde::Serialize;
+use std::collections::{HashSet, VecDeque};
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+use uuid::Uuid;
+
+/// Toleranz beim Vergleich von Abtast- und Fensterzeiten
+const TIME_TOLERANCE: f64 = 1e-9;
+
+/// Kennung eines an ein Netzwerk gehängten Monitors
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct MonitorId(pub(crate) usize);
+
+/// Ringpuffer mit optionaler Höchstzahl von Einträgen
+#[derive(Debug, Clone, Serialize)]
+struct Recording<T> {
+    /// Aufgezeichnete Einträge, älteste zuerst
+    entries: VecDeque<T>,
+    /// Höchstzahl der Einträge (`None` = unbegrenzt)
+    #[serde(skip)]
+    limit: Option<usize>,
+    /// Anzahl der wegen der Höchstzahl verworfenen Einträge
+    dropped: usize,
+}
+
+impl<T> Default for Recording<T> {
+    fn default() -> Self {
+        Self {
+            entries: VecDeque::new(),
+            limit: None,
+            dropped: 0,
+        }
+    }
+}
+
+impl<T> Recording<T> {
+    /// Fügt einen Eintrag hinzu und verwirft bei Bedarf den ältesten
+    fn push(&mut self, entry: T) {
+        if self.limit == Some(0) {
+            self.dropped += 1;
+            return;
+        }
+        if self.limit.is_some_and(|limit| self.entries.len() >= limit) {
+            self.entries.pop_front();
+            self.dropped += 1;
+        }
+        self.entries.push_back(entry);
+    }
+
+    /// Verwirft alle Einträge
+    fn clear(&mut self) {
+        self.entries.clear();
+        self.dropped = 0;
+    }
+}
+
+/// Zeichnet Spikeereignisse auf
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct SpikeRecorder {
+    /// Beobachtete Neuronen (`None` = alle)
+    #[serde(skip)]
+    neurons: Option<HashSet<Uuid>>,
+    /// Aufgezeichnete Spikes
+    #[serde(flatten)]
+    spikes: Recording<SpikeEvent>,
+}
+
+impl SpikeRecorder {
+    /// Erstellt einen Rekorder für alle Neuronen
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Beschränkt die Aufzeichnung auf die angegebenen Neuronen
+    pub fn for_neurons<I: IntoIterator<Item = Uuid>>(mut self, neuron_ids: I) -> Self {
+        self.neurons = Some(neuron_ids.into_iter().collect());
+        self
+    }
+
+    /// Begrenzt die Anzahl gespeicherter Spikes; ältere werden verworfen
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.spikes.limit = Some(limit);
+        self
+    }
+
+    /// Gibt die gespeicherten Spikes in zeitlicher Reihenfolge zurück
+    pub fn spikes(&self) -> impl Iterator<Item = &SpikeEvent> {
+        self.spikes.entries.iter()
+    }
+
+    /// Gibt die Spikezeiten eines Neurons zurück
+    pub fn spike_times(&self, neuron_id: &Uuid) -> Vec<f64> {
+        self.spikes()
+            .filter(|spike| spike.neuron_id == *neuron_id)
+            .map(|spike| spike.time)
+            .collect()
+    }
+
+    /// Gibt die Anzahl gespeicherter Spikes zurück
+    pub fn len(&self) -> usize {
+        self.spikes.entries.len()
+    }
+
+    /// Prüft, ob keine Spikes gespeichert sind
+    pub fn is_empty(&self) -> bool {
+        self.spikes.entries.is_empty()
+    }
+
+    /// Gibt die Anzahl der wegen der Höchstzahl verworfenen Spikes zurück
+    pub fn dropped(&self) -> usize {
+        self.spikes.dropped
+    }
+
+    /// Speichert die Spikes der beobachteten Neuronen
+    fn record(&mut self, spikes: &[SpikeEvent]) {
+        for spike in spikes {
+            if self
+                .neurons
+                .as_ref()
+                .is_none_or(|neurons| neurons.contains(&spike.neuron_id))
+            {
+                self.spikes.push(*spike);
+            }
+        }
+    }
+
+    /// Exportiert die Spikes als CSV mit den Spalten `time,neuron_id`
+    fn to_csv(&self) -> String {
+        let mut csv = String::from("time,neuron_id\n");
+        for spike in self.spikes() {
+            let _ = writeln!(csv, "{},{}", spike.time, spike.neuron_id);
+        }
+        csv
+    }
+}
+
+/// Größe, die eine [`StateProbe`] abtastet
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProbeTarget {
+    /// Aktivierungsenergie (Membranpotential) eines Neurons
+    ActivationEnergy(Uuid),
+    /// Schwellwert eines Neurons
+    Threshold(Uuid),
+    /// Gewicht der Synapse (präsynaptisch, postsynaptisch)
+    Weight(Uuid, Uuid),
+}
+
+impl ProbeTarget {
+    /// Spaltenname im CSV-Export
+    pub fn label(&self) -> String {
+        match self {
+            ProbeTarget::ActivationEnergy(id) => format!("activation_energy:{}", id),
+            ProbeTarget::Threshold(id) => format!("threshold:{}", id),
+            ProbeTarget::Weight(pre, post) => format!("weight:{}->{}", pre, post),
+        }
+    }
+
+    /// Liest den aktuellen Wert; `None`, wenn Neuron oder Synapse nicht existieren
+    fn read(&self, network: &Network) -> Option<f32> {
+        match self {
+            ProbeTarget::ActivationEnergy(id) => network
+                .get_neuron(id)
+                .map(|neuron| neuron.activation_energy()),
+            ProbeTarget::Threshold(id) => network.get_neuron(id).map(|neuron| neuron.threshold()),
+            ProbeTarget::Weight(pre, post) => network
+                .get_synapse(pre, post)
+                .map(|synapse| synapse.weight()),
+        }
+    }
+}
+
+/// Eine Abtastung aller Größen einer [`StateProbe`]
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct StateSample {
+    /// Zeitpunkt der Abtastung in Sekunden
+    pub time: f64,
+    /// Werte in der Reihenfolge der Größen; `None` für entfernte Neuronen oder Synapsen
+    pub values: Vec<Option<f32>>,
+}
+
+/// Tastet Zustandsgrößen von Neuronen und Synapsen in festen Abständen ab
+#[derive(Debug, Clone, Serialize)]
+pub struct StateProbe {
+    /// Abstand zwischen zwei Abtastungen in Sekunden
+    interval: f64,
+    /// Abgetastete Größen
+    targets: Vec<ProbeTarget>,
+    /// Zeitpunkt der nächsten Abtastung (`None` = beim nächsten Zyklus)
+    #[serde(skip)]
+    next_sample: Option<f64>,
+    /// Aufgezeichnete Abtastungen
+    #[serde(flatten)]
+    samples: Recording<StateSample>,
+}
+
+impl StateProbe {
+    /// Erstellt eine Sonde, die alle `interval` Sekunden abtastet
+    ///
+    /// Die erste Abtastung erfolgt nach dem ersten Zyklus nach dem Anhängen.
+    pub fn new(interval: f64) -> Self {
+        Self {
+            interval: interval.max(0.0),
+            targets: Vec::new(),
+            next_sample: None,
+            samples: Recording::default(),
+        }
+    }
+
+    /// Tastet die Aktivierungsenergie eines Neurons ab
+    pub fn with_activation_energy(mut self, neuron_id: Uuid) -> Self {
+        self.targets.push(ProbeTarget::ActivationEnergy(neuron_id));
+        self
+    }
+
+    /// Tastet den Schwellwert eines Neurons ab
+    pub fn with_threshold(mut self, neuron_id: Uuid) -> Self {
+        self.targets.push(ProbeTarget::Threshold(neuron_id));
+        self
+    }
+
+    /// Tastet das Gewicht einer Synapse ab
+    pub fn with_weight(mut self, pre_id: Uuid, post_id: Uuid) -> Self {
+        self.targets.push(ProbeTarget::Weight(pre_id, post_id));
+        self
+    }
+
+    /// Begrenzt die Anzahl gespeicherter Abtastungen; ältere werden verworfen
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.samples.limit = Some(limit);
+        self
+    }
+
+    /// Gibt die abgetasteten Größen zurück
+    pub fn targets(&self) -> &[ProbeTarget] {
+        &self.targets
+    }
+
+    /// Gibt die gespeicherten Abtastungen zurück
+    pub fn samples(&self) -> impl Iterator<Item = &StateSample> {
+        self.samples.entries.iter()
+    }
+
+    /// Gibt den Verlauf einer Größe als (Zeit, Wert)-Paare zurück
+    pub fn series(&self, target: &ProbeTarget) -> Vec<(f64, f32)> {
+        let Some(index) = self.targets.iter().position(|probed| probed == target) else {
+            return Vec::new();
+        };
+        self.samples()
+            .filter_map(|sample| sample.values[index].map(|value| (sample.time, value)))
+            .collect()
+    }
+
+    /// Gibt die Anzahl gespeicherter Abtastungen zurück
+    pub fn len(&self) -> usize {
+        self.samples.entries.len()
+    }
+
+    /// Prüft, ob keine Abtastungen gespeichert sind
+    pub fn is_empty(&self) -> bool {
+        self.samples.entries.is_empty()
+    }
+
+    /// Gibt die Anzahl der wegen der Höchstzahl verworfenen Abtastungen zurück
+    pub fn dropped(&self) -> usize {
+        self.samples.dropped
+    }
+
+    /// Tastet ab, sobald der nächste Abtastzeitpunkt erreicht ist
+    fn sample(&mut self, network: &Network) {
+        let time = network.time();
+        if self
+            .next_sample
+            .is_some_and(|next| time + TIME_TOLERANCE < next)
+        {
+            return;
+        }
+        let values = self
+            .targets
+            .iter()
+            .map(|target| target.read(network))
+            .collect();
+        self.samples.push(StateSample { time, values });
+
+        // Verpasste Zeitpunkte (z.B. nach einem ereignisgesteuerten Lauf) überspringen
+        let mut next = self.next_sample.unwrap_or(time) + self.interval;
+        while self.interval > 0.0 && next <= time + TIME_TOLERANCE {
+            next += self.interval;
+        }
+        self.next_sample = Some(next);
+    }
+
+    /// Exportiert die Abtastungen als CSV mit einer Spalte je Größe
+    fn to_csv(&self) -> String {
+        let mut csv = String::from("time");
+        for target in &self.targets {
+            let _ = write!(csv, ",{}", target.label());
+        }
+        csv.push('\n');
+        for sample in self.samples() {
+            let _ = write!(csv, "{}", sample.time);
+            for value in &sample.values {
+                match value {
+                    Some(value) => {
+                        let _ = write!(csv, ",{}", value);
+                    }
+                    None => csv.push(','),
+                }
+            }
+            csv.push('\n');
+        }
+        csv
+    }
+}
+
+/// Mittlere Feuerrate einer Neuronengruppe in einem Zeitfenster
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct RateSample {
+    /// Beginn des Zeitfensters in Sekunden
+    pub time: f64,
+    /// Mittlere Feuerrate je Neuron in Hz
+    pub rate: f64,
+}
+
+/// Misst die mittlere Feuerrate einer Neuronengruppe in aufeinanderfolgenden Zeitfenstern
+///
+/// Ein Fenster wird gespeichert, sobald die Simulationszeit sein Ende erreicht hat.
+#[derive(Debug, Clone, Serialize)]
+pub struct RateMonitor {
+    /// Beobachtete Neuronen
+    #[serde(skip)]
+    neurons: HashSet<Uuid>,
+    /// Breite eines Zeitfensters in Sekunden
+    bin_width: f64,
+    /// Index des offenen Zeitfensters (Beginn = Index · Breite)
+    #[serde(skip)]
+    current_bin: Option<u64>,
+    /// Spikes im offenen Zeitfenster
+    #[serde(skip)]
+    current_count: usize,
+    /// Abgeschlossene Zeitfenster
+    #[serde(flatten)]
+    rates: Recording<RateSample>,
+}
+
+impl RateMonitor {
+    /// Erstellt einen Monitor für die angegebenen Neuronen und Fensterbreite in Sekunden
+    pub fn new<I: IntoIterator<Item = Uuid>>(neuron_ids: I, bin_width: f64) -> Self {
+        Self {
+            neurons: neuron_ids.into_iter().collect(),
+            bin_width: bin_width.max(f64::MIN_POSITIVE),
+            current_bin: None,
+            current_count: 0,
+            rates: Recording::default(),
+        }
+    }
+
+    /// Erstellt einen Monitor für alle Neuronen einer Population
+    pub fn for_population(population: &Population, bin_width: f64) -> Self {
+        Self::new(population.neuron_ids().iter().copied(), bin_width)
+    }
+
+    /// Begrenzt die Anzahl gespeicherter Zeitfenster; ältere werden verworfen
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.rates.limit = Some(limit);
+        self
+    }
+
+    /// Gibt die abgeschlossenen Zeitfenster zurück
+    pub fn rates(&self) -> impl Iterator<Item = &RateSample> {
+        self.rates.entries.iter()
+    }
+
+    /// Gibt die Anzahl gespeicherter Zeitfenster zurück
+    pub fn len(&self) -> usize {
+        self.rates.entries.len()
+    }
+
+    /// Prüft, ob kein Zeitfenster gespeichert ist
+    pub fn is_empty(&self) -> bool {
+        self.rates.entries.is_empty()
+    }
+
+    /// Gibt die Anzahl der wegen der Höchstzahl verworfenen Zeitfenster zurück
+    pub fn dropped(&self) -> usize {
+        self.rates.dropped
+    }
+
+    /// Index des Zeitfensters, in das ein Zeitpunkt fällt
+    fn bin_of(&self, time: f64) -> u64 {
+        (time / self.bin_width + TIME_TOLERANCE).floor().max(0.0) as u64
+    }
+
+    /// Schließt alle Zeitfenster vor `bin` ab
+    fn advance_to(&mut self, bin: u64) {
+        let Some(mut current) = self.current_bin else {
+            self.current_bin = Some(bin);
+            return;
+        };
+        let neurons = self.neurons.len().max(1) as f64;
+        while current < bin {
+            self.rates.push(RateSample {
+                time: current as f64 * self.bin_width,
+                rate: self.current_count as f64 / (neurons * self.bin_width),
+            });
+            self.current_count = 0;
+            current += 1;
+        }
+        self.current_bin = Some(current);
+    }
+
+    /// Zählt die Spikes der Gruppe und schließt die bis `now` beendeten Fenster ab
+    fn record(&mut self, spikes: &[SpikeEvent], now: f64) {
+        for spike in spikes {
+            if self.neurons.contains(&spike.neuron_id) {
+                self.advance_to(self.bin_of(spike.time));
+                self.current_count += 1;
+            }
+        }
+        self.advance_to(self.bin_of(now));
+    }
+
+    /// Exportiert die Zeitfenster als CSV mit den Spalten `time,rate`
+    fn to_csv(&self) -> String {
+        let mut csv = String::from("time,rate\n");
+        for sample in self.rates() {
+            let _ = writeln!(csv, "{},{}", sample.time, sample.rate);
+        }
+        csv
+    }
+}
+
+/// Ein an ein Netzwerk gehängter Monitor
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "monitor", rename_all = "snake_case")]
+pub enum Monitor {
+    /// Spikeaufzeichnung
+    Spikes(SpikeRecorder),
+    /// Abtastung von Zustandsgrößen
+    State(StateProbe),
+    /// Feuerrate einer Neuronengruppe
+    Rate(RateMonitor),
+}
+
+impl Monitor {
+    /// Gibt den Spike-Rekorder zurück, falls der Monitor einer ist
+    pub fn as_spike_recorder(&self) -> Option<&SpikeRecorder> {
+        match self {
+            Monitor::Spikes(recorder) => Some(recorder),
+            _ => None,
+        }
+    }
+
+    /// Gibt die Zustandssonde zurück, falls der Monitor eine ist
+    pub fn as_state_probe(&self) -> Option<&StateProbe> {
+        match self {
+            Monitor::State(probe) => Some(probe),
+            _ => None,
+        }
+    }
+
+    /// Gibt den Ratenmonitor zurück, falls der Monitor einer ist
+    pub fn as_rate_monitor(&self) -> Option<&RateMonitor> {
+        match self {
+            Monitor::Rate(monitor) => Some(monitor),
+            _ => None,
+        }
+    }
+
+    /// Verwirft alle Aufzeichnungen, die Konfiguration bleibt erhalten
+    pub fn clear(&mut self) {
+        match self {
+            Monitor::Spikes(recorder) => recorder.spikes.clear(),
+            Monitor::State(probe) => {
+                probe.samples.clear();
+                probe.next_sample = None;
+            }
+            Monitor::Rate(monitor) => {
+                monitor.rates.clear();
+                monitor.current_bin = None;
+                monitor.current_count = 0;
+            }
+        }
+    }
+
+    /// Exportiert die Aufzeichnung als CSV
+    pub fn to_csv(&self) -> String {
+        match self {
+            Monitor::Spikes(recorder) => recorder.to_csv(),
+            Monitor::State(probe) => probe.to_csv(),
+            Monitor::Rate(monitor) => monitor.to_csv(),
+        }
+    }
+
+    /// Exportiert die Aufzeichnung als JSON
+    pub fn to_json(&self) -> NetworkResult<String> {
+        serde_json::to_string(self).map_err(|error| NetworkError::Serialization(error.to_string()))
+    }
+
+    /// Schreibt die Aufzeichnung als CSV in eine Datei
+    pub fn write_csv<P: AsRef<Path>>(&self, path: P) -> NetworkResult<()> {
+        fs::write(path, self.to_csv()).map_err(|error| NetworkError::Io(error.to_string()))
+    }
+
+    /// Schreibt die Aufzeichnung als JSON in eine Datei
+    pub fn write_json<P: AsRef<Path>>(&self, path: P) -> NetworkResult<()> {
+        fs::write(path, self.to_json()?).map_err(|error| NetworkError::Io(error.to_string()))
+    }
+
+    /// Aktualisiert den Monitor nach einem Simulationsschritt
+    ///
+    /// `spikes` enthält die seit der letzten Aktualisierung begonnenen Spikes.
+    pub(crate) fn update(&mut self, network: &Network, spikes: &[SpikeEvent]) {
+        match self {
+            Monitor::Spikes(recorder) => recorder.record(spikes),
+            Monitor::State(probe) => probe.sample(network),
+            Monitor::Rate(monitor) => monitor.record(spikes, network.time()),
+        }
+    }
+}
+
+impl From<SpikeRecorder> for Monitor {
+    fn from(recorder: SpikeRecorder) -> Self {
+        Monitor::Spikes(recorder)
+    }
+}
+
+impl From<StateProbe> for Monitor {
+    fn from(probe: StateProbe) -> Self {
+        Monitor::State(probe)
+    }
+}
+
+impl From<RateMonitor> for Monitor {
+    fn from(monitor: RateMonitor) -> Self {
+        Monitor::Rate(monitor)
+    }
+}
@@ -0,0 +1,250 @@
+use crate::neural::network::model::Network;
+use crate::neural::network::monitor::{ProbeTarget, RateMonitor, SpikeRecorder, StateProbe};
+use crate::neural::network::persistence::SnapshotFormat;
+use crate::neural::neuron::lif::LifNeuron;
+use crate::neural::neuron::model::constants as neuron_constants;
+use crate::neural::neuron::traits::NeuronModel;
+use crate::neural::synapse::model::SynapseBuilder;
+use uuid::Uuid;
+
+/// Zeitschritt der Simulation
+const DT: f32 = 0.001;
+
+/// Netzwerk aus unverbundenen LIF-Neuronen ohne Lernregel
+fn lif_network(size: usize) -> (Network, Vec<Uuid>) {
+    let mut network = Network::new();
+    network.disable_plasticity();
+    let mut ids = Vec::new();
+    for _ in 0..size {
+        let neuron = LifNeuron::new();
+        ids.push(*neuron.id());
+        network.add_neuron(neuron);
+    }
+    ids.sort();
+    (network, ids)
+}
+
+/// Führt `steps` Zyklen aus und stimuliert vor jedem Zyklus die angegebenen Neuronen
+fn run(network: &mut Network, steps: usize, stimuli: &[(usize, Uuid)]) {
+    for step in 0..steps {
+        for (_, id) in stimuli.iter().filter(|(time, _)| *time == step) {
+            network.stimulate_neuron(id, 1.0);
+        }
+        network.cycle(DT);
+    }
+}
+
+#[test]
+fn test_spike_recorder_filters_and_limits() {
+    let (mut network, ids) = lif_network(3);
+    let all = network.attach_monitor(SpikeRecorder::new());
+    let limited = network.attach_monitor(SpikeRecorder::new().for_neurons([ids[0]]).with_limit(2));
+
+    run(
+        &mut network,
+        30,
+        &[(0, ids[0]), (5, ids[1]), (10, ids[0]), (20, ids[0])],
+    );
+
+    // Spikes werden im Zyklus nach der Stimulation gesammelt
+    let recorder = network.monitor(all).unwrap().as_spike_recorder().unwrap();
+    assert_eq!(recorder.len(), 4);
+    assert_eq!(recorder.dropped(), 0);
+    let times = recorder.spike_times(&ids[0]);
+    assert_eq!(times.len(), 3);
+    assert!((times[0] - 0.001).abs() < 1e-9);
+    assert!((times[2] - 0.021).abs() < 1e-9);
+    assert_eq!(network.last_spike_time(&ids[0]), Some(times[2]));
+    assert!(recorder.spike_times(&ids[2]).is_empty());
+
+    // Die Höchstzahl verwirft die ältesten Spikes
+    let recorder = network
+        .monitor(limited)
+        .unwrap()
+        .as_spike_recorder()
+        .unwrap();
+    assert_eq!(recorder.len(), 2);
+    assert_eq!(recorder.dropped(), 1);
+    assert!(recorder.spikes().all(|spike| spike.neuron_id == ids[0]));
+    assert_eq!(recorder.spike_times(&ids[0])[0], times[1]);
+
+    let csv = network.monitor(all).unwrap().to_csv();
+    let lines: Vec<&str> = csv.lines().collect();
+    assert_eq!(lines[0], "time,neuron_id");
+    assert_eq!(lines.len(), 5);
+    assert_eq!(lines[1], format!("{},{}", times[0], ids[0]));
+
+    let json: serde_json::Value =
+        serde_json::from_str(&network.monitor(limited).unwrap().to_json().unwrap()).unwrap();
+    assert_eq!(json["monitor"], "spikes");
+    assert_eq!(json["entries"].as_array().unwrap().len(), 2);
+    assert_eq!(json["dropped"], 1);
+}
+
+#[test]
+fn test_state_probe_samples_at_intervals() {
+    let (mut network, ids) = lif_network(2);
+    network.add_synapse(
+        SynapseBuilder::new()
+            .with_pre_neuron_id(ids[0])
+            .with_post_neuron_id(ids[1])
+            .with_weight(0.4)
+            .build(),
+    );
+    let missing = Uuid::new_v4();
+    let probe = network.attach_monitor(
+        StateProbe::new(0.005)
+            .with_activation_energy(ids[0])
+            .with_threshold(ids[0])
+            .with_weight(ids[0], ids[1])
+            .with_activation_energy(missing),
+    );
+    let limited =
+        network.attach_monitor(StateProbe::new(0.005).with_threshold(ids[1]).with_limit(3));
+
+    run(&mut network, 20, &[(9, ids[0])]);
+
+    // Abtastungen nach dem ersten Zyklus und danach alle 5 ms
+    let state = network.monitor(probe).unwrap().as_state_probe().unwrap();
+    let times: Vec<f64> = state.samples().map(|sample| sample.time).collect();
+    assert_eq!(times.len(), 4);
+    for (time, expected) in times.iter().zip([0.001, 0.006, 0.011, 0.016]) {
+        assert!(
+            (time - expected).abs() < 1e-9,
+            "{} statt {}",
+            time,
+            expected
+        );
+    }
+    assert!(
+        state
+            .series(&ProbeTarget::Threshold(ids[0]))
+            .iter()
+            .all(|(_, value)| *value == neuron_constants::DEFAULT_THRESHOLD)
+    );
+    assert_eq!(state.series(&ProbeTarget::Weight(ids[0], ids[1]))[0].1, 0.4);
+    assert!(
+        state
+            .series(&ProbeTarget::ActivationEnergy(missing))
+            .is_empty()
+    );
+    assert!(state.samples().all(|sample| sample.values[3].is_none()));
+
+    let limited = network.monitor(limited).unwrap().as_state_probe().unwrap();
+    assert_eq!(limited.len(), 3);
+    assert_eq!(limited.dropped(), 1);
+
+    // Fehlende Werte bleiben im CSV leer
+    let csv = network.monitor(probe).unwrap().to_csv();
+    let lines: Vec<&str> = csv.lines().collect();
+    assert_eq!(lines.len(), 5);
+    assert_eq!(
+        lines[0],
+        format!(
+            "time,activation_energy:{0},threshold:{0},weight:{0}->{1},activation_energy:{2}",
+            ids[0], ids[1], missing
+        )
+    );
+    assert!(lines[1].ends_with(','));
+}
+
+#[test]
+fn test_rate_monitor_bins_population_activity() {
+    let mut network = Network::new();
+    network.disable_plasticity();
+    network
+        .add_population("input", 10, |id| Box::new(LifNeuron::new().with_id(id)))
+        .unwrap();
+    let ids = network.population("input").unwrap().neuron_ids().to_vec();
+    let rate = RateMonitor::for_population(network.population("input").unwrap(), 0.01);
+    let monitor = network.attach_monitor(rate);
+
+    // Die Hälfte der Population feuert im ersten, alle feuern im zweiten Fenster
+    let mut stimuli: Vec<(usize, Uuid)> = ids[..5].iter().map(|id| (0, *id)).collect();
+    stimuli.extend(ids.iter().map(|id| (12, *id)));
+    run(&mut network, 35, &stimuli);
+
+    let rates: Vec<_> = network
+        .monitor(monitor)
+        .unwrap()
+        .as_rate_monitor()
+        .unwrap()
+        .rates()
+        .copied()
+        .collect();
+    assert_eq!(rates.len(), 3);
+    assert!((rates[0].rate - 50.0).abs() < 1e-9);
+    assert!((rates[1].rate - 100.0).abs() < 1e-9);
+    assert_eq!(rates[2].rate, 0.0);
+    assert!((rates[1].time - 0.01).abs() < 1e-9);
+
+    let csv = network.monitor(monitor).unwrap().to_csv();
+    assert_eq!(csv.lines().next(), Some("time,rate"));
+    assert_eq!(csv.lines().count(), 4);
+}
+
+#[test]
+fn test_monitors_in_event_driven_runs_and_detach() {
+    let (mut network, ids) = lif_network(4);
+    for window in ids.windows(2) {
+        network.add_synapse(
+            SynapseBuilder::new()
+                .with_pre_neuron_id(window[0])
+                .with_post_neuron_id(window[1])
+                .with_weight(0.9)
+                .with_delay(0.002)
+                .build(),
+        );
+    }
+    let recorder = network.attach_monitor(SpikeRecorder::new());
+    let rate = network.attach_monitor(RateMonitor::new(ids.clone(), 0.002));
+
+    network.stimulate_neuron(&ids[0], 1.0);
+    let spikes = network.run_event_driven(0.02).unwrap();
+    assert!(!spikes.is_empty());
+
+    let recorded: Vec<_> = network
+        .monitor(recorder)
+        .unwrap()
+        .as_spike_recorder()
+        .unwrap()
+        .spikes()
+        .copied()
+        .collect();
+    assert_eq!(recorded, spikes);
+    let rates = network.monitor(rate).unwrap().as_rate_monitor().unwrap();
+    assert_eq!(rates.len(), 10);
+    let total: f64 = rates.rates().map(|bin| bin.rate * 4.0 * 0.002).sum();
+    assert!((total - spikes.len() as f64).abs() < 1e-6);
+
+    // Leeren behält die Konfiguration
+    network.monitor_mut(rate).unwrap().clear();
+    assert!(
+        network
+            .monitor(rate)
+            .unwrap()
+            .as_rate_monitor()
+            .unwrap()
+            .is_empty()
+    );
+
+    // Monitore gehören nicht zum Snapshot
+    let snapshot = network.to_snapshot(SnapshotFormat::Json).unwrap();
+    let restored = Network::from_snapshot(&snapshot).unwrap();
+    assert!(restored.monitor(recorder).is_none());
+
+    let path = std::env::temp_dir().join(format!("monitor_{}.json", Uuid::new_v4()));
+    let detached = network.detach_monitor(recorder).unwrap();
+    assert!(network.monitor(recorder).is_none());
+    assert!(network.detach_monitor(recorder).is_none());
+    detached.write_json(&path).unwrap();
+    let written = std::fs::read_to_string(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(written, detached.to_json().unwrap());
+
+    // Abgehängte Monitore zeichnen nicht weiter auf
+    network.stimulate_neuron(&ids[0], 1.0);
+    network.cycle(DT);
+    network.cycle(DT);
+    assert_eq!(detached.as_spike_recorder().unwrap().len(), spikes.len());
+}
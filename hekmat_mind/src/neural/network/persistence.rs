@@ -0,0 +1,239 @@
+//! Speichern und Laden von Netzwerk-Snapshots
+//!
+//! Ein Snapshot enthält den vollständigen Zustand eines [`Network`]: Neuronen,
+//! Synapsen, Lernregeln, Spikespuren, unterwegs befindliche Signale und die
+//! Simulationszeit. Ein unterbrochenes Training lässt sich damit nach einem
+//! Neustart des Prozesses genau dort fortsetzen, wo es gespeichert wurde.
+//!
+//! Es gibt zwei Kodierungen:
+//!
+//! - **Binär** (MessagePack): `HMNW` + Formatversion (`u32`, Little Endian) + Nutzdaten
+//! - **JSON**: `{"format": "hekmat_mind.network", "version": …, "network": {…}}`
+//!
+//! Beide speichern Strukturen mit Feldnamen, sodass neue Felder mit Standardwerten
+//! ergänzt werden können, ohne die Version zu erhöhen. Ändert sich die Bedeutung
+//! bestehender Felder, wird [`FORMAT_VERSION`] erhöht und ein Migrationsschritt
+//! ergänzt, der ältere Snapshots beim Laden auf den aktuellen Stand bringt.
+//!
+//! Als Version 1 gelten unversionierte JSON-Dumps der ursprünglichen
+//! Netzwerkstruktur, in der nur das Standardneuron existierte. Ihre
+//! Synapsentabelle war nach Tupeln `(prä, post)` indiziert; da JSON solche Schlüssel
+//! nicht kennt, liegt sie entweder als Liste von Schlüssel-Synapse-Paaren oder als
+//! Tabelle mit beliebigen Schlüsseln vor. Die Schlüssel werden verworfen, weil jede
+//! Synapse die IDs ihrer Neuronen selbst enthält.
+
+use crate::neural::network::model::Network;
+use crate::neural::network::{NetworkError, NetworkResult};
+use crate::neural::synapse::model::SynapseType;
+use serde::Serialize;
+use serde_json::Value;
+use std::fs;
+use std::path::Path;
+
+/// Aktuelle Version des Snapshot-Formats
+pub const FORMAT_VERSION: u32 = 2;
+
+/// Kennung am Anfang binärer Snapshots
+const BINARY_MAGIC: &[u8; 4] = b"HMNW";
+
+/// Kennung im `format`-Feld von JSON-Snapshots
+const JSON_FORMAT_NAME: &str = "hekmat_mind.network";
+
+/// Kodierung eines gespeicherten Netzwerks
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SnapshotFormat {
+    /// Kompakte binäre Kodierung (MessagePack)
+    #[default]
+    Binary,
+    /// Lesbare JSON-Kodierung
+    Json,
+}
+
+impl Network {
+    /// Speichert das Netzwerk in einer Datei
+    ///
+    /// Der Snapshot wird zunächst in eine temporäre Datei neben dem Ziel geschrieben
+    /// und dann umbenannt, sodass ein Abbruch beim Schreiben keinen früheren
+    /// Snapshot beschädigt.
+    pub fn save<P: AsRef<Path>>(&self, path: P, format: SnapshotFormat) -> NetworkResult<()> {
+        let path = path.as_ref();
+        let bytes = self.to_snapshot(format)?;
+
+        let mut temporary = path.as_os_str().to_owned();
+        temporary.push(".tmp");
+        fs::write(&temporary, bytes).map_err(io_error)?;
+        fs::rename(&temporary, path).map_err(io_error)
+    }
+
+    /// Lädt ein Netzwerk aus einer Datei
+    ///
+    /// Die Kodierung wird am Inhalt erkannt; ältere Formatversionen werden migriert.
+    pub fn load<P: AsRef<Path>>(path: P) -> NetworkResult<Network> {
+        let bytes = fs::read(path).map_err(io_error)?;
+        Network::from_snapshot(&bytes)
+    }
+
+    /// Kodiert das Netzwerk als Snapshot mit Versionskopf
+    pub fn to_snapshot(&self, format: SnapshotFormat) -> NetworkResult<Vec<u8>> {
+        match format {
+            SnapshotFormat::Binary => {
+                let mut bytes = BINARY_MAGIC.to_vec();
+                bytes.extend_from_slice(&FORMAT_VERSION.to_le_bytes());
+                let mut serializer = rmp_serde::Serializer::new(&mut bytes)
+                    .with_struct_map()
+                    .with_human_readable();
+                self.serialize(&mut serializer)
+                    .map_err(serialization_error)?;
+                Ok(bytes)
+            }
+            SnapshotFormat::Json => {
+                let network = serde_json::to_value(self).map_err(serialization_error)?;
+                let envelope = serde_json::json!({
+                    "format": JSON_FORMAT_NAME,
+                    "version": FORMAT_VERSION,
+                    "network": network,
+                });
+                serde_json::to_vec(&envelope).map_err(serialization_error)
+            }
+        }
+    }
+
+    /// Dekodiert ein Netzwerk aus einem Snapshot in einer der beiden Kodierungen
+    pub fn from_snapshot(bytes: &[u8]) -> NetworkResult<Network> {
+        if let Some(rest) = bytes.strip_prefix(BINARY_MAGIC) {
+            if rest.len() < 4 {
+                return Err(NetworkError::InvalidSnapshot(
+                    "Versionskopf unvollständig".to_string(),
+                ));
+            }
+            let (version, payload) = rest.split_at(4);
+            let version = u32::from_le_bytes(version.try_into().expect("vier Bytes"));
+            check_version(version)?;
+
+            let mut deserializer =
+                rmp_serde::Deserializer::from_read_ref(payload).with_human_readable();
+            if version == FORMAT_VERSION {
+                return serde::Deserialize::deserialize(&mut deserializer)
+                    .map_err(serialization_error);
+            }
+            let network: Value =
+                serde::Deserialize::deserialize(&mut deserializer).map_err(serialization_error)?;
+            return decode_migrated(network, version);
+        }
+
+        let document: Value = serde_json::from_slice(bytes).map_err(|_| {
+            NetworkError::InvalidSnapshot("weder binärer noch JSON-Snapshot".to_string())
+        })?;
+        match document {
+            Value::Object(mut envelope)
+                if envelope.get("format").and_then(Value::as_str) == Some(JSON_FORMAT_NAME) =>
+            {
+                let version = envelope
+                    .get("version")
+                    .and_then(Value::as_u64)
+                    .and_then(|version| u32::try_from(version).ok())
+                    .ok_or_else(|| {
+                        NetworkError::InvalidSnapshot("Formatversion fehlt".to_string())
+                    })?;
+                check_version(version)?;
+                let network = envelope.remove("network").ok_or_else(|| {
+                    NetworkError::InvalidSnapshot("Netzwerkdaten fehlen".to_string())
+                })?;
+                decode_migrated(network, version)
+            }
+            // Unversionierter JSON-Dump aus der Zeit vor dem Snapshot-Format
+            legacy => decode_migrated(legacy, 1),
+        }
+    }
+}
+
+/// Lehnt Snapshots ab, die von einer neueren Version geschrieben wurden
+fn check_version(version: u32) -> NetworkResult<()> {
+    if version == 0 || version > FORMAT_VERSION {
+        return Err(NetworkError::UnsupportedVersion {
+            found: version,
+            supported: FORMAT_VERSION,
+        });
+    }
+    Ok(())
+}
+
+/// Bringt die Netzwerkdaten schrittweise auf die aktuelle Version und dekodiert sie
+fn decode_migrated(mut network: Value, version: u32) -> NetworkResult<Network> {
+    for from in version..FORMAT_VERSION {
+        network = match from {
+            1 => migrate_v1_to_v2(network),
+            _ => unreachable!("Migrationsschritt für Version {} fehlt", from),
+        };
+    }
+    serde_json::from_value(network).map_err(serialization_error)
+}
+
+/// Felder der ursprünglichen Netzwerkstruktur, die nur dem Testbetrieb dienten
+const V1_OBSOLETE_FIELDS: [&str; 5] = [
+    "pending_signals",
+    "cycle_counter",
+    "activity_cycle_test_mode",
+    "inhibitory_test_mode",
+    "test_cycle_count",
+];
+
+/// Version 1 → 2: Neuronen erhalten ihre Modellkennung, Synapsen ihren Typ und
+/// werden als Liste statt als Tabelle gespeichert
+fn migrate_v1_to_v2(mut network: Value) -> Value {
+    let Value::Object(fields) = &mut network else {
+        return network;
+    };
+    for field in V1_OBSOLETE_FIELDS {
+        fields.remove(field);
+    }
+
+    // Neuronen behalten ihre Felder; Zeitkonstanten und Phasenzeit erhalten Standardwerte
+    if let Some(Value::Object(neurons)) = fields.get_mut("neurons") {
+        for neuron in neurons.values_mut() {
+            if let Value::Object(neuron) = neuron {
+                neuron
+                    .entry("model")
+                    .or_insert_with(|| Value::from("Neuron"));
+            }
+        }
+    }
+
+    let synapses: Vec<Value> = match fields.remove("synapses") {
+        Some(Value::Object(table)) => table.into_iter().map(|(_, synapse)| synapse).collect(),
+        Some(Value::Array(entries)) => entries
+            .into_iter()
+            .map(|entry| match entry {
+                // Schlüssel-Synapse-Paar aus der Tupeltabelle
+                Value::Array(mut pair) if pair.len() == 2 => pair.remove(1),
+                synapse => synapse,
+            })
+            .collect(),
+        _ => Vec::new(),
+    };
+    let synapses = synapses.into_iter().map(|mut synapse| {
+        if let Value::Object(fields) = &mut synapse
+            && !fields.contains_key("synapse_type")
+        {
+            let weight = fields.get("weight").and_then(Value::as_f64).unwrap_or(0.0);
+            let synapse_type = SynapseType::from_weight(weight as f32);
+            fields.insert(
+                "synapse_type".to_string(),
+                serde_json::to_value(synapse_type).expect("Synapsentyp ist serialisierbar"),
+            );
+        }
+        synapse
+    });
+    fields.insert("synapses".to_string(), Value::Array(synapses.collect()));
+    network
+}
+
+/// Wandelt einen Ein-/Ausgabefehler in einen Netzwerkfehler um
+fn io_error(error: std::io::Error) -> NetworkError {
+    NetworkError::Io(error.to_string())
+}
+
+/// Wandelt einen Kodierungsfehler in einen Netzwerkfehler um
+fn serialization_error<E: std::fmt::Display>(error: E) -> NetworkError {
+    NetworkError::Serialization(error.to_string())
+}
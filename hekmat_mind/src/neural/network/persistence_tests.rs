@@ -0,0 +1,260 @@
+use crate::neural::growth::Position;
+use crate::neural::network::NetworkError;
+use crate::neural::network::model::{Network, NetworkBuilder};
+use crate::neural::network::persistence::{FORMAT_VERSION, SnapshotFormat};
+use crate::neural::network::population::{ConnectionRule, Projection};
+use crate::neural::neuron::lif::LifParams;
+use crate::neural::neuron::model::{Neuron, NeuronState};
+use crate::neural::neuron::traits::NeuronModel;
+use crate::neural::synapse::model::{Synapse, SynapseType};
+use crate::neural::synapse::plasticity::OjaRule;
+use crate::neural::synapse::short_term::StpParams;
+use crate::neural::synapse::stdp::StdpRule;
+use serde_json::Value;
+use std::fs;
+use std::path::PathBuf;
+use uuid::Uuid;
+
+/// Eindeutiger Dateipfad im temporären Verzeichnis
+fn temporary_path(extension: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("hekmat_network_{}.{}", Uuid::new_v4(), extension))
+}
+
+/// Trainiertes Netzwerk mit Lernregeln, Kurzzeitplastizität und unterwegs befindlichen Signalen
+fn trained_network() -> (Network, Vec<Uuid>) {
+    let mut network = NetworkBuilder::new()
+        .with_neurons(12, 100)
+        .with_lif_neurons(LifParams::default())
+        .with_deterministic_connections(0.3, 0.6)
+        .build();
+    let mut ids: Vec<Uuid> = network.neurons().keys().copied().collect();
+    ids.sort();
+
+    let mut synapse = Synapse::new(ids[0], ids[1], 0.8);
+    synapse.set_short_term_plasticity(Some(StpParams::depressing()));
+    synapse.set_delay(0.004);
+    network.add_synapse(synapse);
+    network.add_projection_rule(ids[..4].to_vec(), ids[4..].to_vec(), OjaRule::new(0.02));
+    network.set_stdp(Some(StdpRule::triplet()));
+
+    run(&mut network, &ids, 0..150);
+    (network, ids)
+}
+
+/// Stimuliert reihum einzelne Neuronen und führt die angegebenen Zyklen aus
+fn run(network: &mut Network, ids: &[Uuid], cycles: std::ops::Range<usize>) {
+    for cycle in cycles {
+        if cycle % 5 == 0 {
+            network.stimulate_neuron(&ids[cycle % ids.len()], 1.0);
+        }
+        network.cycle(0.001);
+    }
+}
+
+/// Vergleichbarer Zustand: Zeit, Neuronen und Synapsen in fester Reihenfolge
+fn state(network: &Network) -> (u64, Vec<String>, Vec<String>) {
+    let mut neurons: Vec<String> = network
+        .neurons()
+        .values()
+        .map(|neuron| serde_json::to_string(neuron).unwrap())
+        .collect();
+    neurons.sort();
+    let mut synapses: Vec<String> = network
+        .synapses()
+        .values()
+        .map(|synapse| serde_json::to_string(synapse).unwrap())
+        .collect();
+    synapses.sort();
+    (network.time().to_bits(), neurons, synapses)
+}
+
+#[test]
+fn test_training_resumes_identically_after_binary_load() {
+    let (mut network, ids) = trained_network();
+    assert!(network.synapse_count() > 0);
+
+    let path = temporary_path("bin");
+    network.save(&path, SnapshotFormat::Binary).unwrap();
+    let mut restored = Network::load(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(state(&restored), state(&network));
+    assert_eq!(
+        restored.pending_spike_count(),
+        network.pending_spike_count()
+    );
+    assert_eq!(restored.stdp(), network.stdp());
+
+    // Beide Netzwerke lernen nach dem Neustart exakt gleich weiter
+    run(&mut network, &ids, 150..300);
+    run(&mut restored, &ids, 150..300);
+    assert_eq!(state(&restored), state(&network));
+}
+
+#[test]
+fn test_json_snapshot_has_versioned_header() {
+    let (network, _) = trained_network();
+
+    let path = temporary_path("json");
+    network.save(&path, SnapshotFormat::Json).unwrap();
+    let document: Value = serde_json::from_slice(&fs::read(&path).unwrap()).unwrap();
+    let restored = Network::load(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(document["format"], "hekmat_mind.network");
+    assert_eq!(document["version"], FORMAT_VERSION);
+    assert!(document["network"]["synapses"].is_array());
+    assert_eq!(state(&restored), state(&network));
+
+    // Die binäre Kodierung ist deutlich kompakter
+    let binary = network.to_snapshot(SnapshotFormat::Binary).unwrap();
+    let json = network.to_snapshot(SnapshotFormat::Json).unwrap();
+    assert!(binary.len() < json.len());
+}
+
+/// Fügt eine Population und eine Projektion aus dem Zufallsstrom des Netzwerks hinzu
+/// und gibt deren Neuronen und Synapsen zurück
+fn grow(network: &mut Network) -> (Vec<Uuid>, Vec<(Uuid, Uuid)>) {
+    let ids = network
+        .add_population("grown", 6, |id| Box::new(Neuron::new(100).with_id(id)))
+        .unwrap()
+        .neuron_ids()
+        .to_vec();
+    network
+        .add_projection(Projection::new(
+            "seed",
+            "grown",
+            ConnectionRule::FixedProbability(0.5),
+        ))
+        .unwrap();
+    let mut synapses: Vec<(Uuid, Uuid)> = network.synapses().keys().copied().collect();
+    synapses.sort();
+    (ids, synapses)
+}
+
+#[test]
+fn test_random_stream_survives_snapshots() {
+    for format in [SnapshotFormat::Binary, SnapshotFormat::Json] {
+        for seed in [Some(7), None] {
+            let mut builder = NetworkBuilder::new().with_neurons(4, 100);
+            if let Some(seed) = seed {
+                builder = builder.with_seed(seed);
+            }
+            let mut network = builder.build();
+            network
+                .add_population("seed", 4, |id| Box::new(Neuron::new(100).with_id(id)))
+                .unwrap();
+
+            let path = temporary_path("snapshot");
+            network.save(&path, format).unwrap();
+            let mut restored = Network::load(&path).unwrap();
+            fs::remove_file(&path).unwrap();
+
+            // Nach dem Laden entstehen dieselben IDs und Verbindungen wie ohne Unterbrechung
+            assert_eq!(grow(&mut restored), grow(&mut network));
+        }
+    }
+}
+
+/// Unversionierter Dump der ursprünglichen Netzwerkstruktur: vier Standardneuronen in
+/// allen Zuständen und zwei Synapsen als Liste von Tupelschlüssel-Synapse-Paaren
+const V1_FIXTURE: &str = include_str!("../../../tests/fixtures/network_v1.json");
+
+/// Prüft das aus [`V1_FIXTURE`] geladene Netzwerk
+fn assert_v1_fixture(network: &Network) {
+    let id = |index: u32| Uuid::from_u128(0x4000_8000_0000_0000_0000 + index as u128);
+    assert_eq!(network.neuron_count(), 4);
+    assert_eq!(network.time(), 0.0);
+
+    let states: Vec<(NeuronState, f32)> = (1..=4)
+        .map(|index| {
+            let neuron = network.get_neuron_as::<Neuron>(&id(index)).unwrap();
+            assert_eq!(neuron.model_name(), "neuron");
+            assert!((neuron.threshold() - (0.3 + 0.1 * index as f32)).abs() < 1e-6);
+            (neuron.state(), neuron.activation_energy())
+        })
+        .collect();
+    assert_eq!(
+        states,
+        vec![
+            (NeuronState::Inactive, 0.0),
+            (NeuronState::Active, 1.0),
+            (NeuronState::Refractory, 0.0),
+            (NeuronState::Inactive, 0.3),
+        ]
+    );
+    let neuron = network.get_neuron_as::<Neuron>(&id(3)).unwrap();
+    assert_eq!(neuron.speed(), 500);
+    assert_eq!(*neuron.position(), Position::new(2.0, 4.0, -2.0));
+
+    assert_eq!(network.synapse_count(), 2);
+    let synapse = network.get_synapse(&id(1), &id(2)).unwrap();
+    assert_eq!(synapse.synapse_type(), SynapseType::Excitatory);
+    assert!((synapse.weight() - 0.7).abs() < 1e-6);
+    assert!((synapse.delay() - 0.002).abs() < 1e-6);
+    assert!(network.get_synapse(&id(2), &id(3)).is_some());
+}
+
+#[test]
+fn test_unversioned_dumps_are_migrated() {
+    let mut network = Network::from_snapshot(V1_FIXTURE.as_bytes()).unwrap();
+    assert_v1_fixture(&network);
+
+    // Das migrierte Netzwerk läuft mit der aktuellen Zyklussemantik weiter
+    let id = Uuid::from_u128(0x4000_8000_0000_0000_0001);
+    network.stimulate_neuron(&id, 1.0);
+    network.cycle(0.001);
+    assert_eq!(network.last_spike_time(&id), Some(0.0));
+
+    // Dieselbe Migration gilt für Snapshots mit Versionskopf 1, auch wenn die
+    // Synapsentabelle mit Zeichenketten statt Tupeln indiziert ist
+    let mut legacy: Value = serde_json::from_str(V1_FIXTURE).unwrap();
+    let table: serde_json::Map<String, Value> = legacy["synapses"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .enumerate()
+        .map(|(index, entry)| (index.to_string(), entry[1].clone()))
+        .collect();
+    legacy["synapses"] = Value::Object(table);
+    let envelope = serde_json::json!({
+        "format": "hekmat_mind.network",
+        "version": 1,
+        "network": legacy,
+    });
+    let migrated = Network::from_snapshot(&serde_json::to_vec(&envelope).unwrap()).unwrap();
+    assert_v1_fixture(&migrated);
+}
+
+#[test]
+fn test_invalid_snapshots_are_rejected() {
+    let network = Network::new();
+
+    // Neuere Formatversionen sind unbekannt
+    let mut binary = network.to_snapshot(SnapshotFormat::Binary).unwrap();
+    binary[4..8].copy_from_slice(&(FORMAT_VERSION + 1).to_le_bytes());
+    assert_eq!(
+        Network::from_snapshot(&binary).unwrap_err(),
+        NetworkError::UnsupportedVersion {
+            found: FORMAT_VERSION + 1,
+            supported: FORMAT_VERSION,
+        }
+    );
+
+    assert!(matches!(
+        Network::from_snapshot(b"HMNW\x02"),
+        Err(NetworkError::InvalidSnapshot(_))
+    ));
+    assert!(matches!(
+        Network::from_snapshot(b"kein Snapshot"),
+        Err(NetworkError::InvalidSnapshot(_))
+    ));
+    assert!(matches!(
+        Network::from_snapshot(b"HMNW\x02\x00\x00\x00\xff"),
+        Err(NetworkError::Serialization(_))
+    ));
+    assert!(matches!(
+        Network::load(temporary_path("bin")),
+        Err(NetworkError::Io(_))
+    ));
+}
@@ -0,0 +1,258 @@
+//! Populationen und Projektionen
+//!
+//! Eine [`Population`] ist eine benannte Gruppe gleichartiger Neuronen, etwa eine
+//! Schicht oder ein Zelltyp einer kortikalen Säule. Eine [`Projection`] verbindet zwei
+//! Populationen nach einer [`ConnectionRule`] und legt die Parameter ihrer Synapsen
+//! sowie optional eine eigene Lernregel fest.
+//!
+//! Populationen werden mit [`Network::add_population`](crate::neural::network::model::Network::add_population)
+//! angelegt und über ihren Namen gefunden, Projektionen mit
+//! [`Network::add_projection`](crate::neural::network::model::Network::add_projection).
+
+use crate::neural::network::{NetworkError, NetworkResult};
+use crate::neural::synapse::model::{Synapse, SynapseBuilder, SynapseType, constants};
+use crate::neural::synapse::plasticity::PlasticityRule;
+use crate::neural::synapse::short_term::StpParams;
+use rand::Rng;
+use rand::seq::index;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Benannte Gruppe von Neuronen
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Population {
+    /// Eindeutiger Name der Population
+    name: String,
+    /// Neuronen in der Reihenfolge ihrer Erzeugung
+    neuron_ids: Vec<Uuid>,
+}
+
+impl Population {
+    /// Erstellt eine Population aus bestehenden Neuronen
+    pub fn new(name: impl Into<String>, neuron_ids: Vec<Uuid>) -> Self {
+        Self {
+            name: name.into(),
+            neuron_ids,
+        }
+    }
+
+    /// Gibt den Namen der Population zurück
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Gibt die Neuronen der Population zurück
+    pub fn neuron_ids(&self) -> &[Uuid] {
+        &self.neuron_ids
+    }
+
+    /// Gibt die Anzahl der Neuronen zurück
+    pub fn len(&self) -> usize {
+        self.neuron_ids.len()
+    }
+
+    /// Prüft, ob die Population keine Neuronen enthält
+    pub fn is_empty(&self) -> bool {
+        self.neuron_ids.is_empty()
+    }
+
+    /// Prüft, ob ein Neuron zur Population gehört
+    pub fn contains(&self, neuron_id: &Uuid) -> bool {
+        self.neuron_ids.contains(neuron_id)
+    }
+
+    /// Entfernt ein Neuron aus der Population
+    pub(crate) fn remove(&mut self, neuron_id: &Uuid) {
+        self.neuron_ids.retain(|id| id != neuron_id);
+    }
+}
+
+/// Regel, nach der eine Projektion Neuronen zweier Populationen verbindet
+///
+/// Selbstverbindungen werden ausgelassen, wenn eine Population auf sich selbst projiziert.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ConnectionRule {
+    /// Das i-te Neuron der Quelle mit dem i-ten Neuron des Ziels; beide gleich groß
+    OneToOne,
+    /// Jedes Neuron der Quelle mit jedem Neuron des Ziels
+    AllToAll,
+    /// Jedes Paar unabhängig mit der angegebenen Wahrscheinlichkeit (0.0 - 1.0)
+    FixedProbability(f32),
+    /// Jedes Zielneuron erhält Eingaben von genau so vielen zufälligen Quellneuronen
+    FixedInDegree(usize),
+}
+
+/// Verbindung zweier Populationen mit gemeinsamen Synapsenparametern
+#[derive(Debug)]
+pub struct Projection {
+    /// Name der präsynaptischen Population
+    source: String,
+    /// Name der postsynaptischen Population
+    target: String,
+    /// Verbindungsregel
+    rule: ConnectionRule,
+    /// Anfangsgewicht aller Synapsen
+    weight: f32,
+    /// Synapsentyp (`None` = nach dem Vorzeichen des Gewichts)
+    synapse_type: Option<SynapseType>,
+    /// Verzögerung aller Synapsen in Sekunden
+    delay: f32,
+    /// Kurzzeitplastizität der Synapsen
+    short_term: Option<StpParams>,
+    /// Lernregel der Projektion (`None` = netzwerkweite Regel)
+    plasticity_rule: Option<Box<dyn PlasticityRule>>,
+    /// Startwert für zufällige Verbindungsregeln (`None` = zufällig)
+    seed: Option<u64>,
+}
+
+impl Projection {
+    /// Erstellt eine Projektion von `source` nach `target` mit Standardsynapsen
+    pub fn new(source: impl Into<String>, target: impl Into<String>, rule: ConnectionRule) -> Self {
+        Self {
+            source: source.into(),
+            target: target.into(),
+            rule,
+            weight: 0.5,
+            synapse_type: None,
+            delay: constants::DEFAULT_DELAY,
+            short_term: None,
+            plasticity_rule: None,
+            seed: None,
+        }
+    }
+
+    /// Setzt das Anfangsgewicht aller Synapsen
+    pub fn with_weight(mut self, weight: f32) -> Self {
+        self.weight = weight;
+        self
+    }
+
+    /// Setzt die Art aller Synapsen
+    ///
+    /// Ohne Angabe wird der Typ aus dem Vorzeichen des Gewichts abgeleitet.
+    pub fn with_type(mut self, synapse_type: SynapseType) -> Self {
+        self.synapse_type = Some(synapse_type);
+        self
+    }
+
+    /// Setzt die Verzögerung aller Synapsen in Sekunden
+    pub fn with_delay(mut self, delay: f32) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Aktiviert die Kurzzeitplastizität aller Synapsen
+    pub fn with_short_term_plasticity(mut self, params: StpParams) -> Self {
+        self.short_term = Some(params);
+        self
+    }
+
+    /// Lässt die Synapsen der Projektion nach einer eigenen Lernregel lernen
+    pub fn with_plasticity_rule<R: PlasticityRule + 'static>(mut self, rule: R) -> Self {
+        self.plasticity_rule = Some(Box::new(rule));
+        self
+    }
+
+    /// Macht zufällige Verbindungsregeln reproduzierbar
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Gibt den Namen der präsynaptischen Population zurück
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Gibt den Namen der postsynaptischen Population zurück
+    pub fn target(&self) -> &str {
+        &self.target
+    }
+
+    /// Gibt den Startwert für zufällige Verbindungsregeln zurück
+    pub fn seed(&self) -> Option<u64> {
+        self.seed
+    }
+
+    /// Entnimmt die Lernregel der Projektion
+    pub(crate) fn take_plasticity_rule(&mut self) -> Option<Box<dyn PlasticityRule>> {
+        self.plasticity_rule.take()
+    }
+
+    /// Erzeugt die Synapsen zwischen den Neuronen der beiden Populationen
+    pub fn synapses<R: Rng>(
+        &self,
+        source: &[Uuid],
+        target: &[Uuid],
+        rng: &mut R,
+    ) -> NetworkResult<Vec<Synapse>> {
+        let mut pairs = Vec::new();
+        match self.rule {
+            ConnectionRule::OneToOne => {
+                if source.len() != target.len() {
+                    return Err(NetworkError::InvalidProjection(format!(
+                        "1:1-Projektion von '{}' ({}) nach '{}' ({}) erfordert gleich große Populationen",
+                        self.source,
+                        source.len(),
+                        self.target,
+                        target.len()
+                    )));
+                }
+                pairs.extend(source.iter().copied().zip(target.iter().copied()));
+            }
+            ConnectionRule::AllToAll => {
+                for pre in source {
+                    pairs.extend(target.iter().map(|post| (*pre, *post)));
+                }
+            }
+            ConnectionRule::FixedProbability(probability) => {
+                let probability = probability.clamp(0.0, 1.0) as f64;
+                for pre in source {
+                    for post in target {
+                        if pre != post && rng.gen_bool(probability) {
+                            pairs.push((*pre, *post));
+                        }
+                    }
+                }
+            }
+            ConnectionRule::FixedInDegree(in_degree) => {
+                for post in target {
+                    let candidates: Vec<Uuid> =
+                        source.iter().copied().filter(|pre| pre != post).collect();
+                    if in_degree > candidates.len() {
+                        return Err(NetworkError::InvalidProjection(format!(
+                            "Eingangsgrad {} übersteigt die {} möglichen Quellen in '{}'",
+                            in_degree,
+                            candidates.len(),
+                            self.source
+                        )));
+                    }
+                    pairs.extend(
+                        index::sample(rng, candidates.len(), in_degree)
+                            .into_iter()
+                            .map(|index| (candidates[index], *post)),
+                    );
+                }
+            }
+        }
+
+        Ok(pairs
+            .into_iter()
+            .filter(|(pre, post)| pre != post)
+            .map(|(pre, post)| {
+                let mut builder = SynapseBuilder::new()
+                    .with_pre_neuron_id(pre)
+                    .with_post_neuron_id(post)
+                    .with_weight(self.weight)
+                    .with_delay(self.delay);
+                if let Some(synapse_type) = self.synapse_type {
+                    builder = builder.with_type(synapse_type);
+                }
+                if let Some(params) = self.short_term {
+                    builder = builder.with_short_term_plasticity(params);
+                }
+                builder.build()
+            })
+            .collect())
+    }
+}
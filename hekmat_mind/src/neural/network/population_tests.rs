@@ -0,0 +1,314 @@
+use crate::neural::network::NetworkError;
+use crate::neural::network::model::{Network, NetworkBuilder};
+use crate::neural::network::persistence::SnapshotFormat;
+use crate::neural::network::population::{ConnectionRule, Projection};
+use crate::neural::neuron::lif::{LifNeuron, LifParams};
+use crate::neural::neuron::model::Neuron;
+use crate::neural::synapse::model::{Synapse, SynapseType};
+use crate::neural::synapse::plasticity::OjaRule;
+use crate::neural::synapse::short_term::StpParams;
+use uuid::Uuid;
+
+/// Netzwerk mit einer LIF-Eingabeschicht und zwei Populationen aus Standardneuronen
+fn layered_network() -> Network {
+    let mut network = Network::new();
+    network
+        .add_population("input", 10, |id| {
+            Box::new(LifNeuron::with_params(LifParams::default(), 0.4, 0.01).with_id(id))
+        })
+        .unwrap();
+    network
+        .add_population("excitatory", 20, |id| {
+            Box::new(Neuron::new(150).with_id(id))
+        })
+        .unwrap();
+    network
+        .add_population("inhibitory", 10, |id| {
+            Box::new(Neuron::new(200).with_id(id))
+        })
+        .unwrap();
+    network
+}
+
+#[test]
+fn test_populations_share_parameters_and_are_found_by_name() {
+    let network = layered_network();
+    assert_eq!(network.neuron_count(), 40);
+    assert_eq!(network.populations().len(), 3);
+
+    let input = network.population("input").unwrap();
+    assert_eq!(input.name(), "input");
+    assert_eq!(input.len(), 10);
+    for id in input.neuron_ids() {
+        let neuron = network.get_neuron(id).unwrap();
+        assert_eq!(neuron.model_name(), "lif");
+        assert_eq!(neuron.threshold(), 0.4);
+    }
+    let excitatory = network.population("excitatory").unwrap();
+    assert!(
+        excitatory.neuron_ids().iter().all(|id| network
+            .get_neuron_as::<Neuron>(id)
+            .unwrap()
+            .speed()
+            == 150)
+    );
+    assert!(!excitatory.contains(&input.neuron_ids()[0]));
+    assert!(network.population("output").is_none());
+}
+
+#[test]
+fn test_seeded_networks_create_identical_populations_and_projections() {
+    let build = |seed: u64| {
+        let mut network = Network::new();
+        network.set_seed(seed);
+        network
+            .add_population("a", 8, |id| Box::new(Neuron::new(100).with_id(id)))
+            .unwrap();
+        network
+            .add_population("b", 8, |id| Box::new(Neuron::new(100).with_id(id)))
+            .unwrap();
+        network
+            .add_projection(Projection::new(
+                "a",
+                "b",
+                ConnectionRule::FixedProbability(0.3),
+            ))
+            .unwrap();
+        let mut synapses: Vec<(Uuid, Uuid)> = network.synapses().keys().copied().collect();
+        synapses.sort();
+        (
+            network.population("a").unwrap().neuron_ids().to_vec(),
+            synapses,
+        )
+    };
+
+    assert_eq!(build(5), build(5));
+    assert_ne!(build(5), build(6));
+
+    // Ein gebautes Netzwerk erhält seinen Startwert vom Builder
+    let built = |seed: u64| {
+        let mut network = NetworkBuilder::new()
+            .with_neurons(4, 100)
+            .with_seed(seed)
+            .build();
+        let population = network
+            .add_population("extra", 3, |id| Box::new(Neuron::new(100).with_id(id)))
+            .unwrap();
+        population.neuron_ids().to_vec()
+    };
+    assert_eq!(built(9), built(9));
+    assert_ne!(built(9), built(10));
+}
+
+#[test]
+fn test_connection_rules() {
+    let mut network = layered_network();
+
+    // 1:1 erfordert gleich große Populationen
+    let created = network
+        .add_projection(Projection::new(
+            "input",
+            "inhibitory",
+            ConnectionRule::OneToOne,
+        ))
+        .unwrap();
+    assert_eq!(created, 10);
+    let input = network.population("input").unwrap().neuron_ids().to_vec();
+    let inhibitory = network
+        .population("inhibitory")
+        .unwrap()
+        .neuron_ids()
+        .to_vec();
+    for (pre, post) in input.iter().zip(&inhibitory) {
+        assert!(network.get_synapse(pre, post).is_some());
+    }
+    assert!(matches!(
+        network.add_projection(Projection::new(
+            "input",
+            "excitatory",
+            ConnectionRule::OneToOne
+        )),
+        Err(NetworkError::InvalidProjection(_))
+    ));
+
+    // Alle zu allen, bei rekurrenten Projektionen ohne Selbstverbindungen
+    let created = network
+        .add_projection(Projection::new(
+            "inhibitory",
+            "inhibitory",
+            ConnectionRule::AllToAll,
+        ))
+        .unwrap();
+    assert_eq!(created, 10 * 9);
+
+    // Fester Eingangsgrad
+    let created = network
+        .add_projection(
+            Projection::new("excitatory", "excitatory", ConnectionRule::FixedInDegree(5))
+                .with_seed(1),
+        )
+        .unwrap();
+    assert_eq!(created, 20 * 5);
+    let excitatory = network
+        .population("excitatory")
+        .unwrap()
+        .neuron_ids()
+        .to_vec();
+    for post in &excitatory {
+        let in_degree = excitatory
+            .iter()
+            .filter(|pre| network.get_synapse(pre, post).is_some())
+            .count();
+        assert_eq!(in_degree, 5);
+        assert!(network.get_synapse(post, post).is_none());
+    }
+    assert!(matches!(
+        network.add_projection(Projection::new(
+            "input",
+            "excitatory",
+            ConnectionRule::FixedInDegree(11)
+        )),
+        Err(NetworkError::InvalidProjection(_))
+    ));
+
+    // Feste Wahrscheinlichkeit, reproduzierbar über den Startwert
+    let mut first = layered_network();
+    let mut second = layered_network();
+    let projection = || {
+        Projection::new("input", "excitatory", ConnectionRule::FixedProbability(0.3)).with_seed(5)
+    };
+    let count = first.add_projection(projection()).unwrap();
+    assert!((30..=90).contains(&count), "{} Synapsen", count);
+    assert_eq!(second.add_projection(projection()).unwrap(), count);
+
+    assert_eq!(
+        network.add_projection(Projection::new("input", "output", ConnectionRule::AllToAll)),
+        Err(NetworkError::UnknownPopulation("output".to_string()))
+    );
+}
+
+#[test]
+fn test_projection_synapse_parameters_and_plasticity() {
+    let mut network = layered_network();
+    network
+        .add_projection(
+            Projection::new("inhibitory", "excitatory", ConnectionRule::AllToAll)
+                .with_weight(-0.7)
+                .with_type(SynapseType::Inhibitory)
+                .with_delay(0.003)
+                .with_short_term_plasticity(StpParams::depressing())
+                .with_plasticity_rule(OjaRule::new(0.05)),
+        )
+        .unwrap();
+
+    let inhibitory = network
+        .population("inhibitory")
+        .unwrap()
+        .neuron_ids()
+        .to_vec();
+    let excitatory = network
+        .population("excitatory")
+        .unwrap()
+        .neuron_ids()
+        .to_vec();
+    let input = network.population("input").unwrap().neuron_ids().to_vec();
+    let synapse = network.get_synapse(&inhibitory[0], &excitatory[3]).unwrap();
+    assert_eq!(synapse.weight(), -0.7);
+    assert_eq!(synapse.synapse_type(), SynapseType::Inhibitory);
+    assert_eq!(synapse.delay(), 0.003);
+    assert!(synapse.short_term_plasticity().is_some());
+
+    // Die Lernregel gilt nur innerhalb der Projektion
+    assert_eq!(
+        network
+            .plasticity_rule_for(&inhibitory[0], &excitatory[3])
+            .unwrap()
+            .name(),
+        "oja"
+    );
+    assert_eq!(
+        network
+            .plasticity_rule_for(&input[0], &excitatory[3])
+            .unwrap()
+            .name(),
+        "hebbian"
+    );
+}
+
+#[test]
+fn test_rejected_projection_leaves_network_unchanged() {
+    let mut network = layered_network();
+    network.set_dales_law(true).unwrap();
+    let ids = |name: &str| network.population(name).unwrap().neuron_ids().to_vec();
+    let (input, excitatory, inhibitory) = (ids("input"), ids("excitatory"), ids("inhibitory"));
+
+    // Das letzte Neuron der Quellpopulation hemmt bereits, das erste erregt schwach
+    let last = excitatory[excitatory.len() - 1];
+    network
+        .try_add_synapse(Synapse::new(last, input[0], -0.5))
+        .unwrap();
+    network
+        .try_add_synapse(Synapse::new(excitatory[0], inhibitory[0], 0.2))
+        .unwrap();
+    let count = network.synapse_count();
+
+    let result = network.add_projection(
+        Projection::new("excitatory", "inhibitory", ConnectionRule::AllToAll)
+            .with_weight(0.6)
+            .with_plasticity_rule(OjaRule::new(0.05)),
+    );
+    assert!(matches!(
+        result,
+        Err(NetworkError::DalesLawViolation { neuron, .. }) if neuron == last
+    ));
+
+    // Weder neue noch ersetzte Synapsen noch die Lernregel bleiben zurück
+    assert_eq!(network.synapse_count(), count);
+    assert_eq!(
+        network
+            .get_synapse(&excitatory[0], &inhibitory[0])
+            .unwrap()
+            .weight(),
+        0.2
+    );
+    assert!(
+        network
+            .get_synapse(&excitatory[0], &inhibitory[1])
+            .is_none()
+    );
+    assert_eq!(
+        network
+            .plasticity_rule_for(&excitatory[0], &inhibitory[0])
+            .unwrap()
+            .name(),
+        "hebbian"
+    );
+}
+
+#[test]
+fn test_populations_survive_snapshots_and_reject_duplicates() {
+    let mut network = layered_network();
+    assert_eq!(
+        network
+            .add_population("input", 3, |id| Box::new(Neuron::new(100).with_id(id)))
+            .unwrap_err(),
+        NetworkError::DuplicatePopulation("input".to_string())
+    );
+
+    // Bestehende Neuronen lassen sich nachträglich gruppieren
+    let mut readout: Vec<Uuid> =
+        network.population("excitatory").unwrap().neuron_ids()[..4].to_vec();
+    network
+        .define_population("readout", readout.clone())
+        .unwrap();
+    readout.push(Uuid::new_v4());
+    assert!(matches!(
+        network.define_population("broken", readout),
+        Err(NetworkError::NeuronNotFound(_))
+    ));
+
+    let snapshot = network.to_snapshot(SnapshotFormat::Binary).unwrap();
+    let restored = Network::from_snapshot(&snapshot).unwrap();
+    assert_eq!(restored.populations(), network.populations());
+    assert_eq!(restored.population("readout").unwrap().len(), 4);
+}
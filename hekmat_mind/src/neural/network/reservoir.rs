@@ -0,0 +1,518 @@
+//! Reservoir Computing mit zufällig verbundenen Netzwerken
+//!
+//! Ein [`Reservoir`] ist ein fest verdrahtetes, rekurrentes Netzwerk, das eine
+//! Eingabezeitreihe in hochdimensionale Zustände übersetzt (Echo-State- bzw.
+//! Liquid-State-Machine). Gelernt wird nur eine [`LinearReadout`] auf diesen Zuständen.
+//!
+//! Ablauf:
+//!
+//! 1. Ein [`NetworkBuilder`] mit zufälligen Verbindungen an [`ReservoirBuilder::new`]
+//!    übergeben; der Builder macht einen Teil der Neuronen hemmend, skaliert die
+//!    Gewichte auf einen Spektralradius und wählt die Eingangsneuronen.
+//! 2. Mit [`Reservoir::drive`] jeden Eingabevektor über einen [`Encoder`] für ein
+//!    Präsentationsfenster anlegen und den Zustand aller Neuronen am Fensterende über
+//!    einen [`Decoder`] auslesen; das ergibt die Zustandsmatrix.
+//! 3. Mit [`Reservoir::fit`] eine Ridge-Regression auf den Zuständen anpassen und mit
+//!    [`Reservoir::evaluate`] auf neuen Daten bewerten ([`nrmse`]).
+//!
+//! [`BenchmarkTask`] erzeugt die üblichen Testaufgaben (verzögertes Erinnern, NARMA).
+//! Plastizität ist im Reservoir abgeschaltet.
+
+use crate::neural::network::decoding::{Decoder, Decoding, LinearReadout};
+use crate::neural::network::encoding::{Encoder, Encoding};
+use crate::neural::network::model::{Network, NetworkBuilder};
+use crate::neural::network::monitor::{MonitorId, SpikeRecorder};
+use crate::neural::network::{NetworkError, NetworkResult};
+use crate::neural::synapse::model::{SynapseBuilder, SynapseType};
+use rand::prelude::*;
+use rand::rngs::StdRng;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// Anzahl der Iterationen zur Schätzung des Spektralradius
+const SPECTRAL_ITERATIONS: usize = 200;
+
+impl Network {
+    /// Schätzt den Spektralradius der Gewichtsmatrix
+    ///
+    /// Nach der Formel von Gelfand aus dem mittleren Wachstum von `‖Wᵏx‖` über
+    /// wiederholte Multiplikation; funktioniert auch bei komplexen dominanten
+    /// Eigenwerten. Modulierende Synapsen werden nicht berücksichtigt.
+    pub fn spectral_radius(&self) -> f32 {
+        let mut ids: Vec<Uuid> = self.neurons().keys().copied().collect();
+        ids.sort();
+        let index: HashMap<Uuid, usize> = ids
+            .iter()
+            .enumerate()
+            .map(|(position, id)| (*id, position))
+            .collect();
+        let edges: Vec<(usize, usize, f64)> = self
+            .synapses()
+            .values()
+            .filter(|synapse| synapse.synapse_type() != SynapseType::Modulatory)
+            .map(|synapse| {
+                (
+                    index[synapse.pre_neuron_id()],
+                    index[synapse.post_neuron_id()],
+                    synapse.weight() as f64,
+                )
+            })
+            .collect();
+        if edges.is_empty() {
+            return 0.0;
+        }
+
+        // Ungleichmäßiger Startvektor, damit keine Symmetrie den Eigenvektor verdeckt
+        let mut state: Vec<f64> = (0..ids.len())
+            .map(|position| 1.0 + (position as f64 * 0.618_033_988_75).fract())
+            .collect();
+        let mut log_growth = 0.0;
+        let mut counted = 0;
+        for iteration in 0..SPECTRAL_ITERATIONS {
+            let mut next = vec![0.0; ids.len()];
+            for (pre, post, weight) in &edges {
+                next[*post] += weight * state[*pre];
+            }
+            let norm = next.iter().map(|value| value * value).sum::<f64>().sqrt();
+            if norm == 0.0 || !norm.is_finite() {
+                return 0.0;
+            }
+            // Die erste Hälfte dient dem Einschwingen auf die dominanten Eigenwerte
+            if iteration >= SPECTRAL_ITERATIONS / 2 {
+                log_growth += norm.ln();
+                counted += 1;
+            }
+            state = next.into_iter().map(|value| value / norm).collect();
+        }
+        (log_growth / counted as f64).exp() as f32
+    }
+
+    /// Skaliert alle Gewichte, sodass der Spektralradius `target` erreicht
+    ///
+    /// Gibt den angewendeten Faktor zurück (1.0, wenn das Netzwerk keine Zyklen hat).
+    /// Gewichte werden weiterhin auf den Bereich ihres Synapsentyps begrenzt; liegt
+    /// das Ziel zu hoch, wird es daher nur näherungsweise erreicht.
+    pub fn scale_to_spectral_radius(&mut self, target: f32) -> f32 {
+        let radius = self.spectral_radius();
+        if radius <= f32::EPSILON {
+            return 1.0;
+        }
+        let factor = target.max(0.0) / radius;
+        let keys: Vec<(Uuid, Uuid)> = self.synapses().keys().copied().collect();
+        for (pre, post) in keys {
+            if let Some(synapse) = self.get_synapse_mut(&pre, &post)
+                && synapse.synapse_type() != SynapseType::Modulatory
+            {
+                synapse.set_weight(synapse.weight() * factor);
+            }
+        }
+        factor
+    }
+}
+
+/// Normalisierte Wurzel des mittleren quadratischen Fehlers
+///
+/// Der Fehler wird durch die Standardabweichung der Zielwerte geteilt; 0.0 ist eine
+/// perfekte Vorhersage, 1.0 entspricht der Vorhersage des Mittelwerts.
+pub fn nrmse(predictions: &[Vec<f32>], targets: &[Vec<f32>]) -> f64 {
+    let values: Vec<(f64, f64)> = predictions
+        .iter()
+        .zip(targets)
+        .flat_map(|(prediction, target)| prediction.iter().zip(target))
+        .map(|(prediction, target)| (*prediction as f64, *target as f64))
+        .collect();
+    if values.is_empty() {
+        return 0.0;
+    }
+    let count = values.len() as f64;
+    let mean = values.iter().map(|(_, target)| target).sum::<f64>() / count;
+    let variance = values
+        .iter()
+        .map(|(_, target)| (target - mean).powi(2))
+        .sum::<f64>()
+        / count;
+    let error = values
+        .iter()
+        .map(|(prediction, target)| (prediction - target).powi(2))
+        .sum::<f64>()
+        / count;
+    if variance > 0.0 {
+        (error / variance).sqrt()
+    } else {
+        error.sqrt()
+    }
+}
+
+/// Standardaufgaben zur Bewertung eines Reservoirs
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BenchmarkTask {
+    /// Gleichverteilte Eingabe in 0.0 - 1.0, Ziel ist die Eingabe von vor `delay` Schritten
+    DelayedRecall {
+        /// Verzögerung in Präsentationsfenstern
+        delay: usize,
+    },
+    /// Nichtlineares autoregressives System der Ordnung `order` (NARMA)
+    ///
+    /// Eingabe `u(t)` gleichverteilt in 0.0 - 0.5, Ziel
+    /// `y(t+1) = 0.3·y(t) + 0.05·y(t)·Σ y(t−i) + 1.5·u(t−order+1)·u(t) + 0.1`
+    /// mit der Summe über die letzten `order` Werte. Übliche Ordnung ist 10; höhere
+    /// Ordnungen können divergieren.
+    Narma {
+        /// Ordnung des Systems
+        order: usize,
+    },
+}
+
+impl BenchmarkTask {
+    /// Erzeugt `length` Eingabe- und Zielvektoren mit je einem Wert
+    pub fn generate<R: Rng>(&self, length: usize, rng: &mut R) -> (Vec<Vec<f32>>, Vec<Vec<f32>>) {
+        match *self {
+            BenchmarkTask::DelayedRecall { delay } => {
+                let inputs: Vec<f32> = (0..length).map(|_| rng.r#gen::<f32>()).collect();
+                let targets = (0..length)
+                    .map(|t| vec![if t >= delay { inputs[t - delay] } else { 0.0 }])
+                    .collect();
+                (inputs.into_iter().map(|u| vec![u]).collect(), targets)
+            }
+            BenchmarkTask::Narma { order } => {
+                let order = order.max(1);
+                let inputs: Vec<f64> = (0..length).map(|_| rng.gen_range(0.0..0.5)).collect();
+                let mut outputs = vec![0.0_f64; length + 1];
+                for t in 0..length {
+                    let history: f64 = outputs[t.saturating_sub(order - 1)..=t].iter().sum();
+                    let lagged = if t + 1 >= order {
+                        inputs[t + 1 - order]
+                    } else {
+                        0.0
+                    };
+                    outputs[t + 1] = 0.3 * outputs[t]
+                        + 0.05 * outputs[t] * history
+                        + 1.5 * lagged * inputs[t]
+                        + 0.1;
+                }
+                (
+                    inputs.iter().map(|u| vec![*u as f32]).collect(),
+                    outputs[1..].iter().map(|y| vec![*y as f32]).collect(),
+                )
+            }
+        }
+    }
+}
+
+/// Builder für ein [`Reservoir`] auf Grundlage eines [`NetworkBuilder`]
+pub struct ReservoirBuilder {
+    /// Konfiguration des rekurrenten Netzwerks
+    network: NetworkBuilder,
+    /// Angestrebter Spektralradius (`None` = Gewichte unverändert)
+    spectral_radius: Option<f32>,
+    /// Anteil hemmender Neuronen (0.0 - 1.0)
+    inhibitory_fraction: f32,
+    /// Anzahl der Eingabewerte je Zeitschritt
+    input_dim: usize,
+    /// Kodierung der Eingabewerte
+    encoding: Encoding,
+    /// Präsentationsdauer eines Eingabevektors in Sekunden
+    window: f64,
+    /// Eingabe je kodiertem Spike
+    amplitude: f32,
+    /// Auslese der Zustände am Fensterende
+    state_decoding: Decoding,
+    /// Hängt die Aktivierungsenergien der Neuronen an den Zustand an
+    membrane_state: bool,
+    /// Zeitschritt der Simulation in Sekunden
+    time_step: f32,
+    /// Startwert für hemmende Neuronen, Eingangsauswahl und Kodierung
+    seed: Option<u64>,
+}
+
+impl ReservoirBuilder {
+    /// Erstellt einen Reservoir-Builder für das Netzwerk des `NetworkBuilder`
+    ///
+    /// Standard: ein Eingabewert mit Ratenkodierung (bis 300 Hz) in Fenstern von 20 ms,
+    /// Zustände aus exponentiell gefilterten Spikefolgen und Aktivierungsenergien,
+    /// 20 % hemmende Neuronen. Mit LIF-Neuronen
+    /// ([`NetworkBuilder::with_lif_neurons`]) klingt der Zustand langsam ab und bewahrt
+    /// so frühere Eingaben; Standardneuronen integrieren ohne Leck und eignen sich
+    /// schlechter.
+    pub fn new(network: NetworkBuilder) -> Self {
+        Self {
+            network,
+            spectral_radius: None,
+            inhibitory_fraction: 0.2,
+            input_dim: 1,
+            encoding: Encoding::Rate { max_rate: 300.0 },
+            window: 0.02,
+            amplitude: 1.0,
+            state_decoding: Decoding::ExponentialTrace { tau: 0.01 },
+            membrane_state: true,
+            time_step: 0.001,
+            seed: None,
+        }
+    }
+
+    /// Skaliert die Gewichte auf den angegebenen Spektralradius
+    pub fn with_spectral_radius(mut self, radius: f32) -> Self {
+        self.spectral_radius = Some(radius);
+        self
+    }
+
+    /// Setzt den Anteil der Neuronen, deren ausgehende Synapsen hemmend sind
+    pub fn with_inhibitory_fraction(mut self, fraction: f32) -> Self {
+        self.inhibitory_fraction = fraction.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Setzt die Anzahl der Eingabewerte je Zeitschritt und ihre Kodierung
+    pub fn with_inputs(mut self, input_dim: usize, encoding: Encoding) -> Self {
+        self.input_dim = input_dim;
+        self.encoding = encoding;
+        self
+    }
+
+    /// Setzt die Präsentationsdauer und die Eingabe je kodiertem Spike
+    pub fn with_window(mut self, window: f64, amplitude: f32) -> Self {
+        self.window = window;
+        self.amplitude = amplitude;
+        self
+    }
+
+    /// Setzt, wie der Zustand eines Neurons am Fensterende ausgelesen wird
+    pub fn with_state_decoding(mut self, decoding: Decoding) -> Self {
+        self.state_decoding = decoding;
+        self
+    }
+
+    /// Hängt die Aktivierungsenergie jedes Neurons am Fensterende an den Zustand an
+    ///
+    /// Unterschwellige Energie trägt Information über frühere Eingaben, die in den
+    /// Spikes eines Fensters nicht sichtbar ist.
+    pub fn with_membrane_state(mut self, include: bool) -> Self {
+        self.membrane_state = include;
+        self
+    }
+
+    /// Setzt den Zeitschritt der Simulation in Sekunden
+    pub fn with_time_step(mut self, time_step: f32) -> Self {
+        self.time_step = time_step;
+        self
+    }
+
+    /// Macht hemmende Neuronen, Eingangsauswahl und Poisson-Kodierung reproduzierbar
+    ///
+    /// Die Verbindungen selbst werden über `NetworkBuilder::with_seed` festgelegt.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Erstellt das Reservoir
+    pub fn build(self) -> NetworkResult<Reservoir> {
+        let mut network = self.network.build();
+        network.disable_plasticity();
+        let mut rng = match self.seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_entropy(),
+        };
+
+        let mut state_ids: Vec<Uuid> = network.neurons().keys().copied().collect();
+        state_ids.sort();
+        let input_count = self.input_dim * self.encoding.neurons_per_value();
+        if input_count > state_ids.len() {
+            return Err(NetworkError::InvalidReservoir(format!(
+                "{} Eingangsneuronen benötigt, das Netzwerk hat {}",
+                input_count,
+                state_ids.len()
+            )));
+        }
+
+        // Hemmende Neuronen senden nur hemmende Synapsen (Dale'sches Prinzip)
+        let mut shuffled = state_ids.clone();
+        shuffled.shuffle(&mut rng);
+        let inhibitory_count = (shuffled.len() as f32 * self.inhibitory_fraction).round() as usize;
+        for pre in &shuffled[..inhibitory_count] {
+            let synapses: Vec<_> = network
+                .outgoing_synapses(pre)
+                .map(|synapse| {
+                    SynapseBuilder::new()
+                        .with_pre_neuron_id(*pre)
+                        .with_post_neuron_id(*synapse.post_neuron_id())
+                        .with_weight(-synapse.weight().abs())
+                        .with_type(SynapseType::Inhibitory)
+                        .with_delay(synapse.delay())
+                        .build()
+                })
+                .collect();
+            for synapse in synapses {
+                network.try_add_synapse(synapse)?;
+            }
+        }
+        if let Some(radius) = self.spectral_radius {
+            network.scale_to_spectral_radius(radius);
+        }
+
+        shuffled.shuffle(&mut rng);
+        let input_ids = shuffled[..input_count].to_vec();
+        let mut encoder = Encoder::new(self.encoding, input_ids.clone())
+            .with_window(self.window)
+            .with_amplitude(self.amplitude);
+        if self.seed.is_some() {
+            encoder = encoder.with_seed(rng.r#gen());
+        }
+        let recorder = network.attach_monitor(SpikeRecorder::new());
+
+        Ok(Reservoir {
+            network,
+            encoder,
+            decoder: Decoder::new(self.state_decoding, state_ids),
+            recorder,
+            membrane_state: self.membrane_state,
+            time_step: self.time_step,
+            readout: None,
+        })
+    }
+}
+
+/// Fest verdrahtetes rekurrentes Netzwerk mit trainierbarer linearer Auslese
+#[derive(Debug, Clone)]
+pub struct Reservoir {
+    /// Rekurrentes Netzwerk
+    network: Network,
+    /// Kodierung der Eingabevektoren auf die Eingangsneuronen
+    encoder: Encoder,
+    /// Auslese der Zustände aller Neuronen
+    decoder: Decoder,
+    /// Spike-Rekorder für die Zustände des laufenden Fensters
+    recorder: MonitorId,
+    /// Hängt die Aktivierungsenergien an den Zustand an
+    membrane_state: bool,
+    /// Zeitschritt der Simulation in Sekunden
+    time_step: f32,
+    /// Angepasste Auslese
+    readout: Option<LinearReadout>,
+}
+
+impl Reservoir {
+    /// Gibt das Netzwerk des Reservoirs zurück
+    pub fn network(&self) -> &Network {
+        &self.network
+    }
+
+    /// Gibt die Eingangsneuronen zurück
+    pub fn input_ids(&self) -> &[Uuid] {
+        self.encoder.input_ids()
+    }
+
+    /// Gibt die Neuronen zurück, deren Zustände ausgelesen werden
+    pub fn state_ids(&self) -> &[Uuid] {
+        self.decoder.output_ids()
+    }
+
+    /// Gibt die angepasste Auslese zurück
+    pub fn readout(&self) -> Option<&LinearReadout> {
+        self.readout.as_ref()
+    }
+
+    /// Setzt den Zustand des Netzwerks zurück; die Auslese bleibt erhalten
+    pub fn reset(&mut self) {
+        self.network.reset();
+        if let Some(recorder) = self.network.monitor_mut(self.recorder) {
+            recorder.clear();
+        }
+    }
+
+    /// Legt die Eingabevektoren nacheinander an und gibt die Zustandsmatrix zurück
+    ///
+    /// Jede Zeile ist der Zustand aller Neuronen am Ende eines Präsentationsfensters.
+    /// Das Netzwerk wird dabei nicht zurückgesetzt.
+    pub fn drive(&mut self, inputs: &[Vec<f32>]) -> NetworkResult<Vec<Vec<f32>>> {
+        let mut states = Vec::with_capacity(inputs.len());
+        for values in inputs {
+            let schedule = self.encoder.encode(values)?;
+            let start = self.network.time();
+            self.network.run_schedule(&schedule, self.time_step)?;
+            let end = self.network.time();
+
+            let monitor = self
+                .network
+                .monitor_mut(self.recorder)
+                .expect("Rekorder gehört zum Reservoir");
+            let spikes = monitor
+                .as_spike_recorder()
+                .expect("Monitor ist ein Spike-Rekorder");
+            let mut state = self.decoder.decode(spikes.spikes(), start, end);
+            monitor.clear();
+            if self.membrane_state {
+                state.extend(self.decoder.output_ids().iter().map(|id| {
+                    self.network
+                        .get_neuron(id)
+                        .map_or(0.0, |neuron| neuron.activation_energy())
+                }));
+            }
+            states.push(state);
+        }
+        Ok(states)
+    }
+
+    /// Passt die Auslese an und gibt den Trainingsfehler ([`nrmse`]) zurück
+    ///
+    /// Die ersten `washout` Zustände werden verworfen, damit der Anfangszustand
+    /// des Netzwerks das Training nicht verzerrt.
+    pub fn fit(
+        &mut self,
+        inputs: &[Vec<f32>],
+        targets: &[Vec<f32>],
+        washout: usize,
+        lambda: f64,
+    ) -> NetworkResult<f64> {
+        if inputs.len() != targets.len() {
+            return Err(NetworkError::InvalidReadout(format!(
+                "{} Eingaben, aber {} Zielvektoren",
+                inputs.len(),
+                targets.len()
+            )));
+        }
+        let states = self.drive(inputs)?;
+        let washout = washout.min(states.len());
+        let readout = LinearReadout::fit_ridge(&states[washout..], &targets[washout..], lambda)?;
+        let predictions = predict_all(&readout, &states[washout..])?;
+        self.readout = Some(readout);
+        Ok(nrmse(&predictions, &targets[washout..]))
+    }
+
+    /// Legt die Eingaben an und gibt die Vorhersagen der Auslese zurück
+    pub fn predict(&mut self, inputs: &[Vec<f32>]) -> NetworkResult<Vec<Vec<f32>>> {
+        if self.readout.is_none() {
+            return Err(NetworkError::InvalidReadout(
+                "Auslese wurde noch nicht angepasst".to_string(),
+            ));
+        }
+        let states = self.drive(inputs)?;
+        predict_all(self.readout.as_ref().expect("wurde geprüft"), &states)
+    }
+
+    /// Bewertet die Auslese auf neuen Daten und gibt den Fehler ([`nrmse`]) zurück
+    ///
+    /// Die ersten `washout` Vorhersagen werden nicht bewertet.
+    pub fn evaluate(
+        &mut self,
+        inputs: &[Vec<f32>],
+        targets: &[Vec<f32>],
+        washout: usize,
+    ) -> NetworkResult<f64> {
+        if inputs.len() != targets.len() {
+            return Err(NetworkError::InvalidReadout(format!(
+                "{} Eingaben, aber {} Zielvektoren",
+                inputs.len(),
+                targets.len()
+            )));
+        }
+        let predictions = self.predict(inputs)?;
+        let washout = washout.min(predictions.len());
+        Ok(nrmse(&predictions[washout..], &targets[washout..]))
+    }
+}
+
+/// Wendet die Auslese auf jede Zeile der Zustandsmatrix an
+fn predict_all(readout: &LinearReadout, states: &[Vec<f32>]) -> NetworkResult<Vec<Vec<f32>>> {
+    states.iter().map(|state| readout.predict(state)).collect()
+}
@@ -0,0 +1,145 @@
+use crate::neural::network::NetworkError;
+use crate::neural::network::encoding::Encoding;
+use crate::neural::network::model::NetworkBuilder;
+use crate::neural::network::reservoir::{BenchmarkTask, ReservoirBuilder, nrmse};
+use crate::neural::network::topology::Topology;
+use crate::neural::neuron::lif::LifParams;
+use crate::neural::synapse::model::SynapseType;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+/// Zufällig verbundenes LIF-Netzwerk als Grundlage eines Reservoirs
+fn lif_builder(size: usize) -> NetworkBuilder {
+    NetworkBuilder::new()
+        .with_neurons(size, 100)
+        .with_lif_neurons(LifParams::default())
+        .with_random_connections(0.1, 0.5)
+        .with_seed(1)
+}
+
+#[test]
+fn test_spectral_radius_estimation_and_scaling() {
+    // Ein gerichteter Ring mit Gewicht 0.5 hat genau den Spektralradius 0.5
+    let mut ring = NetworkBuilder::new()
+        .with_neurons(10, 100)
+        .with_topology(Topology::Ring { neighbors: 1 }, 0.5)
+        .with_seed(3)
+        .build();
+    assert!((ring.spectral_radius() - 0.5).abs() < 1e-4);
+    let factor = ring.scale_to_spectral_radius(0.25);
+    assert!((factor - 0.5).abs() < 1e-3);
+    assert!((ring.spectral_radius() - 0.25).abs() < 1e-4);
+
+    // Ohne Zyklen ist der Spektralradius null und es wird nicht skaliert
+    let mut layered = NetworkBuilder::new()
+        .with_topology(Topology::FeedForward { layers: vec![3, 3] }, 0.5)
+        .build();
+    assert_eq!(layered.spectral_radius(), 0.0);
+    assert_eq!(layered.scale_to_spectral_radius(0.9), 1.0);
+
+    let reservoir = ReservoirBuilder::new(lif_builder(60))
+        .with_spectral_radius(0.9)
+        .with_inhibitory_fraction(0.3)
+        .with_seed(2)
+        .build()
+        .unwrap();
+    let network = reservoir.network();
+    assert!((network.spectral_radius() - 0.9).abs() < 0.05);
+
+    // Jedes Neuron sendet nur Synapsen einer Polarität
+    let mut inhibitory = 0;
+    for id in reservoir.state_ids() {
+        let types: Vec<SynapseType> = network
+            .outgoing_synapses(id)
+            .map(|synapse| synapse.synapse_type())
+            .collect();
+        assert!(types.windows(2).all(|pair| pair[0] == pair[1]));
+        if types.first() == Some(&SynapseType::Inhibitory) {
+            inhibitory += 1;
+        }
+    }
+    assert!((10..=25).contains(&inhibitory), "{} hemmend", inhibitory);
+}
+
+#[test]
+fn test_benchmark_tasks() {
+    let mut rng = StdRng::seed_from_u64(4);
+    let (inputs, targets) = BenchmarkTask::DelayedRecall { delay: 2 }.generate(50, &mut rng);
+    assert_eq!(inputs.len(), 50);
+    assert_eq!(targets[0], vec![0.0]);
+    assert_eq!(targets[10], inputs[8]);
+
+    let (inputs, targets) = BenchmarkTask::Narma { order: 10 }.generate(500, &mut rng);
+    assert!(inputs.iter().all(|u| (0.0..0.5).contains(&u[0])));
+    assert!(
+        targets
+            .iter()
+            .all(|y| y[0].is_finite() && y[0] > 0.0 && y[0] < 1.5)
+    );
+    // Rekursion am Ende der Zeitreihe nachrechnen
+    let t = 400;
+    let y = |t: usize| targets[t - 1][0] as f64;
+    let history: f64 = (t - 9..=t).map(y).sum();
+    let expected = 0.3 * y(t)
+        + 0.05 * y(t) * history
+        + 1.5 * inputs[t - 9][0] as f64 * inputs[t][0] as f64
+        + 0.1;
+    assert!((targets[t][0] as f64 - expected).abs() < 1e-5);
+
+    assert_eq!(nrmse(&targets, &targets), 0.0);
+    let mean = targets.iter().map(|y| y[0]).sum::<f32>() / targets.len() as f32;
+    let constant = vec![vec![mean]; targets.len()];
+    assert!((nrmse(&constant, &targets) - 1.0).abs() < 1e-3);
+}
+
+#[test]
+fn test_reservoir_learns_delayed_recall() {
+    let mut reservoir = ReservoirBuilder::new(lif_builder(80))
+        .with_spectral_radius(0.9)
+        .with_seed(2)
+        .build()
+        .unwrap();
+    assert_eq!(reservoir.input_ids().len(), 1);
+    assert_eq!(reservoir.state_ids().len(), 80);
+
+    let mut rng = StdRng::seed_from_u64(3);
+    let task = BenchmarkTask::DelayedRecall { delay: 1 };
+    let (test_inputs, test_targets) = task.generate(100, &mut rng);
+    assert!(matches!(
+        reservoir.evaluate(&test_inputs, &test_targets, 10),
+        Err(NetworkError::InvalidReadout(_))
+    ));
+
+    // Zustandsmatrix: eine Zeile je Fenster, Spuren und Aktivierungsenergien
+    reservoir.reset();
+    let states = reservoir.drive(&test_inputs[..5]).unwrap();
+    assert_eq!(states.len(), 5);
+    assert!(states.iter().all(|state| state.len() == 160));
+    assert!((reservoir.network().time() - 0.1).abs() < 1e-6);
+
+    reservoir.reset();
+    let (inputs, targets) = task.generate(300, &mut rng);
+    let training = reservoir.fit(&inputs, &targets, 20, 0.01).unwrap();
+    assert!(reservoir.readout().is_some());
+    let test = reservoir.evaluate(&test_inputs, &test_targets, 10).unwrap();
+    assert!(training < 0.7, "Trainingsfehler {}", training);
+    assert!(test < 0.8, "Testfehler {}", test);
+    assert!(matches!(
+        reservoir.evaluate(&test_inputs, &test_targets[..50], 10),
+        Err(NetworkError::InvalidReadout(_))
+    ));
+
+    assert!(matches!(
+        ReservoirBuilder::new(lif_builder(4))
+            .with_inputs(
+                1,
+                Encoding::ReceptiveFields {
+                    neurons_per_value: 5,
+                    min: 0.0,
+                    max: 1.0
+                }
+            )
+            .build(),
+        Err(NetworkError::InvalidReservoir(_))
+    ));
+}
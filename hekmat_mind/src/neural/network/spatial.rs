@@ -0,0 +1,134 @@
+//! Räumliche Anordnung von Neuronen und abstandsabhängige Verbindungen
+//!
+//! Eine [`Placement`] verteilt die Neuronen eines Netzwerks im Raum, ein
+//! [`DistanceKernel`] bestimmt daraus die Wahrscheinlichkeit einer Verbindung.
+//! Beide werden vom [`NetworkBuilder`](crate::neural::network::model::NetworkBuilder)
+//! verwendet; Zufallsentscheidungen stammen aus dessen Zufallsgenerator und sind
+//! damit über `with_seed` reproduzierbar.
+//!
+//! Positionen werden in denselben Einheiten wie [`Position`] angegeben, die
+//! Leitungsgeschwindigkeit in Positionseinheiten pro Sekunde.
+
+use crate::neural::growth::Position;
+use rand::Rng;
+use std::f32::consts::TAU;
+
+/// Verteilung der Neuronen im Raum
+#[derive(Debug, Clone, PartialEq)]
+pub enum Placement {
+    /// Gleichverteilt in einem achsenparallelen Quader
+    Uniform {
+        /// Ecke mit den kleinsten Koordinaten
+        min: Position,
+        /// Ecke mit den größten Koordinaten
+        max: Position,
+    },
+    /// Auf einem kubischen Gitter ab dem Ursprung, x-Achse läuft am schnellsten
+    Grid {
+        /// Abstand benachbarter Gitterpunkte
+        spacing: f32,
+    },
+    /// Normalverteilt um die angegebenen Zentren, Neuronen reihum auf die Cluster verteilt
+    GaussianClusters {
+        /// Mittelpunkte der Cluster
+        centers: Vec<Position>,
+        /// Standardabweichung je Achse
+        spread: f32,
+    },
+}
+
+impl Placement {
+    /// Erzeugt Positionen für `count` Neuronen
+    pub fn positions<R: Rng>(&self, count: usize, rng: &mut R) -> Vec<Position> {
+        match self {
+            Placement::Uniform { min, max } => (0..count)
+                .map(|_| {
+                    Position::new(
+                        sample_between(rng, min.x, max.x),
+                        sample_between(rng, min.y, max.y),
+                        sample_between(rng, min.z, max.z),
+                    )
+                })
+                .collect(),
+            Placement::Grid { spacing } => {
+                // Kleinste Kantenlänge, deren Würfel alle Neuronen aufnimmt
+                let mut side = 1;
+                while side * side * side < count {
+                    side += 1;
+                }
+                (0..count)
+                    .map(|index| {
+                        Position::new(
+                            (index % side) as f32 * spacing,
+                            (index / side % side) as f32 * spacing,
+                            (index / (side * side)) as f32 * spacing,
+                        )
+                    })
+                    .collect()
+            }
+            Placement::GaussianClusters { centers, spread } => {
+                if centers.is_empty() {
+                    return vec![Position::new(0.0, 0.0, 0.0); count];
+                }
+                (0..count)
+                    .map(|index| {
+                        let center = &centers[index % centers.len()];
+                        Position::new(
+                            center.x + spread * standard_normal(rng),
+                            center.y + spread * standard_normal(rng),
+                            center.z + spread * standard_normal(rng),
+                        )
+                    })
+                    .collect()
+            }
+        }
+    }
+}
+
+/// Verbindungswahrscheinlichkeit in Abhängigkeit vom Abstand zweier Neuronen
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DistanceKernel {
+    /// `peak · exp(−d² / (2 · width²))`
+    Gaussian {
+        /// Wahrscheinlichkeit bei Abstand 0 (0.0 - 1.0)
+        peak: f32,
+        /// Standardabweichung des Kerns
+        width: f32,
+    },
+    /// `peak · exp(−d / length)`
+    Exponential {
+        /// Wahrscheinlichkeit bei Abstand 0 (0.0 - 1.0)
+        peak: f32,
+        /// Abklinglänge des Kerns
+        length: f32,
+    },
+}
+
+impl DistanceKernel {
+    /// Gibt die Verbindungswahrscheinlichkeit für den Abstand `distance` zurück
+    pub fn probability(&self, distance: f32) -> f32 {
+        let probability = match *self {
+            DistanceKernel::Gaussian { peak, width } => {
+                peak * (-(distance * distance) / (2.0 * width * width)).exp()
+            }
+            DistanceKernel::Exponential { peak, length } => peak * (-distance / length).exp(),
+        };
+        if probability.is_nan() {
+            0.0
+        } else {
+            probability.clamp(0.0, 1.0)
+        }
+    }
+}
+
+/// Gleichverteilte Zahl zwischen zwei Grenzen (in beliebiger Reihenfolge)
+fn sample_between<R: Rng>(rng: &mut R, a: f32, b: f32) -> f32 {
+    a + (b - a) * rng.r#gen::<f32>()
+}
+
+/// Standardnormalverteilte Zahl nach Box-Muller
+fn standard_normal<R: Rng>(rng: &mut R) -> f32 {
+    let u1 = 1.0 - rng.r#gen::<f32>(); // (0, 1], damit der Logarithmus endlich bleibt
+    let u2 = rng.r#gen::<f32>();
+    (-2.0 * u1.ln()).sqrt() * (TAU * u2).cos()
+}
@@ -0,0 +1,155 @@
+use crate::neural::growth::Position;
+use crate::neural::network::model::NetworkBuilder;
+use crate::neural::network::spatial::{DistanceKernel, Placement};
+use crate::neural::synapse::model::constants as synapse_constants;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+#[test]
+fn test_placements() {
+    let mut rng = StdRng::seed_from_u64(2);
+
+    let uniform = Placement::Uniform {
+        min: Position::new(-1.0, 0.0, 2.0),
+        max: Position::new(1.0, 5.0, 3.0),
+    }
+    .positions(200, &mut rng);
+    assert_eq!(uniform.len(), 200);
+    assert!(uniform.iter().all(|p| (-1.0..=1.0).contains(&p.x)
+        && (0.0..=5.0).contains(&p.y)
+        && (2.0..=3.0).contains(&p.z)));
+
+    // 10 Neuronen passen in einen Würfel mit Kantenlänge 3
+    let grid = Placement::Grid { spacing: 2.0 }.positions(10, &mut rng);
+    assert_eq!(grid[0], Position::new(0.0, 0.0, 0.0));
+    assert_eq!(grid[1], Position::new(2.0, 0.0, 0.0));
+    assert_eq!(grid[3], Position::new(0.0, 2.0, 0.0));
+    assert_eq!(grid[9], Position::new(0.0, 0.0, 2.0));
+
+    let centers = vec![Position::new(0.0, 0.0, 0.0), Position::new(100.0, 0.0, 0.0)];
+    let clustered = Placement::GaussianClusters {
+        centers: centers.clone(),
+        spread: 1.0,
+    }
+    .positions(400, &mut rng);
+    for (index, position) in clustered.iter().enumerate() {
+        assert!(position.distance_to(&centers[index % 2]) < 10.0);
+    }
+    let mean_x = clustered.iter().step_by(2).map(|p| p.x).sum::<f32>() / 200.0;
+    assert!(mean_x.abs() < 0.3, "Mittelwert {}", mean_x);
+}
+
+#[test]
+fn test_distance_kernels() {
+    let gaussian = DistanceKernel::Gaussian {
+        peak: 0.8,
+        width: 2.0,
+    };
+    assert_eq!(gaussian.probability(0.0), 0.8);
+    assert!((gaussian.probability(2.0) - 0.8 * (-0.5_f32).exp()).abs() < 1e-6);
+
+    let exponential = DistanceKernel::Exponential {
+        peak: 1.5,
+        length: 1.0,
+    };
+    assert_eq!(exponential.probability(0.0), 1.0);
+    assert!((exponential.probability(3.0) - 1.5 * (-3.0_f32).exp()).abs() < 1e-6);
+    assert!(exponential.probability(100.0) < 1e-6);
+}
+
+#[test]
+fn test_distance_dependent_connectivity() {
+    let network = NetworkBuilder::new()
+        .with_neurons(300, 100)
+        .with_placement(Placement::Uniform {
+            min: Position::new(0.0, 0.0, 0.0),
+            max: Position::new(10.0, 10.0, 10.0),
+        })
+        .with_distance_connections(
+            DistanceKernel::Gaussian {
+                peak: 1.0,
+                width: 1.5,
+            },
+            0.5,
+        )
+        .with_seed(9)
+        .build();
+    assert!(network.synapse_count() > 0);
+
+    // Verbundene Paare liegen deutlich näher beieinander als zufällige Paare (≈ 6.6)
+    let distance = |pre, post| {
+        let pre = network.get_neuron(pre).unwrap().position();
+        pre.distance_to(network.get_neuron(post).unwrap().position())
+    };
+    let mean = network
+        .synapses()
+        .keys()
+        .map(|(pre, post)| distance(pre, post))
+        .sum::<f32>()
+        / network.synapse_count() as f32;
+    assert!(mean < 3.5, "mittlerer Abstand {}", mean);
+    assert!(
+        network
+            .synapses()
+            .values()
+            .all(|synapse| synapse.delay() == synapse_constants::DEFAULT_DELAY)
+    );
+}
+
+#[test]
+fn test_delays_from_conduction_velocity() {
+    let build = |seed: u64| {
+        NetworkBuilder::new()
+            .with_neurons(27, 100)
+            .with_placement(Placement::Grid { spacing: 1.0 })
+            .with_distance_connections(
+                DistanceKernel::Exponential {
+                    peak: 1.0,
+                    length: 1.0,
+                },
+                0.5,
+            )
+            .with_conduction_velocity(500.0)
+            .with_seed(seed)
+            .build()
+    };
+    let network = build(4);
+    assert!(network.synapse_count() > 0);
+
+    for ((pre, post), synapse) in network.synapses().iter() {
+        let distance = network
+            .get_neuron(pre)
+            .unwrap()
+            .position()
+            .distance_to(network.get_neuron(post).unwrap().position());
+        let expected = synapse_constants::DEFAULT_DELAY + distance / 500.0;
+        assert!((synapse.delay() - expected).abs() < 1e-7);
+    }
+
+    // Gleicher Startwert, gleiche Verbindungen und Verzögerungen
+    let mut first: Vec<_> = network
+        .synapses()
+        .values()
+        .map(|synapse| {
+            (
+                *synapse.pre_neuron_id(),
+                *synapse.post_neuron_id(),
+                synapse.delay(),
+            )
+        })
+        .collect();
+    let mut second: Vec<_> = build(4)
+        .synapses()
+        .values()
+        .map(|synapse| {
+            (
+                *synapse.pre_neuron_id(),
+                *synapse.post_neuron_id(),
+                synapse.delay(),
+            )
+        })
+        .collect();
+    first.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    second.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    assert_eq!(first, second);
+}
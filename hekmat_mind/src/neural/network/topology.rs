@@ -0,0 +1,239 @@
+//! Generatoren für Standardtopologien
+//!
+//! Eine [`Topology`] beschreibt, welche Neuronen eines Netzwerks verbunden werden.
+//! Der [`NetworkBuilder`](crate::neural::network::model::NetworkBuilder) erzeugt die
+//! Verbindungen über [`with_topology`](crate::neural::network::model::NetworkBuilder::with_topology)
+//! und zieht Zufallsentscheidungen (Umverdrahtung, bevorzugte Anbindung) aus seinem
+//! Zufallsgenerator, sodass sie mit `with_seed` reproduzierbar sind.
+//!
+//! Ungerichtete Kanten der klassischen Modelle (Watts–Strogatz, Barabási–Albert,
+//! Gitter) werden als zwei gegenläufige Synapsen angelegt.
+
+use rand::Rng;
+use rand::seq::SliceRandom;
+use std::collections::BTreeSet;
+
+/// Verbindungsstruktur eines Netzwerks
+#[derive(Debug, Clone, PartialEq)]
+pub enum Topology {
+    /// Gerichteter Ring: jedes Neuron verbindet sich mit seinen `neighbors` Nachfolgern
+    Ring {
+        /// Anzahl der Nachfolger je Neuron
+        neighbors: usize,
+    },
+    /// Kleine-Welt-Netzwerk nach Watts und Strogatz
+    ///
+    /// Ausgangspunkt ist ein ungerichteter Ring, in dem jedes Neuron mit seinen
+    /// `neighbors` nächsten Nachbarn verbunden ist (je die Hälfte auf jeder Seite).
+    /// Jede Kante wird mit Wahrscheinlichkeit `rewiring` auf ein zufälliges Ziel umgelegt.
+    SmallWorld {
+        /// Anzahl der Nachbarn je Neuron im Ausgangsring (gerade)
+        neighbors: usize,
+        /// Wahrscheinlichkeit, eine Kante umzuverdrahten (0.0 - 1.0)
+        rewiring: f32,
+    },
+    /// Skalenfreies Netzwerk nach Barabási und Albert
+    ///
+    /// Beginnend mit `edges_per_neuron + 1` vollständig verbundenen Neuronen wird jedes
+    /// weitere Neuron mit `edges_per_neuron` bestehenden Neuronen verbunden, ausgewählt
+    /// mit einer Wahrscheinlichkeit proportional zu ihrem Grad.
+    ScaleFree {
+        /// Anzahl der Kanten jedes neu hinzukommenden Neurons
+        edges_per_neuron: usize,
+    },
+    /// Zweidimensionales Gitter mit Verbindungen zu den vier direkten Nachbarn
+    Lattice2d {
+        /// Breite des Gitters
+        width: usize,
+        /// Höhe des Gitters
+        height: usize,
+        /// Periodische Randbedingungen (Torus)
+        periodic: bool,
+    },
+    /// Dreidimensionales Gitter mit Verbindungen zu den sechs direkten Nachbarn
+    Lattice3d {
+        /// Breite des Gitters
+        width: usize,
+        /// Höhe des Gitters
+        height: usize,
+        /// Tiefe des Gitters
+        depth: usize,
+        /// Periodische Randbedingungen
+        periodic: bool,
+    },
+    /// Vorwärtsgerichtete Schichten, jede vollständig mit der folgenden verbunden
+    FeedForward {
+        /// Anzahl der Neuronen je Schicht, von der Eingabe- zur Ausgabeschicht
+        layers: Vec<usize>,
+    },
+}
+
+impl Topology {
+    /// Anzahl der Neuronen, die die Topologie festlegt
+    ///
+    /// Gitter und Schichten bestimmen die Größe des Netzwerks selbst; für Ringe,
+    /// Kleine-Welt- und skalenfreie Netzwerke gilt die Anzahl des Builders.
+    pub fn neuron_count(&self) -> Option<usize> {
+        match self {
+            Topology::Lattice2d { width, height, .. } => Some(width * height),
+            Topology::Lattice3d {
+                width,
+                height,
+                depth,
+                ..
+            } => Some(width * height * depth),
+            Topology::FeedForward { layers } => Some(layers.iter().sum()),
+            _ => None,
+        }
+    }
+
+    /// Erzeugt die gerichteten Verbindungen zwischen `count` Neuronen als Indexpaare
+    ///
+    /// Die Paare sind sortiert und enthalten weder Duplikate noch Selbstverbindungen.
+    pub fn connections<R: Rng>(&self, count: usize, rng: &mut R) -> Vec<(usize, usize)> {
+        let mut edges = BTreeSet::new();
+        match self {
+            Topology::Ring { neighbors } => {
+                for i in 0..count {
+                    for offset in 1..=(*neighbors).min(count.saturating_sub(1)) {
+                        edges.insert((i, (i + offset) % count));
+                    }
+                }
+            }
+            Topology::SmallWorld {
+                neighbors,
+                rewiring,
+            } => small_world(count, *neighbors, *rewiring, rng, &mut edges),
+            Topology::ScaleFree { edges_per_neuron } => {
+                scale_free(count, *edges_per_neuron, rng, &mut edges)
+            }
+            Topology::Lattice2d {
+                width,
+                height,
+                periodic,
+            } => lattice(&[*width, *height], *periodic, count, &mut edges),
+            Topology::Lattice3d {
+                width,
+                height,
+                depth,
+                periodic,
+            } => lattice(&[*width, *height, *depth], *periodic, count, &mut edges),
+            Topology::FeedForward { layers } => {
+                let mut start = 0;
+                for pair in layers.windows(2) {
+                    let next = start + pair[0];
+                    for pre in start..next.min(count) {
+                        for post in next..(next + pair[1]).min(count) {
+                            edges.insert((pre, post));
+                        }
+                    }
+                    start = next;
+                }
+            }
+        }
+        edges.into_iter().collect()
+    }
+}
+
+/// Fügt eine ungerichtete Kante als zwei gerichtete Verbindungen hinzu
+fn insert_undirected(edges: &mut BTreeSet<(usize, usize)>, a: usize, b: usize) {
+    if a != b {
+        edges.insert((a, b));
+        edges.insert((b, a));
+    }
+}
+
+/// Watts–Strogatz: Ringgitter mit zufälliger Umverdrahtung
+fn small_world<R: Rng>(
+    count: usize,
+    neighbors: usize,
+    rewiring: f32,
+    rng: &mut R,
+    edges: &mut BTreeSet<(usize, usize)>,
+) {
+    if count < 2 {
+        return;
+    }
+    let half = (neighbors / 2).max(1).min(((count - 1) / 2).max(1));
+    let rewiring = rewiring.clamp(0.0, 1.0) as f64;
+
+    for offset in 1..=half {
+        for i in 0..count {
+            let j = (i + offset) % count;
+            if rng.gen_bool(rewiring) {
+                // Neues Ziel ohne Selbstverbindung und ohne doppelte Kante
+                let candidates: Vec<usize> = (0..count)
+                    .filter(|&k| k != i && !edges.contains(&(i, k)))
+                    .collect();
+                if let Some(&k) = candidates.choose(rng) {
+                    insert_undirected(edges, i, k);
+                    continue;
+                }
+            }
+            insert_undirected(edges, i, j);
+        }
+    }
+}
+
+/// Barabási–Albert: Wachstum mit bevorzugter Anbindung
+fn scale_free<R: Rng>(
+    count: usize,
+    edges_per_neuron: usize,
+    rng: &mut R,
+    edges: &mut BTreeSet<(usize, usize)>,
+) {
+    let m = edges_per_neuron.max(1);
+    let seed_size = (m + 1).min(count);
+
+    // Jeder Eintrag steht für ein Kantenende; ein Neuron erscheint so oft wie sein Grad
+    let mut endpoints = Vec::new();
+    for a in 0..seed_size {
+        for b in (a + 1)..seed_size {
+            insert_undirected(edges, a, b);
+            endpoints.extend([a, b]);
+        }
+    }
+
+    for new in seed_size..count {
+        let mut targets = BTreeSet::new();
+        while targets.len() < m {
+            targets.insert(*endpoints.choose(rng).expect("Startnetzwerk hat Kanten"));
+        }
+        for target in targets {
+            insert_undirected(edges, new, target);
+            endpoints.extend([new, target]);
+        }
+    }
+}
+
+/// Gitter beliebiger Dimension mit Verbindungen zu den direkten Nachbarn
+fn lattice(shape: &[usize], periodic: bool, count: usize, edges: &mut BTreeSet<(usize, usize)>) {
+    let size: usize = shape.iter().product();
+    for index in 0..size.min(count) {
+        // Koordinaten aus dem Index, erste Achse läuft am schnellsten
+        let mut coordinates = Vec::with_capacity(shape.len());
+        let mut rest = index;
+        for &extent in shape {
+            coordinates.push(rest % extent);
+            rest /= extent;
+        }
+
+        let mut stride = 1;
+        for (axis, &extent) in shape.iter().enumerate() {
+            let coordinate = coordinates[axis];
+            let next = if coordinate + 1 < extent {
+                Some(index + stride)
+            } else if periodic && extent > 1 {
+                Some(index + stride - extent * stride)
+            } else {
+                None
+            };
+            if let Some(neighbor) = next
+                && neighbor < count
+            {
+                insert_undirected(edges, index, neighbor);
+            }
+            stride *= extent;
+        }
+    }
+}
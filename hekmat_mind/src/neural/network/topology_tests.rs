@@ -0,0 +1,176 @@
+use crate::neural::network::model::NetworkBuilder;
+use crate::neural::network::topology::Topology;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use std::collections::HashSet;
+
+/// Anzahl der ausgehenden Verbindungen je Neuron
+fn out_degrees(edges: &[(usize, usize)], count: usize) -> Vec<usize> {
+    let mut degrees = vec![0; count];
+    for (pre, _) in edges {
+        degrees[*pre] += 1;
+    }
+    degrees
+}
+
+/// Prüft, dass jede Kante in beide Richtungen existiert
+fn is_symmetric(edges: &[(usize, usize)]) -> bool {
+    let set: HashSet<_> = edges.iter().copied().collect();
+    edges.iter().all(|(a, b)| set.contains(&(*b, *a)))
+}
+
+#[test]
+fn test_ring_connects_successors() {
+    let mut rng = StdRng::seed_from_u64(1);
+    let edges = Topology::Ring { neighbors: 2 }.connections(6, &mut rng);
+
+    assert_eq!(edges.len(), 12);
+    assert!(edges.contains(&(5, 0)));
+    assert!(edges.contains(&(5, 1)));
+    assert!(!edges.contains(&(0, 5)));
+    assert!(out_degrees(&edges, 6).iter().all(|&degree| degree == 2));
+}
+
+#[test]
+fn test_small_world_rewiring() {
+    let mut rng = StdRng::seed_from_u64(1);
+
+    // Ohne Umverdrahtung entsteht das reguläre Ringgitter
+    let regular = Topology::SmallWorld {
+        neighbors: 4,
+        rewiring: 0.0,
+    }
+    .connections(20, &mut rng);
+    assert_eq!(regular.len(), 20 * 4);
+    assert!(is_symmetric(&regular));
+    assert!(regular.contains(&(0, 18)) && regular.contains(&(0, 2)));
+
+    // Umverdrahtung erhält die Kantenzahl, verändert aber die Struktur
+    let rewired = Topology::SmallWorld {
+        neighbors: 4,
+        rewiring: 0.3,
+    }
+    .connections(20, &mut rng);
+    assert!(is_symmetric(&rewired));
+    assert!(rewired.iter().all(|(a, b)| a != b));
+    assert_ne!(rewired, regular);
+    assert!(rewired.len() <= regular.len() && rewired.len() >= regular.len() * 8 / 10);
+}
+
+#[test]
+fn test_scale_free_degree_distribution() {
+    let mut rng = StdRng::seed_from_u64(5);
+    let count = 300;
+    let edges = Topology::ScaleFree {
+        edges_per_neuron: 2,
+    }
+    .connections(count, &mut rng);
+
+    // Startclique mit drei Kanten, danach zwei Kanten je Neuron
+    assert_eq!(edges.len(), 2 * (3 + 2 * (count - 3)));
+    assert!(is_symmetric(&edges));
+
+    let degrees = out_degrees(&edges, count);
+    assert!(degrees.iter().all(|&degree| degree >= 2));
+    // Bevorzugte Anbindung erzeugt Knoten mit weit überdurchschnittlichem Grad
+    let max_degree = *degrees.iter().max().unwrap();
+    assert!(max_degree > 5 * 4, "maximaler Grad {}", max_degree);
+}
+
+#[test]
+fn test_lattices_with_and_without_periodic_boundaries() {
+    let mut rng = StdRng::seed_from_u64(1);
+
+    let open = Topology::Lattice2d {
+        width: 4,
+        height: 3,
+        periodic: false,
+    };
+    let edges = open.connections(12, &mut rng);
+    // (3 · 3 waagrechte + 4 · 2 senkrechte Kanten) in beide Richtungen
+    assert_eq!(edges.len(), 2 * (9 + 8));
+    assert_eq!(out_degrees(&edges, 12)[0], 2);
+    assert_eq!(out_degrees(&edges, 12)[5], 4);
+
+    let torus = Topology::Lattice2d {
+        width: 4,
+        height: 3,
+        periodic: true,
+    };
+    let edges = torus.connections(12, &mut rng);
+    assert!(out_degrees(&edges, 12).iter().all(|&degree| degree == 4));
+    assert!(edges.contains(&(3, 0)) && edges.contains(&(8, 0)));
+
+    let cube = Topology::Lattice3d {
+        width: 3,
+        height: 3,
+        depth: 3,
+        periodic: true,
+    };
+    assert_eq!(cube.neuron_count(), Some(27));
+    let edges = cube.connections(27, &mut rng);
+    assert!(out_degrees(&edges, 27).iter().all(|&degree| degree == 6));
+    assert!(is_symmetric(&edges));
+}
+
+#[test]
+fn test_feed_forward_layers() {
+    let mut rng = StdRng::seed_from_u64(1);
+    let topology = Topology::FeedForward {
+        layers: vec![3, 4, 2],
+    };
+    assert_eq!(topology.neuron_count(), Some(9));
+
+    let edges = topology.connections(9, &mut rng);
+    assert_eq!(edges.len(), 3 * 4 + 4 * 2);
+    assert!(edges.iter().all(|(pre, post)| pre < post));
+    assert!(edges.contains(&(0, 3)) && edges.contains(&(6, 8)));
+    assert!(!edges.contains(&(0, 7)));
+}
+
+#[test]
+fn test_builder_uses_topology_and_seed() {
+    let build = |seed: u64| {
+        NetworkBuilder::new()
+            .with_neurons(40, 100)
+            .with_topology(
+                Topology::SmallWorld {
+                    neighbors: 4,
+                    rewiring: 0.2,
+                },
+                0.4,
+            )
+            .with_seed(seed)
+            .build()
+    };
+
+    let network = build(11);
+    assert_eq!(network.neuron_count(), 40);
+    assert!(network.synapse_count() > 0);
+    assert!(
+        network
+            .synapses()
+            .values()
+            .all(|synapse| synapse.weight() == 0.4)
+    );
+    let mut first: Vec<_> = network.synapses().keys().copied().collect();
+    let mut second: Vec<_> = build(11).synapses().keys().copied().collect();
+    first.sort();
+    second.sort();
+    assert_eq!(first, second);
+
+    // Gitter legen die Anzahl der Neuronen selbst fest
+    let lattice = NetworkBuilder::new()
+        .with_neurons(5, 100)
+        .with_topology(
+            Topology::Lattice2d {
+                width: 5,
+                height: 5,
+                periodic: true,
+            },
+            0.5,
+        )
+        .build();
+    assert_eq!(lattice.neuron_count(), 25);
+    assert_eq!(lattice.synapse_count(), 100);
+}
@@ -0,0 +1,494 @@
+//! Leitfähigkeitsbasiertes Hodgkin-Huxley-Neuron
+//!
+//! Das Modell beschreibt das Membranpotential des Tintenfisch-Riesenaxons über
+//! spannungsabhängige Natrium- und Kaliumkanäle sowie einen Leckstrom:
+//!
+//! ```text
+//! C·V' = I - g_Na·m³·h·(V - E_Na) - g_K·n⁴·(V - E_K) - g_L·(V - E_L)
+//! x'   = α_x(V)·(1 - x) - β_x(V)·x      für x ∈ {m, h, n}
+//! ```
+//!
+//! Es werden die Originalparameter (1952) in der heute üblichen Konvention mit einem
+//! Ruhepotential von etwa -65 mV verwendet. Zeit in ms, Spannung in mV,
+//! Ströme in µA/cm², Leitfähigkeiten in mS/cm².
+//!
+//! Da das Gleichungssystem steif ist, wird intern mit einem festen, kleinen Zeitschritt
+//! integriert. Das Verfahren ist über [`HhIntegrator`] wählbar.
+
+use crate::neural::growth::Position;
+use crate::neural::neuron::model::NeuronState;
+use crate::neural::neuron::traits::NeuronModel;
+use serde::{Deserialize, Serialize};
+use std::any::Any;
+use std::collections::VecDeque;
+use uuid::Uuid;
+
+/// Referenzparameter des Hodgkin-Huxley-Modells
+pub mod constants {
+    /// Membrankapazität in µF/cm²
+    pub const MEMBRANE_CAPACITANCE: f64 = 1.0;
+    /// Maximale Natriumleitfähigkeit in mS/cm²
+    pub const G_NA: f64 = 120.0;
+    /// Maximale Kaliumleitfähigkeit in mS/cm²
+    pub const G_K: f64 = 36.0;
+    /// Leckleitfähigkeit in mS/cm²
+    pub const G_L: f64 = 0.3;
+    /// Umkehrpotential von Natrium in mV
+    pub const E_NA: f64 = 50.0;
+    /// Umkehrpotential von Kalium in mV
+    pub const E_K: f64 = -77.0;
+    /// Umkehrpotential des Leckstroms in mV
+    pub const E_L: f64 = -54.387;
+    /// Ruhepotential in mV
+    pub const RESTING_POTENTIAL_MV: f64 = -65.0;
+    /// Spannung, deren Überschreitung von unten als Spike gezählt wird
+    pub const SPIKE_DETECTION_MV: f64 = 0.0;
+    /// Standard-Integrationsschritt in Millisekunden
+    pub const DEFAULT_INTEGRATION_STEP_MS: f64 = 0.01;
+    /// Standard-Spannungssprung in mV pro Einheit synaptischer Eingabe
+    pub const DEFAULT_INPUT_GAIN_MV: f64 = 20.0;
+    /// Standardkapazität des Spannungsverlaufs in Messpunkten (100 ms bei 0.01 ms Schrittweite)
+    pub const DEFAULT_TRACE_CAPACITY: usize = 10_000;
+}
+
+/// Integrationsverfahren für das Hodgkin-Huxley-Gleichungssystem
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum HhIntegrator {
+    /// Exponentielles Euler-Verfahren: für jede Variable wird die exakte Lösung bei
+    /// eingefrorenen Koeffizienten verwendet, was auch bei größeren Schritten stabil bleibt
+    #[default]
+    ExponentialEuler,
+    /// Klassisches Runge-Kutta-Verfahren vierter Ordnung
+    RungeKutta4,
+}
+
+/// Leitfähigkeiten und Umkehrpotentiale der Ionenkanäle
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct HhParams {
+    /// Membrankapazität in µF/cm²
+    pub capacitance: f64,
+    /// Maximale Natriumleitfähigkeit in mS/cm²
+    pub g_na: f64,
+    /// Maximale Kaliumleitfähigkeit in mS/cm²
+    pub g_k: f64,
+    /// Leckleitfähigkeit in mS/cm²
+    pub g_l: f64,
+    /// Umkehrpotential von Natrium in mV
+    pub e_na: f64,
+    /// Umkehrpotential von Kalium in mV
+    pub e_k: f64,
+    /// Umkehrpotential des Leckstroms in mV
+    pub e_l: f64,
+}
+
+impl Default for HhParams {
+    fn default() -> Self {
+        Self {
+            capacitance: constants::MEMBRANE_CAPACITANCE,
+            g_na: constants::G_NA,
+            g_k: constants::G_K,
+            g_l: constants::G_L,
+            e_na: constants::E_NA,
+            e_k: constants::E_K,
+            e_l: constants::E_L,
+        }
+    }
+}
+
+/// Ein Messpunkt der Membranspannung
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct VoltageSample {
+    /// Simulationszeit des Neurons in ms
+    pub time_ms: f64,
+    /// Membranspannung in mV
+    pub voltage_mv: f64,
+}
+
+/// Zustand des Gleichungssystems: Spannung und Gating-Variablen
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+struct HhState {
+    v: f64,
+    m: f64,
+    h: f64,
+    n: f64,
+}
+
+/// Ratenfunktionen α und β der Gating-Variablen
+fn alpha_m(v: f64) -> f64 {
+    let x = v + 40.0;
+    if x.abs() < 1e-7 {
+        1.0
+    } else {
+        0.1 * x / (1.0 - (-x / 10.0).exp())
+    }
+}
+
+fn beta_m(v: f64) -> f64 {
+    4.0 * (-(v + 65.0) / 18.0).exp()
+}
+
+fn alpha_h(v: f64) -> f64 {
+    0.07 * (-(v + 65.0) / 20.0).exp()
+}
+
+fn beta_h(v: f64) -> f64 {
+    1.0 / (1.0 + (-(v + 35.0) / 10.0).exp())
+}
+
+fn alpha_n(v: f64) -> f64 {
+    let x = v + 55.0;
+    if x.abs() < 1e-7 {
+        0.1
+    } else {
+        0.01 * x / (1.0 - (-x / 10.0).exp())
+    }
+}
+
+fn beta_n(v: f64) -> f64 {
+    0.125 * (-(v + 65.0) / 80.0).exp()
+}
+
+impl HhState {
+    /// Ruhezustand bei gegebener Spannung (Gating-Variablen im Gleichgewicht)
+    fn at_rest(v: f64) -> Self {
+        let steady = |alpha: f64, beta: f64| alpha / (alpha + beta);
+        Self {
+            v,
+            m: steady(alpha_m(v), beta_m(v)),
+            h: steady(alpha_h(v), beta_h(v)),
+            n: steady(alpha_n(v), beta_n(v)),
+        }
+    }
+
+    /// Zeitableitungen aller Zustandsvariablen
+    fn derivatives(&self, params: &HhParams, current: f64) -> Self {
+        let i_na = params.g_na * self.m.powi(3) * self.h * (self.v - params.e_na);
+        let i_k = params.g_k * self.n.powi(4) * (self.v - params.e_k);
+        let i_l = params.g_l * (self.v - params.e_l);
+
+        Self {
+            v: (current - i_na - i_k - i_l) / params.capacitance,
+            m: alpha_m(self.v) * (1.0 - self.m) - beta_m(self.v) * self.m,
+            h: alpha_h(self.v) * (1.0 - self.h) - beta_h(self.v) * self.h,
+            n: alpha_n(self.v) * (1.0 - self.n) - beta_n(self.v) * self.n,
+        }
+    }
+
+    /// Linearkombination `self + k·other` für das Runge-Kutta-Verfahren
+    fn add_scaled(&self, other: &Self, k: f64) -> Self {
+        Self {
+            v: self.v + k * other.v,
+            m: self.m + k * other.m,
+            h: self.h + k * other.h,
+            n: self.n + k * other.n,
+        }
+    }
+
+    /// Ein Schritt des klassischen Runge-Kutta-Verfahrens
+    fn rk4_step(&self, params: &HhParams, current: f64, dt: f64) -> Self {
+        let k1 = self.derivatives(params, current);
+        let k2 = self.add_scaled(&k1, dt / 2.0).derivatives(params, current);
+        let k3 = self.add_scaled(&k2, dt / 2.0).derivatives(params, current);
+        let k4 = self.add_scaled(&k3, dt).derivatives(params, current);
+
+        Self {
+            v: self.v + dt / 6.0 * (k1.v + 2.0 * k2.v + 2.0 * k3.v + k4.v),
+            m: self.m + dt / 6.0 * (k1.m + 2.0 * k2.m + 2.0 * k3.m + k4.m),
+            h: self.h + dt / 6.0 * (k1.h + 2.0 * k2.h + 2.0 * k3.h + k4.h),
+            n: self.n + dt / 6.0 * (k1.n + 2.0 * k2.n + 2.0 * k3.n + k4.n),
+        }
+    }
+
+    /// Ein Schritt des exponentiellen Euler-Verfahrens
+    fn exponential_euler_step(&self, params: &HhParams, current: f64, dt: f64) -> Self {
+        let gate = |x: f64, alpha: f64, beta: f64| {
+            let tau = 1.0 / (alpha + beta);
+            let inf = alpha * tau;
+            inf + (x - inf) * (-dt / tau).exp()
+        };
+
+        // Spannung: lineare Gleichung bei festen Leitfähigkeiten
+        let g_na = params.g_na * self.m.powi(3) * self.h;
+        let g_k = params.g_k * self.n.powi(4);
+        let g_total = g_na + g_k + params.g_l;
+        let v_inf =
+            (g_na * params.e_na + g_k * params.e_k + params.g_l * params.e_l + current) / g_total;
+        let v = v_inf + (self.v - v_inf) * (-dt * g_total / params.capacitance).exp();
+
+        Self {
+            v,
+            m: gate(self.m, alpha_m(self.v), beta_m(self.v)),
+            h: gate(self.h, alpha_h(self.v), beta_h(self.v)),
+            n: gate(self.n, alpha_n(self.v), beta_n(self.v)),
+        }
+    }
+}
+
+/// Hodgkin-Huxley-Neuron mit Natrium-, Kalium- und Leckkanal
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HodgkinHuxleyNeuron {
+    /// Eindeutige ID des Neurons
+    id: Uuid,
+    /// Kanalparameter
+    params: HhParams,
+    /// Integrationsverfahren
+    integrator: HhIntegrator,
+    /// Interner Integrationsschritt in ms
+    integration_step_ms: f64,
+    /// Spannung und Gating-Variablen
+    state: HhState,
+    /// Konstanter Eingangsstrom in µA/cm²
+    input_current: f64,
+    /// Spannungssprung in mV pro Einheit synaptischer Eingabe
+    input_gain_mv: f64,
+    /// Interne Simulationszeit in ms
+    time_ms: f64,
+    /// Gibt an, ob das Neuron im letzten Zeitschritt gefeuert hat
+    spiked: bool,
+    /// Aufgezeichneter Spannungsverlauf, falls aktiviert (älteste Messpunkte zuerst)
+    #[serde(default)]
+    trace: Option<VecDeque<VoltageSample>>,
+    /// Höchstzahl der aufbewahrten Messpunkte des Spannungsverlaufs
+    #[serde(default = "default_trace_capacity")]
+    trace_capacity: usize,
+    /// Position des Neurons im 3D-Raum
+    position: Position,
+}
+
+fn default_trace_capacity() -> usize {
+    constants::DEFAULT_TRACE_CAPACITY
+}
+
+impl HodgkinHuxleyNeuron {
+    /// Erstellt ein Hodgkin-Huxley-Neuron mit den Referenzparametern im Ruhezustand
+    pub fn new() -> Self {
+        Self::with_params(HhParams::default())
+    }
+
+    /// Erstellt ein Hodgkin-Huxley-Neuron mit benutzerdefinierten Kanalparametern
+    pub fn with_params(params: HhParams) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            params,
+            integrator: HhIntegrator::default(),
+            integration_step_ms: constants::DEFAULT_INTEGRATION_STEP_MS,
+            state: HhState::at_rest(constants::RESTING_POTENTIAL_MV),
+            input_current: 0.0,
+            input_gain_mv: constants::DEFAULT_INPUT_GAIN_MV,
+            time_ms: 0.0,
+            spiked: false,
+            trace: None,
+            trace_capacity: constants::DEFAULT_TRACE_CAPACITY,
+            position: Position::new(0.0, 0.0, 0.0),
+        }
+    }
+
+    /// Ersetzt die ID des Neurons
+    pub fn with_id(mut self, id: Uuid) -> Self {
+        self.id = id;
+        self
+    }
+
+    /// Wählt das Integrationsverfahren und die interne Schrittweite in ms
+    pub fn with_integrator(mut self, integrator: HhIntegrator, step_ms: f64) -> Self {
+        self.integrator = integrator;
+        self.integration_step_ms = step_ms.max(1e-6);
+        self
+    }
+
+    /// Setzt den Spannungssprung in mV pro Einheit synaptischer Eingabe
+    pub fn with_input_gain(mut self, gain_mv: f64) -> Self {
+        self.input_gain_mv = gain_mv;
+        self
+    }
+
+    /// Aktiviert die Aufzeichnung des Spannungsverlaufs bei jedem internen Schritt
+    ///
+    /// Aufbewahrt werden die letzten [`constants::DEFAULT_TRACE_CAPACITY`] Messpunkte.
+    /// Für längere Aufzeichnungen über ganze Netzwerke sind Monitore vorgesehen.
+    pub fn with_voltage_trace(self) -> Self {
+        self.with_voltage_trace_capacity(constants::DEFAULT_TRACE_CAPACITY)
+    }
+
+    /// Aktiviert die Aufzeichnung des Spannungsverlaufs mit höchstens `capacity`
+    /// Messpunkten; ist der Puffer voll, wird der älteste Messpunkt verworfen
+    pub fn with_voltage_trace_capacity(mut self, capacity: usize) -> Self {
+        self.trace = Some(VecDeque::with_capacity(capacity.min(1024)));
+        self.trace_capacity = capacity;
+        self
+    }
+
+    /// Gibt die Kanalparameter zurück
+    pub fn params(&self) -> &HhParams {
+        &self.params
+    }
+
+    /// Gibt das verwendete Integrationsverfahren zurück
+    pub fn integrator(&self) -> HhIntegrator {
+        self.integrator
+    }
+
+    /// Gibt die Membranspannung in mV zurück
+    pub fn voltage(&self) -> f64 {
+        self.state.v
+    }
+
+    /// Gibt die Gating-Variablen `(m, h, n)` zurück
+    pub fn gating(&self) -> (f64, f64, f64) {
+        (self.state.m, self.state.h, self.state.n)
+    }
+
+    /// Gibt die interne Simulationszeit in ms zurück
+    pub fn time_ms(&self) -> f64 {
+        self.time_ms
+    }
+
+    /// Gibt den konstanten Eingangsstrom in µA/cm² zurück
+    pub fn input_current(&self) -> f64 {
+        self.input_current
+    }
+
+    /// Setzt einen konstanten Eingangsstrom in µA/cm²
+    pub fn set_input_current(&mut self, current: f64) {
+        self.input_current = current;
+    }
+
+    /// Gibt den aufgezeichneten Spannungsverlauf zurück (leer, falls nicht aktiviert)
+    pub fn voltage_trace(&self) -> &VecDeque<VoltageSample> {
+        static EMPTY: VecDeque<VoltageSample> = VecDeque::new();
+        self.trace.as_ref().unwrap_or(&EMPTY)
+    }
+
+    /// Verwirft den bisher aufgezeichneten Spannungsverlauf
+    pub fn clear_voltage_trace(&mut self) {
+        if let Some(trace) = self.trace.as_mut() {
+            trace.clear();
+        }
+    }
+
+    /// Integriert das Modell um `duration_ms` und gibt zurück, ob ein Spike auftrat
+    fn advance(&mut self, duration_ms: f64) -> bool {
+        // Rundungsfehler aus der f32-Umrechnung dürfen keinen zusätzlichen Teilschritt erzeugen
+        let substeps = (duration_ms / self.integration_step_ms - 1e-6)
+            .ceil()
+            .max(1.0) as usize;
+        let dt = duration_ms / substeps as f64;
+        let mut spiked = false;
+
+        for _ in 0..substeps {
+            let previous_v = self.state.v;
+            self.state = match self.integrator {
+                HhIntegrator::ExponentialEuler => {
+                    self.state
+                        .exponential_euler_step(&self.params, self.input_current, dt)
+                }
+                HhIntegrator::RungeKutta4 => {
+                    self.state.rk4_step(&self.params, self.input_current, dt)
+                }
+            };
+            self.time_ms += dt;
+
+            if previous_v < constants::SPIKE_DETECTION_MV
+                && self.state.v >= constants::SPIKE_DETECTION_MV
+            {
+                spiked = true;
+            }
+
+            if let Some(trace) = self.trace.as_mut() {
+                if trace.len() >= self.trace_capacity {
+                    trace.pop_front();
+                }
+                if self.trace_capacity > 0 {
+                    trace.push_back(VoltageSample {
+                        time_ms: self.time_ms,
+                        voltage_mv: self.state.v,
+                    });
+                }
+            }
+        }
+
+        spiked
+    }
+}
+
+impl Default for HodgkinHuxleyNeuron {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[typetag::serde]
+impl NeuronModel for HodgkinHuxleyNeuron {
+    fn id(&self) -> &Uuid {
+        &self.id
+    }
+
+    fn model_name(&self) -> &'static str {
+        "hodgkin_huxley"
+    }
+
+    /// Injiziert die Eingabe als Ladung, die die Membran instantan depolarisiert
+    ///
+    /// Ob daraus ein Aktionspotential entsteht, zeigt sich erst bei der Integration.
+    fn receive_input(&mut self, input: f32) -> bool {
+        self.state.v += input as f64 * self.input_gain_mv / self.params.capacitance;
+        false
+    }
+
+    fn step(&mut self, time_step: f32) -> bool {
+        self.spiked = self.advance(time_step as f64 * 1000.0);
+        self.spiked
+    }
+
+    fn is_spiking(&self) -> bool {
+        self.spiked
+    }
+
+    /// Bildet den kontinuierlichen Zustand auf die vereinfachten Zustände ab
+    ///
+    /// Refraktär ist das Neuron, solange die Natriumkanäle überwiegend inaktiviert sind.
+    fn state(&self) -> NeuronState {
+        if self.spiked {
+            NeuronState::Active
+        } else if self.state.h < 0.3 {
+            NeuronState::Refractory
+        } else {
+            NeuronState::Inactive
+        }
+    }
+
+    fn activation_energy(&self) -> f32 {
+        self.state.v as f32
+    }
+
+    fn threshold(&self) -> f32 {
+        constants::SPIKE_DETECTION_MV as f32
+    }
+
+    fn position(&self) -> &Position {
+        &self.position
+    }
+
+    fn set_position(&mut self, position: Position) {
+        self.position = position;
+    }
+
+    fn reset(&mut self) {
+        self.state = HhState::at_rest(constants::RESTING_POTENTIAL_MV);
+        self.time_ms = 0.0;
+        self.spiked = false;
+        self.clear_voltage_trace();
+    }
+
+    fn clone_box(&self) -> Box<dyn NeuronModel> {
+        Box::new(self.clone())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
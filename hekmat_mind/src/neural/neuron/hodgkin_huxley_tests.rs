@@ -0,0 +1,192 @@
+use crate::neural::network::model::NetworkBuilder;
+use crate::neural::neuron::hodgkin_huxley::{
+    HhIntegrator, HodgkinHuxleyNeuron, VoltageSample, constants,
+};
+use crate::neural::neuron::model::NeuronState;
+use crate::neural::neuron::traits::NeuronModel;
+use crate::neural::synapse::model::Synapse;
+
+const INTEGRATORS: [HhIntegrator; 2] = [HhIntegrator::ExponentialEuler, HhIntegrator::RungeKutta4];
+
+/// Simuliert ein Neuron mit konstantem Strom und gibt die Spikezeiten in ms zurück
+fn spike_times(integrator: HhIntegrator, current: f64, duration_ms: usize) -> Vec<usize> {
+    let mut neuron = HodgkinHuxleyNeuron::new().with_integrator(integrator, 0.01);
+    neuron.set_input_current(current);
+
+    (0..duration_ms).filter(|_| neuron.step(0.001)).collect()
+}
+
+/// Zeichnet ein durch einen Spannungssprung ausgelöstes Aktionspotential auf
+fn action_potential(integrator: HhIntegrator) -> Vec<VoltageSample> {
+    let mut neuron = HodgkinHuxleyNeuron::new()
+        .with_integrator(integrator, 0.01)
+        .with_voltage_trace();
+
+    neuron.receive_input(1.0);
+    for _ in 0..20 {
+        neuron.step(0.001);
+    }
+
+    neuron.voltage_trace().iter().copied().collect()
+}
+
+#[test]
+fn test_hodgkin_huxley_resting_state_is_stable() {
+    for integrator in INTEGRATORS {
+        let mut neuron = HodgkinHuxleyNeuron::new().with_integrator(integrator, 0.01);
+
+        for _ in 0..200 {
+            assert!(!neuron.step(0.001));
+        }
+
+        // Ruhepotential nach Hodgkin & Huxley: etwa -65 mV
+        assert!((neuron.voltage() - constants::RESTING_POTENTIAL_MV).abs() < 0.1);
+        let (m, h, n) = neuron.gating();
+        assert!((m - 0.053).abs() < 0.005);
+        assert!((h - 0.596).abs() < 0.005);
+        assert!((n - 0.3177).abs() < 0.005);
+        assert_eq!(neuron.state(), NeuronState::Inactive);
+    }
+}
+
+#[test]
+fn test_action_potential_shape() {
+    for integrator in INTEGRATORS {
+        let trace = action_potential(integrator);
+        let peak = trace
+            .iter()
+            .max_by(|a, b| a.voltage_mv.total_cmp(&b.voltage_mv))
+            .unwrap();
+        let trough = trace
+            .iter()
+            .filter(|s| s.time_ms > peak.time_ms)
+            .min_by(|a, b| a.voltage_mv.total_cmp(&b.voltage_mv))
+            .unwrap();
+
+        // Spitze bei etwa +40 mV, Nachhyperpolarisation in Richtung E_K (etwa -76 mV)
+        assert!(peak.voltage_mv > 35.0 && peak.voltage_mv < 45.0);
+        assert!(trough.voltage_mv < -74.0 && trough.voltage_mv > constants::E_K);
+
+        // Halbwertsbreite des Aktionspotentials: etwa 1 ms
+        let half = (peak.voltage_mv + constants::RESTING_POTENTIAL_MV) / 2.0;
+        let above: Vec<_> = trace.iter().filter(|s| s.voltage_mv >= half).collect();
+        let width = above.last().unwrap().time_ms - above[0].time_ms;
+        assert!(width > 0.5 && width < 1.5, "Halbwertsbreite {} ms", width);
+    }
+}
+
+#[test]
+fn test_firing_threshold_for_depolarisation() {
+    for integrator in INTEGRATORS {
+        let fires = |jump_mv: f64| {
+            let mut neuron = HodgkinHuxleyNeuron::new()
+                .with_integrator(integrator, 0.01)
+                .with_input_gain(jump_mv);
+            neuron.receive_input(1.0);
+            (0..20).any(|_| neuron.step(0.001))
+        };
+
+        // Alles-oder-nichts: Die Schwelle liegt etwa 6-7 mV über dem Ruhepotential
+        assert!(!fires(5.0));
+        assert!(!fires(6.0));
+        assert!(fires(7.0));
+        assert!(fires(20.0));
+    }
+}
+
+#[test]
+fn test_repetitive_firing_threshold_and_frequency() {
+    for integrator in INTEGRATORS {
+        // Unterhalb der Rheobase keine Spikes, knapp darüber nur ein einzelner Spike
+        assert!(spike_times(integrator, 2.0, 500).is_empty());
+        assert_eq!(spike_times(integrator, 5.0, 500).len(), 1);
+
+        // Dauerfeuern setzt zwischen 6,0 und 6,3 µA/cm² ein
+        let below = spike_times(integrator, 6.0, 500);
+        assert!(below.iter().all(|&t| t < 100));
+        let above = spike_times(integrator, 6.3, 500);
+        assert!(above.iter().any(|&t| t > 400));
+
+        // Bei 10 µA/cm² feuert das Neuron mit etwa 68 Hz
+        let times = spike_times(integrator, 10.0, 1000);
+        let rate = (times.len() - 1) as f64 / (times.last().unwrap() - times[0]) as f64 * 1000.0;
+        assert!((rate - 68.0).abs() < 3.0, "Feuerrate {} Hz", rate);
+    }
+}
+
+#[test]
+fn test_integrators_agree() {
+    let euler = spike_times(HhIntegrator::ExponentialEuler, 10.0, 200);
+    let rk4 = spike_times(HhIntegrator::RungeKutta4, 10.0, 200);
+
+    assert_eq!(euler.len(), rk4.len());
+    for (a, b) in euler.iter().zip(&rk4) {
+        assert!(a.abs_diff(*b) <= 1);
+    }
+}
+
+#[test]
+fn test_voltage_trace_and_reset() {
+    let mut neuron = HodgkinHuxleyNeuron::new().with_integrator(HhIntegrator::RungeKutta4, 0.05);
+    assert!(neuron.voltage_trace().is_empty());
+
+    neuron = neuron.with_voltage_trace();
+    neuron.step(0.001);
+    assert_eq!(neuron.voltage_trace().len(), 20);
+    assert!((neuron.time_ms() - 1.0).abs() < 1e-6);
+
+    neuron.receive_input(1.0);
+    neuron.step(0.001);
+    NeuronModel::reset(&mut neuron);
+    assert!(neuron.voltage_trace().is_empty());
+    assert_eq!(neuron.time_ms(), 0.0);
+    assert!((neuron.voltage() - constants::RESTING_POTENTIAL_MV).abs() < 1e-9);
+}
+
+#[test]
+fn test_voltage_trace_is_bounded() {
+    // Mit begrenzter Kapazität bleiben nur die jüngsten Messpunkte erhalten
+    let mut neuron = HodgkinHuxleyNeuron::new()
+        .with_integrator(HhIntegrator::RungeKutta4, 0.05)
+        .with_voltage_trace_capacity(30);
+    for _ in 0..5 {
+        neuron.step(0.001);
+    }
+    let trace = neuron.voltage_trace();
+    assert_eq!(trace.len(), 30);
+    assert!((trace.back().unwrap().time_ms - 5.0).abs() < 1e-6);
+    assert!((trace.front().unwrap().time_ms - 3.55).abs() < 1e-6);
+}
+
+#[test]
+fn test_hodgkin_huxley_in_network() {
+    let mut network = NetworkBuilder::new()
+        .with_neurons(2, 100)
+        .with_hodgkin_huxley_neurons(HhIntegrator::ExponentialEuler, 0.01)
+        .build();
+    let ids: Vec<_> = network.neurons().keys().cloned().collect();
+    network.add_synapse(Synapse::new(ids[0], ids[1], 1.0));
+
+    network.stimulate_neuron(&ids[0], 1.0);
+
+    let mut first_spike = None;
+    let mut second_spike = None;
+    for step in 0..20 {
+        network.cycle(0.001);
+        if first_spike.is_none() && network.get_neuron(&ids[0]).unwrap().is_spiking() {
+            first_spike = Some(step);
+        }
+        if second_spike.is_none() && network.get_neuron(&ids[1]).unwrap().is_spiking() {
+            second_spike = Some(step);
+        }
+    }
+
+    // Das Aktionspotential wird über die Synapse auf das zweite Neuron übertragen
+    assert!(first_spike.unwrap() < second_spike.unwrap());
+
+    let neuron = network
+        .get_neuron_as::<HodgkinHuxleyNeuron>(&ids[1])
+        .unwrap();
+    assert_eq!(neuron.integrator(), HhIntegrator::ExponentialEuler);
+    assert_eq!(neuron.model_name(), "hodgkin_huxley");
+}
@@ -0,0 +1,288 @@
+//! Izhikevich-Neuron
+//!
+//! Das Zwei-Variablen-Modell nach Izhikevich (2003) reproduziert mit nur vier
+//! Parametern die wichtigsten kortikalen Feuermuster, darunter Adaptation und Bursts:
+//!
+//! ```text
+//! v' = 0.04·v² + 5·v + 140 - u + I
+//! u' = a·(b·v - u)
+//! wenn v ≥ 30 mV: v ← c, u ← u + d
+//! ```
+//!
+//! `v` ist das Membranpotential in mV, `u` die Erholungsvariable und `I` der
+//! Eingangsstrom. Die Zeit wird in Millisekunden gerechnet; ein Netzwerk-Zeitschritt
+//! wird intern in Teilschritte von höchstens [`constants::MAX_SUBSTEP_MS`] zerlegt.
+//!
+//! Synaptische Eingaben über [`NeuronModel::receive_input`] heben das
+//! Membranpotential sofort um `input · gain` mV an. Ein synaptisches Ereignis
+//! überträgt damit unabhängig von der Länge des Zeitschritts dieselbe Ladung.
+
+use crate::neural::growth::Position;
+use crate::neural::neuron::model::NeuronState;
+use crate::neural::neuron::traits::NeuronModel;
+use serde::{Deserialize, Serialize};
+use std::any::Any;
+use uuid::Uuid;
+
+/// Konstanten des Izhikevich-Modells
+pub mod constants {
+    /// Spitzenpotential in mV, bei dem ein Spike registriert und zurückgesetzt wird
+    pub const SPIKE_PEAK_MV: f32 = 30.0;
+    /// Maximale Länge eines internen Integrationsschritts in Millisekunden
+    pub const MAX_SUBSTEP_MS: f32 = 0.1;
+    /// Standard-Verstärkung, mit der synaptische Eingaben in einen Spannungssprung (mV)
+    /// umgerechnet werden
+    pub const DEFAULT_INPUT_GAIN: f32 = 20.0;
+}
+
+/// Die vier Modellparameter `a`, `b`, `c` und `d`
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct IzhikevichParams {
+    /// Zeitskala der Erholungsvariable `u`
+    pub a: f32,
+    /// Empfindlichkeit von `u` gegenüber unterschwelligen Schwankungen von `v`
+    pub b: f32,
+    /// Reset-Potential von `v` nach einem Spike in mV
+    pub c: f32,
+    /// Sprung von `u` nach einem Spike
+    pub d: f32,
+}
+
+impl Default for IzhikevichParams {
+    fn default() -> Self {
+        IzhikevichPreset::RegularSpiking.params()
+    }
+}
+
+/// Benannte Parametersätze für die klassischen kortikalen Feuermuster
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum IzhikevichPreset {
+    /// Regulär feuernd (RS): tonische Spikes mit Frequenzadaptation
+    RegularSpiking,
+    /// Schnell feuernd (FS): hohe Frequenz ohne Adaptation (Interneuronen)
+    FastSpiking,
+    /// Chattering (CH): wiederholte, schnelle Bursts
+    Chattering,
+    /// Intrinsisch burstend (IB): initialer Burst, danach tonische Spikes
+    IntrinsicallyBursting,
+    /// Niedrigschwellig feuernd (LTS): Adaptation bei erhöhter Erregbarkeit
+    LowThresholdSpiking,
+}
+
+impl IzhikevichPreset {
+    /// Gibt die Parameter des Presets zurück
+    pub fn params(self) -> IzhikevichParams {
+        let (a, b, c, d) = match self {
+            IzhikevichPreset::RegularSpiking => (0.02, 0.2, -65.0, 8.0),
+            IzhikevichPreset::FastSpiking => (0.1, 0.2, -65.0, 2.0),
+            IzhikevichPreset::Chattering => (0.02, 0.2, -50.0, 2.0),
+            IzhikevichPreset::IntrinsicallyBursting => (0.02, 0.2, -55.0, 4.0),
+            IzhikevichPreset::LowThresholdSpiking => (0.02, 0.25, -65.0, 2.0),
+        };
+        IzhikevichParams { a, b, c, d }
+    }
+
+    /// Alle verfügbaren Presets
+    pub fn all() -> [IzhikevichPreset; 5] {
+        [
+            IzhikevichPreset::RegularSpiking,
+            IzhikevichPreset::FastSpiking,
+            IzhikevichPreset::Chattering,
+            IzhikevichPreset::IntrinsicallyBursting,
+            IzhikevichPreset::LowThresholdSpiking,
+        ]
+    }
+}
+
+impl From<IzhikevichPreset> for IzhikevichParams {
+    fn from(preset: IzhikevichPreset) -> Self {
+        preset.params()
+    }
+}
+
+/// Izhikevich-Neuron mit Membranpotential `v` und Erholungsvariable `u`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IzhikevichNeuron {
+    /// Eindeutige ID des Neurons
+    id: Uuid,
+    /// Modellparameter
+    params: IzhikevichParams,
+    /// Membranpotential in mV
+    v: f32,
+    /// Erholungsvariable
+    u: f32,
+    /// Konstanter Eingangsstrom (z.B. Bias- oder Stimulationsstrom)
+    input_current: f32,
+    /// Verstärkung, mit der Eingaben in einen Spannungssprung (mV) umgerechnet werden
+    input_gain: f32,
+    /// Gibt an, ob das Neuron im letzten Zeitschritt gefeuert hat
+    spiked: bool,
+    /// Position des Neurons im 3D-Raum
+    position: Position,
+}
+
+impl IzhikevichNeuron {
+    /// Erstellt ein Izhikevich-Neuron mit den Parametern eines Presets
+    pub fn new(preset: IzhikevichPreset) -> Self {
+        Self::with_params(preset.params())
+    }
+
+    /// Erstellt ein Izhikevich-Neuron mit benutzerdefinierten Parametern
+    ///
+    /// Das Neuron startet bei `v = c` und `u = b·c`.
+    pub fn with_params(params: IzhikevichParams) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            params,
+            v: params.c,
+            u: params.b * params.c,
+            input_current: 0.0,
+            input_gain: constants::DEFAULT_INPUT_GAIN,
+            spiked: false,
+            position: Position::new(0.0, 0.0, 0.0),
+        }
+    }
+
+    /// Ersetzt die ID des Neurons
+    pub fn with_id(mut self, id: Uuid) -> Self {
+        self.id = id;
+        self
+    }
+
+    /// Setzt die Verstärkung für synaptische Eingaben in mV je Eingabeeinheit
+    pub fn with_input_gain(mut self, gain: f32) -> Self {
+        self.input_gain = gain;
+        self
+    }
+
+    /// Gibt die Modellparameter zurück
+    pub fn params(&self) -> &IzhikevichParams {
+        &self.params
+    }
+
+    /// Gibt das Membranpotential in mV zurück
+    pub fn membrane_potential(&self) -> f32 {
+        self.v
+    }
+
+    /// Gibt die Erholungsvariable `u` zurück
+    pub fn recovery(&self) -> f32 {
+        self.u
+    }
+
+    /// Gibt den konstanten Eingangsstrom zurück
+    pub fn input_current(&self) -> f32 {
+        self.input_current
+    }
+
+    /// Setzt einen konstanten Eingangsstrom, der bei jedem Zeitschritt integriert wird
+    pub fn set_input_current(&mut self, current: f32) {
+        self.input_current = current;
+    }
+
+    /// Gibt die Verstärkung für synaptische Eingaben zurück
+    pub fn input_gain(&self) -> f32 {
+        self.input_gain
+    }
+
+    /// Integriert das Modell über einen Teilschritt mit dem Euler-Verfahren
+    ///
+    /// Gibt `true` zurück, wenn dabei das Spitzenpotential erreicht wurde.
+    fn integrate(&mut self, dt_ms: f32, current: f32) -> bool {
+        let IzhikevichParams { a, b, c, d } = self.params;
+
+        let dv = 0.04 * self.v * self.v + 5.0 * self.v + 140.0 - self.u + current;
+        let du = a * (b * self.v - self.u);
+        self.v += dt_ms * dv;
+        self.u += dt_ms * du;
+
+        if self.v >= constants::SPIKE_PEAK_MV {
+            self.v = c;
+            self.u += d;
+            return true;
+        }
+
+        false
+    }
+}
+
+#[typetag::serde]
+impl NeuronModel for IzhikevichNeuron {
+    fn id(&self) -> &Uuid {
+        &self.id
+    }
+
+    fn model_name(&self) -> &'static str {
+        "izhikevich"
+    }
+
+    /// Injiziert die Eingabe als Ladung, die das Membranpotential sofort anhebt
+    ///
+    /// Ob der Sprung einen Spike auslöst, ergibt sich erst aus der Integration, daher
+    /// wird hier immer `false` zurückgegeben.
+    fn receive_input(&mut self, input: f32) -> bool {
+        self.v += input * self.input_gain;
+        false
+    }
+
+    fn step(&mut self, time_step: f32) -> bool {
+        let dt_ms = time_step * 1000.0;
+        let substeps = (dt_ms / constants::MAX_SUBSTEP_MS).ceil().max(1.0) as usize;
+        let h = dt_ms / substeps as f32;
+
+        self.spiked = false;
+        for _ in 0..substeps {
+            if self.integrate(h, self.input_current) {
+                self.spiked = true;
+            }
+        }
+
+        self.spiked
+    }
+
+    fn is_spiking(&self) -> bool {
+        self.spiked
+    }
+
+    fn state(&self) -> NeuronState {
+        if self.spiked {
+            NeuronState::Active
+        } else {
+            NeuronState::Inactive
+        }
+    }
+
+    fn activation_energy(&self) -> f32 {
+        self.v
+    }
+
+    fn threshold(&self) -> f32 {
+        constants::SPIKE_PEAK_MV
+    }
+
+    fn position(&self) -> &Position {
+        &self.position
+    }
+
+    fn set_position(&mut self, position: Position) {
+        self.position = position;
+    }
+
+    fn reset(&mut self) {
+        self.v = self.params.c;
+        self.u = self.params.b * self.params.c;
+        self.spiked = false;
+    }
+
+    fn clone_box(&self) -> Box<dyn NeuronModel> {
+        Box::new(self.clone())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
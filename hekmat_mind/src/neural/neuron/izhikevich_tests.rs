@@ -0,0 +1,163 @@
+use crate::neural::network::model::NetworkBuilder;
+use crate::neural::neuron::izhikevich::{IzhikevichNeuron, IzhikevichPreset, constants};
+use crate::neural::neuron::traits::NeuronModel;
+
+/// Simuliert ein Neuron mit konstantem Eingangsstrom und gibt die Spikezeiten in ms zurück
+fn spike_times(preset: IzhikevichPreset, current: f32, duration_ms: usize) -> Vec<usize> {
+    let mut neuron = IzhikevichNeuron::new(preset);
+    neuron.set_input_current(current);
+
+    (0..duration_ms).filter(|_| neuron.step(0.001)).collect()
+}
+
+/// Berechnet die Interspike-Intervalle in ms
+fn isis(times: &[usize]) -> Vec<usize> {
+    times.windows(2).map(|w| w[1] - w[0]).collect()
+}
+
+#[test]
+fn test_izhikevich_presets() {
+    let rs = IzhikevichPreset::RegularSpiking.params();
+    assert_eq!((rs.a, rs.b, rs.c, rs.d), (0.02, 0.2, -65.0, 8.0));
+
+    let fs = IzhikevichPreset::FastSpiking.params();
+    assert_eq!(fs.a, 0.1);
+
+    let neuron = IzhikevichNeuron::new(IzhikevichPreset::Chattering);
+    assert_eq!(neuron.membrane_potential(), -50.0);
+    assert_eq!(neuron.recovery(), 0.2 * -50.0);
+    assert_eq!(neuron.model_name(), "izhikevich");
+    assert_eq!(neuron.threshold(), constants::SPIKE_PEAK_MV);
+}
+
+#[test]
+fn test_izhikevich_silent_without_input() {
+    for preset in IzhikevichPreset::all() {
+        let times = spike_times(preset, 0.0, 500);
+        assert!(times.is_empty(), "{:?} feuert ohne Eingabe", preset);
+    }
+}
+
+#[test]
+fn test_regular_spiking_adapts() {
+    let intervals = isis(&spike_times(IzhikevichPreset::RegularSpiking, 10.0, 1000));
+
+    // Tonisches Feuern ohne Bursts, aber mit deutlich zunehmenden Intervallen
+    assert!(intervals.len() >= 5);
+    assert!(intervals.iter().all(|&isi| isi >= 10));
+    assert!(intervals[0] * 3 < 2 * *intervals.last().unwrap());
+}
+
+#[test]
+fn test_fast_spiking_has_high_rate_without_adaptation() {
+    let fs = spike_times(IzhikevichPreset::FastSpiking, 10.0, 1000);
+    let rs = spike_times(IzhikevichPreset::RegularSpiking, 10.0, 1000);
+    assert!(fs.len() > 2 * rs.len());
+
+    // Nach dem ersten Intervall bleibt die Frequenz konstant
+    let intervals = isis(&fs);
+    let steady = &intervals[1..];
+    let min = *steady.iter().min().unwrap();
+    let max = *steady.iter().max().unwrap();
+    assert!(max - min <= 1);
+}
+
+#[test]
+fn test_chattering_produces_repeated_bursts() {
+    let intervals = isis(&spike_times(IzhikevichPreset::Chattering, 10.0, 1000));
+
+    // Kurze Intervalle innerhalb der Bursts, lange Pausen dazwischen
+    let intra_burst = intervals.iter().filter(|&&isi| isi < 10).count();
+    let inter_burst = intervals.iter().filter(|&&isi| isi > 30).count();
+    assert!(inter_burst >= 10);
+    assert!(intra_burst >= 3 * inter_burst);
+}
+
+#[test]
+fn test_intrinsically_bursting_starts_with_burst() {
+    let intervals = isis(&spike_times(
+        IzhikevichPreset::IntrinsicallyBursting,
+        10.0,
+        1000,
+    ));
+
+    // Initialer Burst, danach tonisches Feuern
+    assert!(intervals[0] < 10);
+    assert!(intervals[1..].iter().all(|&isi| isi > 20));
+}
+
+#[test]
+fn test_low_threshold_spiking_adapts() {
+    let lts = spike_times(IzhikevichPreset::LowThresholdSpiking, 10.0, 1000);
+    let rs = spike_times(IzhikevichPreset::RegularSpiking, 10.0, 1000);
+    assert!(lts.len() > rs.len());
+
+    let intervals = isis(&lts);
+    assert!(intervals[0] * 2 < *intervals.last().unwrap());
+}
+
+#[test]
+fn test_izhikevich_input_pulse_and_reset() {
+    let mut neuron = IzhikevichNeuron::new(IzhikevichPreset::RegularSpiking);
+
+    // Ein einzelner Eingangspuls reicht, um das Neuron zum Feuern zu bringen
+    assert!(!neuron.receive_input(1.0));
+    let fired = (0..10).any(|_| neuron.step(0.001));
+    assert!(fired);
+    assert!(neuron.recovery() > neuron.params().b * neuron.params().c);
+
+    NeuronModel::reset(&mut neuron);
+    assert_eq!(neuron.membrane_potential(), neuron.params().c);
+    assert!(!neuron.is_spiking());
+}
+
+#[test]
+fn test_izhikevich_input_is_independent_of_time_step() {
+    // Derselbe Puls hebt das Potential unabhängig vom Zeitschritt um input · gain an
+    let trajectory = |time_step: f32| {
+        let mut neuron = IzhikevichNeuron::new(IzhikevichPreset::RegularSpiking);
+        let rest = neuron.membrane_potential();
+        neuron.receive_input(0.25);
+        assert_eq!(
+            neuron.membrane_potential(),
+            rest + 0.25 * neuron.input_gain()
+        );
+        for _ in 0..(0.004 / time_step).round() as usize {
+            neuron.step(time_step);
+        }
+        neuron.membrane_potential()
+    };
+
+    let reference = trajectory(0.001);
+    for time_step in [0.0005, 0.002] {
+        assert!((trajectory(time_step) - reference).abs() < 1e-3);
+    }
+}
+
+#[test]
+fn test_network_builder_with_izhikevich_preset() {
+    let mut network = NetworkBuilder::new()
+        .with_neurons(3, 100)
+        .with_izhikevich_neurons(IzhikevichPreset::FastSpiking)
+        .build();
+
+    let ids: Vec<_> = network.neurons().keys().cloned().collect();
+    for id in &ids {
+        let neuron = network.get_neuron_as::<IzhikevichNeuron>(id).unwrap();
+        assert_eq!(*neuron.params(), IzhikevichPreset::FastSpiking.params());
+    }
+
+    network.stimulate_neuron(&ids[0], 1.0);
+    let fired = (0..10).any(|_| {
+        network.cycle(0.001);
+        network.get_neuron(&ids[0]).unwrap().is_spiking()
+    });
+    assert!(fired);
+
+    // Serialisierung über das NeuronModel-Trait erhält Preset und Zustand
+    let boxed = network.get_neuron(&ids[0]).unwrap().clone_box();
+    let json = serde_json::to_string(&boxed).unwrap();
+    let restored: Box<dyn NeuronModel> = serde_json::from_str(&json).unwrap();
+    assert_eq!(restored.model_name(), "izhikevich");
+    assert_eq!(restored.activation_energy(), boxed.activation_energy());
+}
@@ -0,0 +1,367 @@
+//! Leaky-Integrate-and-Fire-Neuron (LIF)
+//!
+//! Das LIF-Modell ergänzt das Standard-[`Neuron`](crate::neural::neuron::Neuron) um
+//! einen Leckstrom: Ohne Eingaben relaxiert das Membranpotential exponentiell mit der
+//! Membranzeitkonstante `τ_m` zum Ruhepotential zurück. Überschreitet das Potential den
+//! Schwellwert, feuert das Neuron, wird auf das Reset-Potential gesetzt und ist für die
+//! absolute Refraktärzeit unempfindlich gegenüber Eingaben.
+//!
+//! Die Dynamik wird mit der exakten Lösung für konstanten Eingangsstrom integriert:
+//!
+//! `V(t + Δt) = V_∞ + (V(t) - V_∞) · exp(-Δt / τ_m)` mit `V_∞ = V_rest + R·I`
+//!
+//! Synaptische Eingaben über [`NeuronModel::receive_input`] wirken als
+//! Spannungssprünge (Delta-Synapsen).
+//!
+//! Da die Lösung zwischen zwei Eingaben geschlossen vorliegt, unterstützt das Modell
+//! auch die ereignisgesteuerte Simulation ([`AnalyticNeuron`]): Bei konstantem
+//! Eingangsstrom `V_∞ > θ` wird der Schwellwert nach
+//! `t = τ_m · ln((V - V_∞) / (θ - V_∞))` erreicht.
+
+use crate::neural::growth::Position;
+use crate::neural::neuron::model::NeuronState;
+use crate::neural::neuron::model::constants as neuron_constants;
+use crate::neural::neuron::traits::{AnalyticNeuron, NeuronModel};
+use serde::{Deserialize, Serialize};
+use std::any::Any;
+use uuid::Uuid;
+
+/// Standardparameter des LIF-Modells
+pub mod constants {
+    /// Standard-Membranzeitkonstante in Millisekunden
+    pub const DEFAULT_MEMBRANE_TIME_CONSTANT_MS: f32 = 20.0;
+    /// Standard-Ruhepotential (in denselben Einheiten wie der Schwellwert)
+    pub const DEFAULT_RESTING_POTENTIAL: f32 = 0.0;
+    /// Standard-Reset-Potential nach einem Spike
+    pub const DEFAULT_RESET_POTENTIAL: f32 = 0.0;
+    /// Standard-Refraktärzeit in Millisekunden
+    pub const DEFAULT_REFRACTORY_PERIOD_MS: f32 = 2.0;
+    /// Standard-Membranwiderstand (skaliert den Eingangsstrom)
+    pub const DEFAULT_MEMBRANE_RESISTANCE: f32 = 1.0;
+}
+
+/// Toleranz in Millisekunden, innerhalb der ein Zeitpunkt noch zur Refraktärzeit zählt
+const REFRACTORY_TOLERANCE_MS: f32 = 1e-4;
+
+/// Parameter eines Leaky-Integrate-and-Fire-Neurons
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct LifParams {
+    /// Membranzeitkonstante τ_m in Millisekunden
+    pub membrane_time_constant_ms: f32,
+    /// Ruhepotential, zu dem die Membran ohne Eingabe zurückkehrt
+    pub resting_potential: f32,
+    /// Potential, auf das die Membran nach einem Spike gesetzt wird
+    pub reset_potential: f32,
+    /// Absolute Refraktärzeit in Millisekunden
+    pub refractory_period_ms: f32,
+    /// Membranwiderstand R, mit dem der Eingangsstrom skaliert wird
+    pub membrane_resistance: f32,
+}
+
+impl Default for LifParams {
+    fn default() -> Self {
+        Self {
+            membrane_time_constant_ms: constants::DEFAULT_MEMBRANE_TIME_CONSTANT_MS,
+            resting_potential: constants::DEFAULT_RESTING_POTENTIAL,
+            reset_potential: constants::DEFAULT_RESET_POTENTIAL,
+            refractory_period_ms: constants::DEFAULT_REFRACTORY_PERIOD_MS,
+            membrane_resistance: constants::DEFAULT_MEMBRANE_RESISTANCE,
+        }
+    }
+}
+
+/// Leaky-Integrate-and-Fire-Neuron mit homöostatischem Schwellwert
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LifNeuron {
+    /// Eindeutige ID des Neurons
+    id: Uuid,
+    /// Modellparameter
+    params: LifParams,
+    /// Aktuelles Membranpotential
+    membrane_potential: f32,
+    /// Konstanter Eingangsstrom (z.B. Bias- oder Stimulationsstrom)
+    input_current: f32,
+    /// Schwellwert für die Auslösung eines Spikes
+    threshold: f32,
+    /// Plastizitätsrate für die homöostatische Schwellwertanpassung
+    plasticity_rate: f32,
+    /// Verbleibende Refraktärzeit in Millisekunden
+    refractory_remaining_ms: f32,
+    /// Gibt an, ob das Neuron im letzten Zeitschritt gefeuert hat
+    spiked: bool,
+    /// Position des Neurons im 3D-Raum
+    position: Position,
+}
+
+impl LifNeuron {
+    /// Erstellt ein LIF-Neuron mit Standardparametern
+    pub fn new() -> Self {
+        Self::with_params(
+            LifParams::default(),
+            neuron_constants::DEFAULT_THRESHOLD,
+            neuron_constants::DEFAULT_PLASTICITY_RATE,
+        )
+    }
+
+    /// Erstellt ein LIF-Neuron mit benutzerdefinierten Parametern
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - Membran- und Refraktärparameter
+    /// * `threshold` - Schwellwert des Membranpotentials
+    /// * `plasticity_rate` - Rate der homöostatischen Schwellwertanpassung
+    pub fn with_params(params: LifParams, threshold: f32, plasticity_rate: f32) -> Self {
+        let params = LifParams {
+            membrane_time_constant_ms: params.membrane_time_constant_ms.max(f32::EPSILON),
+            refractory_period_ms: params.refractory_period_ms.max(0.0),
+            ..params
+        };
+
+        Self {
+            id: Uuid::new_v4(),
+            params,
+            membrane_potential: params.resting_potential,
+            input_current: 0.0,
+            threshold,
+            plasticity_rate,
+            refractory_remaining_ms: 0.0,
+            spiked: false,
+            position: Position::new(0.0, 0.0, 0.0),
+        }
+    }
+
+    /// Ersetzt die ID des Neurons
+    pub fn with_id(mut self, id: Uuid) -> Self {
+        self.id = id;
+        self
+    }
+
+    /// Gibt die Modellparameter zurück
+    pub fn params(&self) -> &LifParams {
+        &self.params
+    }
+
+    /// Gibt das aktuelle Membranpotential zurück
+    pub fn membrane_potential(&self) -> f32 {
+        self.membrane_potential
+    }
+
+    /// Gibt den konstanten Eingangsstrom zurück
+    pub fn input_current(&self) -> f32 {
+        self.input_current
+    }
+
+    /// Setzt einen konstanten Eingangsstrom, der bei jedem Zeitschritt integriert wird
+    pub fn set_input_current(&mut self, current: f32) {
+        self.input_current = current;
+    }
+
+    /// Gibt die Plastizitätsrate des Neurons zurück
+    pub fn plasticity_rate(&self) -> f32 {
+        self.plasticity_rate
+    }
+
+    /// Gibt die verbleibende Refraktärzeit in Millisekunden zurück
+    pub fn refractory_remaining_ms(&self) -> f32 {
+        self.refractory_remaining_ms
+    }
+
+    /// Passt den Schwellwert basierend auf der Aktivität an (wie [`Neuron::adapt_threshold`](crate::neural::neuron::Neuron::adapt_threshold))
+    ///
+    /// # Arguments
+    ///
+    /// * `was_active` - Ob das Neuron im letzten Zeitraum aktiv war
+    /// * `target_activity` - Die gewünschte Aktivitätsrate (0.0-1.0)
+    pub fn adapt_threshold(&mut self, was_active: bool, target_activity: f32) {
+        let activity_error = if was_active { 1.0 } else { 0.0 } - target_activity;
+        self.threshold += self.plasticity_rate * activity_error;
+
+        // Der Schwellwert darf nicht unter das Ruhepotential fallen, sonst feuert das Neuron dauerhaft
+        if self.threshold < self.params.resting_potential {
+            self.threshold = self.params.resting_potential;
+        }
+    }
+
+    /// Löst einen Spike aus und startet die Refraktärzeit
+    fn fire(&mut self) {
+        self.spiked = true;
+        self.membrane_potential = self.params.reset_potential;
+        self.refractory_remaining_ms = self.params.refractory_period_ms;
+    }
+
+    /// Potential, dem die Membran bei konstantem Eingangsstrom zustrebt
+    fn steady_state(&self) -> f32 {
+        self.params.resting_potential + self.params.membrane_resistance * self.input_current
+    }
+
+    /// Lässt die Membran ohne Eingaben `dt_ms` Millisekunden lang relaxieren
+    fn relax(&mut self, dt_ms: f32) {
+        // Exakte Integration des Leckterms bei konstantem Eingangsstrom
+        let steady_state = self.steady_state();
+        let decay = (-dt_ms / self.params.membrane_time_constant_ms).exp();
+        self.membrane_potential = steady_state + (self.membrane_potential - steady_state) * decay;
+    }
+}
+
+impl Default for LifNeuron {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[typetag::serde]
+impl NeuronModel for LifNeuron {
+    fn id(&self) -> &Uuid {
+        &self.id
+    }
+
+    fn model_name(&self) -> &'static str {
+        "lif"
+    }
+
+    /// Addiert die Eingabe als Spannungssprung auf das Membranpotential
+    ///
+    /// Während der Refraktärzeit werden Eingaben ignoriert. Der eigentliche Spike
+    /// wird im nächsten Aufruf von [`NeuronModel::step`] ausgelöst.
+    fn receive_input(&mut self, input: f32) -> bool {
+        if self.refractory_remaining_ms > 0.0 {
+            return false;
+        }
+
+        self.membrane_potential += input;
+        self.membrane_potential >= self.threshold
+    }
+
+    fn step(&mut self, time_step: f32) -> bool {
+        let dt_ms = time_step * 1000.0;
+        self.spiked = false;
+
+        // Absolute Refraktärzeit: Membran bleibt auf dem Reset-Potential
+        if self.refractory_remaining_ms > 0.0 {
+            self.refractory_remaining_ms = (self.refractory_remaining_ms - dt_ms).max(0.0);
+            self.membrane_potential = self.params.reset_potential;
+            return false;
+        }
+
+        // Eingaben seit dem letzten Schritt können den Schwellwert bereits überschritten haben
+        if self.membrane_potential >= self.threshold {
+            self.fire();
+            return true;
+        }
+
+        self.relax(dt_ms);
+
+        if self.membrane_potential >= self.threshold {
+            self.fire();
+        }
+
+        self.spiked
+    }
+
+    fn is_spiking(&self) -> bool {
+        self.spiked
+    }
+
+    fn state(&self) -> NeuronState {
+        if self.spiked {
+            NeuronState::Active
+        } else if self.refractory_remaining_ms > 0.0 {
+            NeuronState::Refractory
+        } else {
+            NeuronState::Inactive
+        }
+    }
+
+    fn activation_energy(&self) -> f32 {
+        self.membrane_potential
+    }
+
+    fn threshold(&self) -> f32 {
+        self.threshold
+    }
+
+    fn position(&self) -> &Position {
+        &self.position
+    }
+
+    fn set_position(&mut self, position: Position) {
+        self.position = position;
+    }
+
+    fn reset(&mut self) {
+        self.membrane_potential = self.params.resting_potential;
+        self.refractory_remaining_ms = 0.0;
+        self.spiked = false;
+    }
+
+    fn clone_box(&self) -> Box<dyn NeuronModel> {
+        Box::new(self.clone())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn as_analytic(&self) -> Option<&dyn AnalyticNeuron> {
+        Some(self)
+    }
+
+    fn as_analytic_mut(&mut self) -> Option<&mut dyn AnalyticNeuron> {
+        Some(self)
+    }
+}
+
+impl AnalyticNeuron for LifNeuron {
+    fn advance(&mut self, duration: f64) {
+        let mut remaining_ms = (duration * 1000.0) as f32;
+        self.spiked = false;
+
+        // Während der Refraktärzeit bleibt die Membran auf dem Reset-Potential
+        if self.refractory_remaining_ms > 0.0 {
+            self.membrane_potential = self.params.reset_potential;
+            if remaining_ms < self.refractory_remaining_ms + REFRACTORY_TOLERANCE_MS {
+                // Wie im taktgesteuerten Schritt ist das Neuron auch genau am Ende
+                // der Refraktärzeit noch unempfindlich für Eingaben
+                self.refractory_remaining_ms =
+                    (self.refractory_remaining_ms - remaining_ms).max(f32::EPSILON);
+                return;
+            }
+            remaining_ms -= self.refractory_remaining_ms;
+            self.refractory_remaining_ms = 0.0;
+        }
+
+        if remaining_ms > 0.0 {
+            self.relax(remaining_ms);
+        }
+    }
+
+    fn time_to_spike(&self) -> Option<f64> {
+        let refractory_ms = self.refractory_remaining_ms.max(0.0) as f64;
+        let start = if refractory_ms > 0.0 {
+            self.params.reset_potential
+        } else {
+            self.membrane_potential
+        } as f64;
+        let threshold = self.threshold as f64;
+        if start >= threshold {
+            return Some(refractory_ms / 1000.0);
+        }
+
+        // Ohne überschwelligen Eingangsstrom wird der Schwellwert nie erreicht
+        let steady_state = self.steady_state() as f64;
+        if steady_state <= threshold {
+            return None;
+        }
+        let tau_ms = self.params.membrane_time_constant_ms as f64;
+        let crossing_ms = tau_ms * ((start - steady_state) / (threshold - steady_state)).ln();
+        Some((refractory_ms + crossing_ms) / 1000.0)
+    }
+
+    fn fire(&mut self) {
+        LifNeuron::fire(self);
+    }
+}
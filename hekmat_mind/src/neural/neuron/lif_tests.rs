@@ -0,0 +1,225 @@
+use crate::neural::network::model::{Network, NetworkBuilder};
+use crate::neural::neuron::lif::{LifNeuron, LifParams, constants};
+use crate::neural::neuron::model::NeuronState;
+use crate::neural::neuron::traits::{AnalyticNeuron, NeuronModel};
+use crate::neural::synapse::model::Synapse;
+
+/// Zeitschritt von 0,1 ms für präzise Integration
+const DT: f32 = 0.0001;
+
+#[test]
+fn test_lif_default_parameters() {
+    let neuron = LifNeuron::new();
+    let params = neuron.params();
+
+    assert_eq!(
+        params.membrane_time_constant_ms,
+        constants::DEFAULT_MEMBRANE_TIME_CONSTANT_MS
+    );
+    assert_eq!(
+        params.refractory_period_ms,
+        constants::DEFAULT_REFRACTORY_PERIOD_MS
+    );
+    assert_eq!(neuron.membrane_potential(), params.resting_potential);
+    assert_eq!(neuron.state(), NeuronState::Inactive);
+    assert_eq!(neuron.model_name(), "lif");
+}
+
+#[test]
+fn test_lif_membrane_leak_follows_time_constant() {
+    let mut neuron = LifNeuron::new();
+    let tau_ms = neuron.params().membrane_time_constant_ms;
+
+    // Unterschwellige Eingabe einmalig anlegen
+    assert!(!neuron.receive_input(0.4));
+
+    // Eine Membranzeitkonstante lang integrieren
+    let steps = (tau_ms / (DT * 1000.0)).round() as usize;
+    for _ in 0..steps {
+        assert!(!neuron.step(DT));
+    }
+
+    // Nach τ_m ist das Potential auf 1/e des Ausgangswerts abgefallen
+    let expected = 0.4 * (-1.0f32).exp();
+    assert!((neuron.membrane_potential() - expected).abs() < 1e-3);
+
+    // Nach sehr langer Zeit ist das Potential wieder auf Ruheniveau
+    for _ in 0..steps * 10 {
+        neuron.step(DT);
+    }
+    assert!(neuron.membrane_potential().abs() < 1e-4);
+}
+
+#[test]
+fn test_lif_spike_reset_and_refractory_period() {
+    let params = LifParams {
+        reset_potential: -0.2,
+        refractory_period_ms: 2.0,
+        ..LifParams::default()
+    };
+    let mut neuron = LifNeuron::with_params(params, 0.5, 0.01);
+
+    // Überschwellige Eingabe löst im nächsten Schritt einen Spike aus
+    assert!(neuron.receive_input(0.8));
+    assert!(neuron.step(DT));
+    assert_eq!(neuron.state(), NeuronState::Active);
+    assert_eq!(neuron.membrane_potential(), -0.2);
+
+    // Während der Refraktärzeit werden Eingaben ignoriert
+    assert!(!neuron.receive_input(5.0));
+    let mut refractory_steps = 0;
+    while neuron.refractory_remaining_ms() > 0.0 {
+        assert!(!neuron.step(DT));
+        refractory_steps += 1;
+    }
+    assert_eq!(refractory_steps, 20); // 2 ms bei 0,1 ms Auflösung
+    assert_eq!(neuron.state(), NeuronState::Inactive);
+
+    // Danach integriert das Neuron wieder
+    assert!(neuron.receive_input(1.0));
+    assert!(neuron.step(DT));
+}
+
+#[test]
+fn test_lif_constant_current_firing_period() {
+    let params = LifParams::default();
+    let mut neuron = LifNeuron::with_params(params, 0.5, 0.0);
+    neuron.set_input_current(1.0);
+
+    let mut spike_times = Vec::new();
+    for step in 0..2000 {
+        if neuron.step(DT) {
+            spike_times.push(step as f32 * DT * 1000.0);
+        }
+    }
+
+    // Analytische Periode: t_ref + τ_m · ln((R·I - V_reset) / (R·I - θ))
+    let expected_period =
+        params.refractory_period_ms + params.membrane_time_constant_ms * 2.0f32.ln();
+    assert!(spike_times.len() >= 3);
+    for pair in spike_times.windows(2).skip(1) {
+        let period = pair[1] - pair[0];
+        assert!(
+            (period - expected_period).abs() <= 0.15,
+            "Periode {} ms weicht von {} ms ab",
+            period,
+            expected_period
+        );
+    }
+}
+
+#[test]
+fn test_lif_analytic_advance_matches_stepping() {
+    let mut stepped = LifNeuron::new();
+    stepped.set_input_current(0.4);
+    stepped.receive_input(0.2);
+    let mut advanced = stepped.clone();
+
+    for _ in 0..100 {
+        assert!(!stepped.step(DT));
+    }
+    advanced.advance(100.0 * DT as f64);
+    assert!((stepped.membrane_potential() - advanced.membrane_potential()).abs() < 1e-5);
+
+    // Unterschwelliger Eingangsstrom: ohne weitere Eingaben kein Spike
+    assert_eq!(advanced.time_to_spike(), None);
+
+    // Nach einem Spike bleibt die Membran für die Refraktärzeit auf dem Reset-Potential
+    advanced.set_input_current(1.0);
+    AnalyticNeuron::fire(&mut advanced);
+    let refractory = advanced.params().refractory_period_ms as f64 / 1000.0;
+    let expected = refractory + 0.02 * 2.0_f64.ln();
+    assert!((advanced.time_to_spike().unwrap() - expected).abs() < 1e-6);
+    advanced.advance(refractory);
+    assert!(
+        !advanced.receive_input(1.0),
+        "Ende der Refraktärzeit zählt noch dazu"
+    );
+    advanced.advance(DT as f64);
+    assert!(advanced.receive_input(1.0));
+}
+
+#[test]
+fn test_lif_threshold_homeostasis() {
+    let mut neuron = LifNeuron::with_params(LifParams::default(), 0.5, 0.1);
+
+    neuron.adapt_threshold(true, 0.2);
+    assert!(neuron.threshold() > 0.5);
+
+    let raised = neuron.threshold();
+    neuron.adapt_threshold(false, 0.2);
+    assert!(neuron.threshold() < raised);
+
+    // Der Schwellwert fällt nie unter das Ruhepotential
+    for _ in 0..50 {
+        neuron.adapt_threshold(false, 1.0);
+    }
+    assert_eq!(neuron.threshold(), neuron.params().resting_potential);
+}
+
+#[test]
+fn test_lif_reset() {
+    let mut neuron = LifNeuron::new();
+    neuron.receive_input(2.0);
+    neuron.step(DT);
+    assert_eq!(neuron.state(), NeuronState::Active);
+
+    NeuronModel::reset(&mut neuron);
+    assert_eq!(neuron.state(), NeuronState::Inactive);
+    assert_eq!(
+        neuron.membrane_potential(),
+        neuron.params().resting_potential
+    );
+    assert_eq!(neuron.refractory_remaining_ms(), 0.0);
+}
+
+#[test]
+fn test_lif_single_stimulus_does_not_integrate_forever_in_network() {
+    let mut network = Network::new();
+    let neuron = LifNeuron::new();
+    let id = *NeuronModel::id(&neuron);
+    network.add_neuron(neuron);
+
+    network.stimulate_neuron(&id, 0.3);
+    for _ in 0..200 {
+        network.cycle(0.001);
+    }
+
+    // Zwei weitere unterschwellige Reize lange danach summieren sich nicht zu einem Spike
+    network.stimulate_neuron(&id, 0.3);
+    network.cycle(0.001);
+    assert!(!network.get_neuron(&id).unwrap().is_spiking());
+    assert!(network.get_neuron(&id).unwrap().activation_energy() < 0.5);
+}
+
+#[test]
+fn test_lif_signal_propagation_in_network() {
+    let network = NetworkBuilder::new()
+        .with_neurons(2, 100)
+        .with_lif_neurons(LifParams::default())
+        .build();
+    let ids: Vec<_> = network.neurons().keys().cloned().collect();
+    let mut network = network;
+    network.add_synapse(Synapse::new(ids[0], ids[1], 1.0));
+
+    for neuron in network.neurons().values() {
+        assert_eq!(neuron.model_name(), "lif");
+    }
+
+    network.stimulate_neuron(&ids[0], 1.0);
+
+    // Zyklus 1: Das erste Neuron feuert und überträgt das Signal
+    network.cycle(0.001);
+    assert!(network.get_neuron(&ids[0]).unwrap().is_spiking());
+
+    // Zyklus 2: Das zweite Neuron feuert, das erste ist refraktär
+    network.cycle(0.001);
+    assert!(network.get_neuron(&ids[1]).unwrap().is_spiking());
+    assert_eq!(
+        network.get_neuron(&ids[0]).unwrap().state(),
+        NeuronState::Refractory
+    );
+
+    let lif = network.get_neuron_as::<LifNeuron>(&ids[1]).unwrap();
+    assert_eq!(lif.membrane_potential(), lif.params().reset_potential);
+}
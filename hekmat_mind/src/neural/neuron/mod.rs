@@ -16,9 +16,11 @@
 //!
 //! 1. **Inaktiver Zustand**: Das Neuron sammelt Energie durch Eingabesignale
 //! 2. **Aktivierung**: Wenn die Energie den Schwellwert überschreitet, wird das Neuron aktiviert
-//! 3. **Signalaussendung**: Während des Aktivierungszyklus sendet das Neuron ein Signal
-//! 4. **Refraktärphase**: Nach der Aktivierung tritt das Neuron in eine Erholungsphase ein
-//! 5. **Rückkehr**: Nach einem weiteren Zyklus kehrt das Neuron zum inaktiven Zustand zurück
+//! 3. **Signalaussendung**: Während der aktiven Dauer (Standard: 2 ms) sendet das Neuron in jedem Zeitschritt ein Signal
+//! 4. **Refraktärphase**: Nach der Aktivierung tritt das Neuron in eine Erholungsphase ein (Standard: 5 ms)
+//! 5. **Rückkehr**: Nach Ablauf der Refraktärphase kehrt das Neuron zum inaktiven Zustand zurück
+//!
+//! Beide Dauern werden in Sekunden angegeben und lassen sich mit [`Neuron::with_timing`] anpassen.
 //!
 //! ## Plastizität
 //!
@@ -29,6 +31,12 @@
 //!
 //! Diese homöostatische Plastizität sorgt für ein ausgewogenes Aktivitätsniveau.
 //!
+//! ## Weitere Modelle
+//!
+//! - [`LifNeuron`]: Leaky-Integrate-and-Fire mit Membranzeitkonstante und Refraktärzeit
+//! - [`IzhikevichNeuron`]: Zwei-Variablen-Modell mit Presets für Adaptation und Bursts
+//! - [`HodgkinHuxleyNeuron`]: Leitfähigkeitsbasiertes Modell mit Na-, K- und Leckkanal
+//!
 //! ## Beispiel
 //!
 //! ```rust
@@ -49,10 +57,25 @@
 //! neuron.adapt_threshold(true, 0.2); // Zu aktiv, Schwellwert erhöhen
 //! ```
 
+pub mod hodgkin_huxley;
+pub mod izhikevich;
+pub mod lif;
 pub mod model;
 pub mod tests;
+pub mod traits;
+
+#[cfg(test)]
+mod hodgkin_huxley_tests;
+#[cfg(test)]
+mod izhikevich_tests;
+#[cfg(test)]
+mod lif_tests;
 
 // Re-exportiere die Kernkomponenten
+pub use hodgkin_huxley::{HhIntegrator, HhParams, HodgkinHuxleyNeuron, VoltageSample};
+pub use izhikevich::{IzhikevichNeuron, IzhikevichParams, IzhikevichPreset};
+pub use lif::{LifNeuron, LifParams};
 pub use model::Neuron;
 pub use model::NeuronState;
 pub use model::constants;
+pub use traits::{AnalyticNeuron, NeuronFactory, NeuronModel};
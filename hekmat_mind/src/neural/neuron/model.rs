@@ -1,5 +1,7 @@
 use crate::neural::growth::{AxonGrowth, GrowthFactor, Position};
+use crate::neural::neuron::traits::NeuronModel;
 use serde::{Deserialize, Serialize};
+use std::any::Any;
 use std::fmt;
 use uuid::Uuid;
 
@@ -15,12 +17,19 @@ pub mod constants {
     pub const CAPACITY_FACTOR: f32 = 1.5;
     /// Standardwert für die Plastizitätsrate
     pub const DEFAULT_PLASTICITY_RATE: f32 = 0.01;
+    /// Standarddauer des aktiven Zustands in Sekunden
+    pub const DEFAULT_ACTIVE_DURATION: f32 = 0.002; // 2ms
+    /// Standarddauer der Refraktärphase in Sekunden
+    pub const DEFAULT_REFRACTORY_PERIOD: f32 = 0.005; // 5ms
+    /// Toleranz beim Vergleich von Phasendauern, um Rundungsfehler abzufangen
+    pub const TIME_TOLERANCE: f32 = 1e-6;
 }
 
 /// Zustand eines Neurons (inaktiv, aktiviert, refraktär)
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
 pub enum NeuronState {
     /// Inaktiv: Neuron kann Eingaben empfangen und aktiviert werden
+    #[default]
     Inactive,
     /// Aktiviert: Neuron hat den Schwellwert überschritten und sendet Signale
     Active,
@@ -28,12 +37,6 @@ pub enum NeuronState {
     Refractory,
 }
 
-impl Default for NeuronState {
-    fn default() -> Self {
-        Self::Inactive
-    }
-}
-
 impl fmt::Display for NeuronState {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -61,6 +64,23 @@ pub struct Neuron {
     plasticity_rate: f32,
     /// Position des Neurons im 3D-Raum
     position: Position,
+    /// Dauer des aktiven Zustands in Sekunden
+    #[serde(default = "default_active_duration")]
+    active_duration: f32,
+    /// Dauer der Refraktärphase in Sekunden
+    #[serde(default = "default_refractory_period")]
+    refractory_period: f32,
+    /// Im aktuellen Zustand (aktiv oder refraktär) verbrachte Zeit in Sekunden
+    #[serde(default)]
+    phase_time: f32,
+}
+
+fn default_active_duration() -> f32 {
+    constants::DEFAULT_ACTIVE_DURATION
+}
+
+fn default_refractory_period() -> f32 {
+    constants::DEFAULT_REFRACTORY_PERIOD
 }
 
 impl Neuron {
@@ -84,6 +104,9 @@ impl Neuron {
             state: NeuronState::default(),
             plasticity_rate: constants::DEFAULT_PLASTICITY_RATE,
             position: Position::new(0.0, 0.0, 0.0), // Standardposition im Ursprung
+            active_duration: constants::DEFAULT_ACTIVE_DURATION,
+            refractory_period: constants::DEFAULT_REFRACTORY_PERIOD,
+            phase_time: 0.0,
         }
     }
 
@@ -109,9 +132,42 @@ impl Neuron {
             state: NeuronState::default(),
             plasticity_rate,
             position: Position::new(0.0, 0.0, 0.0), // Standardposition im Ursprung
+            active_duration: constants::DEFAULT_ACTIVE_DURATION,
+            refractory_period: constants::DEFAULT_REFRACTORY_PERIOD,
+            phase_time: 0.0,
         }
     }
 
+    /// Ersetzt die ID des Neurons
+    ///
+    /// Wird vom `NetworkBuilder` verwendet, um IDs zentral zu vergeben.
+    pub fn with_id(mut self, id: Uuid) -> Self {
+        self.id = id;
+        self
+    }
+
+    /// Legt die Dauer des aktiven Zustands und der Refraktärphase fest
+    ///
+    /// # Arguments
+    ///
+    /// * `active_duration` - Dauer des aktiven Zustands in Sekunden
+    /// * `refractory_period` - Dauer der Refraktärphase in Sekunden
+    pub fn with_timing(mut self, active_duration: f32, refractory_period: f32) -> Self {
+        self.active_duration = active_duration.max(0.0);
+        self.refractory_period = refractory_period.max(0.0);
+        self
+    }
+
+    /// Gibt die Dauer des aktiven Zustands in Sekunden zurück
+    pub fn active_duration(&self) -> f32 {
+        self.active_duration
+    }
+
+    /// Gibt die Dauer der Refraktärphase in Sekunden zurück
+    pub fn refractory_period(&self) -> f32 {
+        self.refractory_period
+    }
+
     /// Berechnet die Informationskapazität des Neurons basierend auf seiner Geschwindigkeit
     ///
     /// # Returns
@@ -277,6 +333,7 @@ impl Neuron {
     pub fn reset(&mut self) {
         self.activation_energy = 0.0;
         self.state = NeuronState::Inactive;
+        self.phase_time = 0.0;
     }
 
     /// Startet das Axonwachstum für dieses Neuron
@@ -316,3 +373,76 @@ impl Neuron {
         GrowthFactor::new(self.position, strength, radius, factor_type)
     }
 }
+
+#[typetag::serde]
+impl NeuronModel for Neuron {
+    fn id(&self) -> &Uuid {
+        Neuron::id(self)
+    }
+
+    fn model_name(&self) -> &'static str {
+        "neuron"
+    }
+
+    fn receive_input(&mut self, input: f32) -> bool {
+        Neuron::receive_input(self, input)
+    }
+
+    /// Misst die im aktuellen Zustand verbrachte Zeit und führt nach der aktiven
+    /// Dauer bzw. der Refraktärphase den nächsten Zustandsübergang über
+    /// [`Neuron::cycle`] aus.
+    ///
+    /// Ein aktives Neuron sendet in jedem Schritt, in dem es aktiv ist, einen Spike.
+    fn step(&mut self, time_step: f32) -> bool {
+        let duration = match self.state {
+            NeuronState::Inactive => return false,
+            NeuronState::Active => self.active_duration,
+            NeuronState::Refractory => self.refractory_period,
+        };
+        let spiking = self.state == NeuronState::Active;
+
+        self.phase_time += time_step;
+        if self.phase_time + constants::TIME_TOLERANCE >= duration {
+            self.cycle(); // Übergang Active -> Refractory bzw. Refractory -> Inactive
+            self.phase_time = 0.0;
+        }
+
+        spiking
+    }
+
+    fn state(&self) -> NeuronState {
+        self.state
+    }
+
+    fn activation_energy(&self) -> f32 {
+        self.activation_energy
+    }
+
+    fn threshold(&self) -> f32 {
+        self.threshold
+    }
+
+    fn position(&self) -> &Position {
+        &self.position
+    }
+
+    fn set_position(&mut self, position: Position) {
+        self.position = position;
+    }
+
+    fn reset(&mut self) {
+        Neuron::reset(self);
+    }
+
+    fn clone_box(&self) -> Box<dyn NeuronModel> {
+        Box::new(self.clone())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
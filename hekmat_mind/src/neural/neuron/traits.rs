@@ -0,0 +1,123 @@
+//! Gemeinsame Schnittstelle für austauschbare Neuronenmodelle
+//!
+//! Das [`NeuronModel`]-Trait beschreibt alles, was ein [`Network`](crate::neural::Network)
+//! von einem Neuron benötigt: Eingaben empfangen, die Dynamik um einen Zeitschritt
+//! fortschreiben, Spikes melden und den Zustand nach außen sichtbar machen.
+//! Dadurch können unterschiedliche Zellmodelle nebeneinander im selben Netzwerk laufen.
+
+use crate::neural::growth::Position;
+use crate::neural::neuron::model::NeuronState;
+use std::any::Any;
+use std::fmt;
+use uuid::Uuid;
+
+/// Schnittstelle für alle Neuronenmodelle, die von einem Netzwerk verwaltet werden können
+///
+/// Implementierungen werden über `typetag` serialisiert, damit Netzwerke mit
+/// gemischten Modellen gespeichert und geladen werden können. Eigene Modelle
+/// müssen dafür ebenfalls mit `#[typetag::serde]` annotiert werden.
+#[typetag::serde(tag = "model")]
+pub trait NeuronModel: fmt::Debug + Send + Sync {
+    /// Gibt die eindeutige ID des Neurons zurück
+    fn id(&self) -> &Uuid;
+
+    /// Kurzer, stabiler Name des Modells (z.B. für Exporte und Telemetrie)
+    fn model_name(&self) -> &'static str;
+
+    /// Empfängt ein Eingabesignal
+    ///
+    /// # Returns
+    ///
+    /// `true`, wenn das Neuron durch diese Eingabe unmittelbar aktiviert wurde
+    fn receive_input(&mut self, input: f32) -> bool;
+
+    /// Schreibt die Dynamik des Neurons um einen Zeitschritt fort
+    ///
+    /// # Arguments
+    ///
+    /// * `time_step` - Zeitschritt in Sekunden
+    ///
+    /// # Returns
+    ///
+    /// `true`, wenn das Neuron in diesem Zeitschritt einen Spike aussendet
+    fn step(&mut self, time_step: f32) -> bool;
+
+    /// Gibt an, ob das Neuron gerade feuert
+    fn is_spiking(&self) -> bool {
+        self.state() == NeuronState::Active
+    }
+
+    /// Gibt den aktuellen (vereinfachten) Zustand des Neurons zurück
+    fn state(&self) -> NeuronState;
+
+    /// Gibt die aktuelle Aktivierungsenergie bzw. das Membranpotential zurück
+    fn activation_energy(&self) -> f32;
+
+    /// Gibt den aktuellen Aktivierungsschwellwert zurück
+    fn threshold(&self) -> f32;
+
+    /// Gibt die Position des Neurons im 3D-Raum zurück
+    fn position(&self) -> &Position;
+
+    /// Setzt die Position des Neurons
+    fn set_position(&mut self, position: Position);
+
+    /// Setzt den dynamischen Zustand des Neurons zurück
+    fn reset(&mut self);
+
+    /// Erstellt eine geboxte Kopie des Neurons
+    fn clone_box(&self) -> Box<dyn NeuronModel>;
+
+    /// Ermöglicht den Zugriff auf den konkreten Typ
+    fn as_any(&self) -> &dyn Any;
+
+    /// Ermöglicht den veränderbaren Zugriff auf den konkreten Typ
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+
+    /// Gibt das Neuron als analytisch lösbares Modell zurück, falls es das unterstützt
+    ///
+    /// Nur solche Modelle können ereignisgesteuert simuliert werden
+    /// ([`Network::run_event_driven`](crate::neural::Network::run_event_driven)).
+    fn as_analytic(&self) -> Option<&dyn AnalyticNeuron> {
+        None
+    }
+
+    /// Gibt das Neuron als veränderbares analytisch lösbares Modell zurück
+    fn as_analytic_mut(&mut self) -> Option<&mut dyn AnalyticNeuron> {
+        None
+    }
+}
+
+/// Erweiterung für Modelle, deren Dynamik zwischen zwei Eingaben geschlossen lösbar ist
+///
+/// Ein ereignisgesteuerter Simulator schreibt solche Neuronen nur dann fort, wenn
+/// ein Ereignis sie erreicht, und sagt den nächsten selbstständigen Spike voraus,
+/// statt jeden Zeitschritt zu integrieren. Eingaben werden weiterhin über
+/// [`NeuronModel::receive_input`] empfangen.
+pub trait AnalyticNeuron {
+    /// Schreibt den Zustand ohne Eingaben um `duration` Sekunden fort
+    ///
+    /// Das Neuron feuert dabei nicht; der Simulator schreibt es höchstens bis zum
+    /// vorhergesagten Spike fort und ruft dann [`AnalyticNeuron::fire`] auf.
+    fn advance(&mut self, duration: f64);
+
+    /// Gibt die Zeit in Sekunden bis zum nächsten Spike ohne weitere Eingaben zurück
+    ///
+    /// `None`, wenn das Neuron ohne Eingaben nie feuert.
+    fn time_to_spike(&self) -> Option<f64>;
+
+    /// Löst einen Spike aus
+    fn fire(&mut self);
+}
+
+impl Clone for Box<dyn NeuronModel> {
+    fn clone(&self) -> Self {
+        self.clone_box()
+    }
+}
+
+/// Fabrikfunktion, mit der ein [`NetworkBuilder`](crate::neural::NetworkBuilder)
+/// Neuronen eines beliebigen Modells erzeugt
+///
+/// Die Fabrik erhält die vom Builder vergebene ID des neuen Neurons.
+pub type NeuronFactory = Box<dyn Fn(Uuid) -> Box<dyn NeuronModel>>;
@@ -1,7 +1,7 @@
 //! # Synapsen-Modul
 //!
 //! Dieses Modul implementiert synaptische Verbindungen zwischen Neuronen,
-//! mit Unterstützung für synaptische Plastizität (Hebbsches Lernen).
+//! mit Unterstützung für synaptische Plastizität (Hebbsches Lernen und STDP).
 //!
 //! ## Überblick
 //!
@@ -11,15 +11,36 @@
 //! - Verbinden jeweils ein präsynaptisches mit einem postsynaptischen Neuron
 //! - Modulieren das Signal durch ein Gewicht (Stärke der Verbindung)
 //! - Fügen eine Verzögerung bei der Signalübertragung ein
-//! - Passen ihre Stärke durch Hebbsches Lernen an (synaptische Plastizität)
+//! - Passen ihre Stärke durch austauschbare Lernregeln ([`PlasticityRule`]) oder
+//!   Spike-Timing-abhängige Plastizität ([`StdpRule`]) an
+//! - Können optional kurzzeitig depressiv oder fazilitierend wirken
+//!   ([`ShortTermPlasticity`] nach Tsodyks und Markram)
 //!
 //! ## Biologische Inspiration
 //!
 //! Das Modell basiert auf biologischen Synapsen, verwendet aber Vereinfachungen für
 //! Effizienz. Wir modellieren sowohl erregende als auch hemmende Effekte
-//! durch positive und negative Gewichte.
+//! durch positive und negative Gewichte. Der [`SynapseType`] legt fest, welches
+//! Vorzeichen ein Gewicht haben darf; modulierende Synapsen beeinflussen statt des
+//! Membranpotentials die Plastizität am Zielneuron.
 
 pub mod model;
+pub mod plasticity;
+pub mod short_term;
+pub mod stdp;
 pub mod tests;
 
-pub use model::{Synapse, SynapseBuilder, constants};
+#[cfg(test)]
+mod plasticity_tests;
+#[cfg(test)]
+mod short_term_tests;
+#[cfg(test)]
+mod stdp_tests;
+
+pub use model::{Synapse, SynapseBuilder, SynapseType, constants};
+pub use plasticity::{
+    ActivityAverage, BcmRule, CovarianceRule, HebbianRule, OjaRule, PlasticityRule,
+    SynapticActivity,
+};
+pub use short_term::{ShortTermPlasticity, StpParams};
+pub use stdp::{SpikeTrace, StdpBounds, StdpRule};
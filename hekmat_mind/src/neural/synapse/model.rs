@@ -1,3 +1,5 @@
+use crate::neural::synapse::short_term::{ShortTermPlasticity, StpParams};
+use crate::neural::synapse::stdp::StdpRule;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
@@ -13,6 +15,44 @@ pub mod constants {
     pub const ACTIVE_DURATION: f32 = 0.005; // 5ms
 }
 
+/// Art der synaptischen Wirkung auf das postsynaptische Neuron
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum SynapseType {
+    /// Erregend: depolarisiert das Zielneuron, Gewicht im Bereich 0.0 bis 1.0
+    #[default]
+    Excitatory,
+    /// Hemmend: hyperpolarisiert das Zielneuron, Gewicht im Bereich -1.0 bis 0.0
+    Inhibitory,
+    /// Modulierend: wirkt nicht auf das Membranpotential, sondern skaliert die
+    /// Plastizität der eingehenden Synapsen des Zielneurons (Gewicht 0.0 bis 1.0)
+    Modulatory,
+}
+
+impl SynapseType {
+    /// Gibt den zulässigen Gewichtsbereich `(min, max)` dieses Typs zurück
+    pub fn weight_range(self) -> (f32, f32) {
+        match self {
+            SynapseType::Excitatory | SynapseType::Modulatory => (0.0, 1.0),
+            SynapseType::Inhibitory => (-1.0, 0.0),
+        }
+    }
+
+    /// Leitet den Typ aus dem Vorzeichen eines Gewichts ab
+    pub fn from_weight(weight: f32) -> Self {
+        if weight < 0.0 {
+            SynapseType::Inhibitory
+        } else {
+            SynapseType::Excitatory
+        }
+    }
+
+    /// Begrenzt ein Gewicht auf den zulässigen Bereich dieses Typs
+    pub fn clamp_weight(self, weight: f32) -> f32 {
+        let (min, max) = self.weight_range();
+        weight.clamp(min, max)
+    }
+}
+
 /// Repräsentiert eine synaptische Verbindung zwischen zwei Neuronen
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Synapse {
@@ -22,7 +62,11 @@ pub struct Synapse {
     /// ID des postsynaptischen Neurons
     post_neuron_id: Uuid,
 
-    /// Synaptisches Gewicht (0.0 bis 1.0)
+    /// Art der Synapse (erregend, hemmend oder modulierend)
+    #[serde(default)]
+    synapse_type: SynapseType,
+
+    /// Synaptisches Gewicht (-1.0 bis 1.0, Vorzeichen passend zum Typ)
     weight: f32,
 
     /// Verzögerung der Signalübertragung in Sekunden
@@ -33,6 +77,10 @@ pub struct Synapse {
 
     /// Verbleibende Zeit im aktiven Zustand
     active_time_remaining: f32,
+
+    /// Optionale Kurzzeitplastizität (Depression und Fazilitation)
+    #[serde(default)]
+    short_term: Option<ShortTermPlasticity>,
 }
 
 impl Synapse {
@@ -42,18 +90,51 @@ impl Synapse {
     ///
     /// * `pre_neuron_id` - ID des präsynaptischen Neurons
     /// * `post_neuron_id` - ID des postsynaptischen Neurons
-    /// * `weight` - Anfangsgewicht der Synapse (0.0-1.0)
+    /// * `weight` - Anfangsgewicht der Synapse (-1.0-1.0)
+    ///
+    /// Der Typ wird aus dem Vorzeichen abgeleitet: Negative Gewichte ergeben eine
+    /// hemmende, alle anderen eine erregende Synapse.
     pub fn new(pre_neuron_id: Uuid, post_neuron_id: Uuid, weight: f32) -> Self {
+        Self::with_type(
+            pre_neuron_id,
+            post_neuron_id,
+            weight,
+            SynapseType::from_weight(weight),
+        )
+    }
+
+    /// Erstellt eine neue Synapse mit explizitem Typ
+    ///
+    /// # Arguments
+    ///
+    /// * `pre_neuron_id` - ID des präsynaptischen Neurons
+    /// * `post_neuron_id` - ID des postsynaptischen Neurons
+    /// * `weight` - Anfangsgewicht, wird auf den Bereich des Typs begrenzt
+    /// * `synapse_type` - Art der Synapse
+    pub fn with_type(
+        pre_neuron_id: Uuid,
+        post_neuron_id: Uuid,
+        weight: f32,
+        synapse_type: SynapseType,
+    ) -> Self {
         Self {
             pre_neuron_id,
             post_neuron_id,
-            weight: weight.clamp(0.0, 1.0),
+            synapse_type,
+            weight: synapse_type.clamp_weight(weight),
             delay: constants::DEFAULT_DELAY,
             active: false,
             active_time_remaining: 0.0,
+            short_term: None,
         }
     }
 
+    /// Verbindet die Synapse mit anderen Neuronen, ohne ihren Zustand zu verändern
+    pub(crate) fn set_endpoints(&mut self, pre_neuron_id: Uuid, post_neuron_id: Uuid) {
+        self.pre_neuron_id = pre_neuron_id;
+        self.post_neuron_id = post_neuron_id;
+    }
+
     /// Gibt die ID des präsynaptischen Neurons zurück
     pub fn pre_neuron_id(&self) -> &Uuid {
         &self.pre_neuron_id
@@ -69,6 +150,21 @@ impl Synapse {
         self.weight
     }
 
+    /// Gibt die Art der Synapse zurück
+    pub fn synapse_type(&self) -> SynapseType {
+        self.synapse_type
+    }
+
+    /// Gibt an, ob die Synapse hemmend ist
+    pub fn is_inhibitory(&self) -> bool {
+        self.synapse_type == SynapseType::Inhibitory
+    }
+
+    /// Gibt den Betrag des Gewichts zurück (Stärke unabhängig von der Wirkung)
+    pub fn strength(&self) -> f32 {
+        self.weight.abs()
+    }
+
     /// Gibt die Verzögerung der Synapse zurück
     pub fn delay(&self) -> f32 {
         self.delay
@@ -79,6 +175,34 @@ impl Synapse {
         self.active
     }
 
+    /// Gibt den Zustand der Kurzzeitplastizität zurück, falls aktiviert
+    pub fn short_term_plasticity(&self) -> Option<&ShortTermPlasticity> {
+        self.short_term.as_ref()
+    }
+
+    /// Aktiviert die Kurzzeitplastizität mit den angegebenen Parametern oder
+    /// deaktiviert sie mit `None`
+    pub fn set_short_term_plasticity(&mut self, params: Option<StpParams>) {
+        self.short_term = params.map(ShortTermPlasticity::new);
+    }
+
+    /// Setzt die Kurzzeitplastizität in den ausgeruhten Zustand zurück
+    pub fn reset_short_term_plasticity(&mut self) {
+        if let Some(short_term) = &mut self.short_term {
+            short_term.reset();
+        }
+    }
+
+    /// Gibt das Gewicht zurück, mit dem der nächste Spike übertragen würde
+    ///
+    /// Ohne Kurzzeitplastizität entspricht es dem Gewicht.
+    pub fn efficacy(&self) -> f32 {
+        match &self.short_term {
+            Some(short_term) => self.weight * short_term.efficacy(),
+            None => self.weight,
+        }
+    }
+
     /// Überträgt ein Signal durch die Synapse
     ///
     /// # Arguments
@@ -87,11 +211,16 @@ impl Synapse {
     ///
     /// # Returns
     ///
-    /// Das gewichtete Signal, das zum postsynaptischen Neuron gesendet wird
+    /// Das gewichtete Signal, das zum postsynaptischen Neuron gesendet wird. Bei
+    /// aktivierter Kurzzeitplastizität wird es mit der aktuellen Ausschüttung skaliert.
     pub fn transmit(&mut self, input: f32) -> f32 {
         self.active = true;
         self.active_time_remaining = constants::ACTIVE_DURATION;
-        input * self.weight
+        let gain = self
+            .short_term
+            .as_mut()
+            .map_or(1.0, ShortTermPlasticity::on_spike);
+        input * self.weight * gain
     }
 
     /// Aktualisiert den Zustand der Synapse
@@ -100,6 +229,9 @@ impl Synapse {
     ///
     /// * `time_step` - Zeitschritt in Sekunden
     pub fn update(&mut self, time_step: f32) {
+        if let Some(short_term) = &mut self.short_term {
+            short_term.recover(time_step);
+        }
         if self.active {
             self.active_time_remaining -= time_step;
             if self.active_time_remaining <= 0.0 {
@@ -122,26 +254,53 @@ impl Synapse {
         post_active: bool,
         plasticity_rate: f32,
     ) {
+        // Gelernt wird auf dem Betrag, damit hemmende Synapsen ebenfalls stärker werden
+        let mut strength = self.strength();
+
         // Hebbsches Lernen: "Neurons that fire together, wire together"
         if pre_active && post_active {
             // Verstärkung bei gemeinsamer Aktivität
-            self.weight += plasticity_rate * (1.0 - self.weight);
+            strength += plasticity_rate * (1.0 - strength);
         } else if pre_active && !post_active {
             // Abschwächung bei präsynaptischer, aber nicht postsynaptischer Aktivität
-            self.weight -= plasticity_rate * self.weight;
+            strength -= plasticity_rate * strength;
         }
 
-        // Gewicht auf gültigen Bereich beschränken
-        self.weight = self.weight.clamp(0.0, 1.0);
+        self.set_strength(strength);
+    }
+
+    /// Wendet eine STDP-Gewichtsänderung auf die Stärke der Synapse an
+    ///
+    /// # Arguments
+    ///
+    /// * `rule` - STDP-Regel, deren Gewichtsbegrenzung angewendet wird
+    /// * `potentiation` - Verstärkung durch postsynaptische Spikes
+    /// * `depression` - Abschwächung durch präsynaptische Spikes
+    pub fn apply_stdp(&mut self, rule: &StdpRule, potentiation: f32, depression: f32) {
+        let strength = rule.update_weight(self.strength(), potentiation, depression);
+        self.set_strength(strength);
+    }
+
+    /// Setzt die Stärke der Synapse, das Vorzeichen ergibt sich aus dem Typ
+    ///
+    /// # Arguments
+    ///
+    /// * `strength` - Neuer Betrag des Gewichts (wird auf 0.0-1.0 begrenzt)
+    pub fn set_strength(&mut self, strength: f32) {
+        let strength = strength.clamp(0.0, 1.0);
+        self.weight = match self.synapse_type {
+            SynapseType::Inhibitory => -strength,
+            SynapseType::Excitatory | SynapseType::Modulatory => strength,
+        };
     }
 
     /// Setzt das Gewicht der Synapse direkt
     ///
     /// # Arguments
     ///
-    /// * `new_weight` - Neues Gewicht (wird auf 0.0-1.0 begrenzt)
+    /// * `new_weight` - Neues Gewicht (wird auf den Bereich des Synapsentyps begrenzt)
     pub fn set_weight(&mut self, new_weight: f32) {
-        self.weight = new_weight.clamp(0.0, 1.0);
+        self.weight = self.synapse_type.clamp_weight(new_weight);
     }
 
     /// Setzt die Verzögerung der Synapse
@@ -160,6 +319,8 @@ pub struct SynapseBuilder {
     post_neuron_id: Option<Uuid>,
     weight: f32,
     delay: f32,
+    synapse_type: Option<SynapseType>,
+    short_term: Option<StpParams>,
 }
 
 impl SynapseBuilder {
@@ -170,6 +331,8 @@ impl SynapseBuilder {
             post_neuron_id: None,
             weight: 0.5, // Standardgewicht
             delay: constants::DEFAULT_DELAY,
+            synapse_type: None,
+            short_term: None,
         }
     }
 
@@ -191,12 +354,26 @@ impl SynapseBuilder {
         self
     }
 
+    /// Setzt die Art der Synapse
+    ///
+    /// Ohne Angabe wird der Typ aus dem Vorzeichen des Gewichts abgeleitet.
+    pub fn with_type(mut self, synapse_type: SynapseType) -> Self {
+        self.synapse_type = Some(synapse_type);
+        self
+    }
+
     /// Setzt die Verzögerung
     pub fn with_delay(mut self, delay: f32) -> Self {
         self.delay = delay;
         self
     }
 
+    /// Aktiviert die Kurzzeitplastizität mit den angegebenen Parametern
+    pub fn with_short_term_plasticity(mut self, params: StpParams) -> Self {
+        self.short_term = Some(params);
+        self
+    }
+
     /// Erstellt die Synapse
     pub fn build(self) -> Synapse {
         // Sicherstellen, dass die erforderlichen Felder gesetzt sind
@@ -207,8 +384,12 @@ impl SynapseBuilder {
             .post_neuron_id
             .expect("Postsynaptische Neuron-ID muss gesetzt sein");
 
-        let mut synapse = Synapse::new(pre_id, post_id, self.weight);
+        let synapse_type = self
+            .synapse_type
+            .unwrap_or_else(|| SynapseType::from_weight(self.weight));
+        let mut synapse = Synapse::with_type(pre_id, post_id, self.weight, synapse_type);
         synapse.set_delay(self.delay);
+        synapse.set_short_term_plasticity(self.short_term);
 
         synapse
     }
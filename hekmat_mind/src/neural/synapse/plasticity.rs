@@ -0,0 +1,258 @@
+//! Austauschbare ratenbasierte Lernregeln
+//!
+//! Eine [`PlasticityRule`] berechnet aus der Aktivität der beiden verbundenen Neuronen
+//! die Änderung des Gewichtsbetrags einer Synapse. Das Netzwerk ruft die Regel in jedem
+//! Zyklus für jede Synapse auf; Regeln können netzwerkweit oder für eine Projektion
+//! (alle Synapsen zwischen zwei Neuronengruppen) registriert werden.
+//!
+//! Mitgeliefert werden:
+//!
+//! - [`HebbianRule`]: `Δw = η · x · y`
+//! - [`OjaRule`]: `Δw = η · y · (x - y · w)`
+//! - [`BcmRule`]: `Δw = η · x · y · (y - θ)` mit gleitender Schwelle `θ = ⟨y²⟩ / y₀`
+//! - [`CovarianceRule`]: `Δw = η · (x - ⟨x⟩) · (y - ⟨y⟩)`
+//!
+//! `x` und `y` sind die prä- bzw. postsynaptische Aktivität (1.0 bei einem Spike,
+//! sonst 0.0), `⟨·⟩` bezeichnet den gleitenden Mittelwert über die Zeit.
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Standardparameter der Lernregeln
+pub mod constants {
+    /// Standard-Lernrate pro Zyklus
+    pub const DEFAULT_LEARNING_RATE: f32 = 0.01;
+    /// Standard-Zielaktivität der BCM-Regel (Anteil der Zyklen mit Spike)
+    pub const DEFAULT_BCM_TARGET_ACTIVITY: f32 = 0.1;
+    /// Standard-Zeitkonstante der gleitenden Aktivitätsmittelwerte in Sekunden
+    pub const DEFAULT_AVERAGING_TIME_CONSTANT: f32 = 0.1;
+}
+
+/// Aktivität an einer Synapse, aus der eine Lernregel die Gewichtsänderung berechnet
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct SynapticActivity {
+    /// Aktuelle präsynaptische Aktivität `x`
+    pub pre: f32,
+    /// Aktuelle postsynaptische Aktivität `y`
+    pub post: f32,
+    /// Gleitender Mittelwert der präsynaptischen Aktivität
+    pub pre_mean: f32,
+    /// Gleitender Mittelwert der postsynaptischen Aktivität
+    pub post_mean: f32,
+    /// Gleitender Mittelwert der quadrierten postsynaptischen Aktivität
+    pub post_mean_square: f32,
+    /// Aktueller Betrag des Gewichts
+    pub weight: f32,
+}
+
+/// Schnittstelle für Lernregeln, die in jedem Zyklus auf Synapsen angewendet werden
+///
+/// Implementierungen werden wie Neuronenmodelle über `typetag` serialisiert; eigene
+/// Regeln müssen dafür mit `#[typetag::serde]` annotiert werden.
+#[typetag::serde(tag = "rule")]
+pub trait PlasticityRule: fmt::Debug + Send + Sync {
+    /// Kurzer, stabiler Name der Regel
+    fn name(&self) -> &'static str;
+
+    /// Berechnet die Änderung des Gewichtsbetrags für einen Zyklus
+    fn weight_change(&self, activity: &SynapticActivity) -> f32;
+
+    /// Erstellt eine geboxte Kopie der Regel
+    fn clone_box(&self) -> Box<dyn PlasticityRule>;
+}
+
+impl Clone for Box<dyn PlasticityRule> {
+    fn clone(&self) -> Self {
+        self.clone_box()
+    }
+}
+
+/// Gleitende Mittelwerte der Aktivität eines Neurons
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct ActivityAverage {
+    /// Mittelwert der Aktivität
+    pub mean: f32,
+    /// Mittelwert der quadrierten Aktivität
+    pub mean_square: f32,
+}
+
+impl ActivityAverage {
+    /// Schreibt die Mittelwerte mit einer neuen Aktivität fort
+    ///
+    /// # Arguments
+    ///
+    /// * `activity` - Aktivität im aktuellen Zyklus
+    /// * `time_step` - Zeitschritt in Sekunden
+    /// * `time_constant` - Zeitkonstante des Mittelwerts in Sekunden
+    pub fn update(&mut self, activity: f32, time_step: f32, time_constant: f32) {
+        let alpha = (time_step / time_constant).min(1.0);
+        self.mean += alpha * (activity - self.mean);
+        self.mean_square += alpha * (activity * activity - self.mean_square);
+    }
+}
+
+/// Klassische Hebbsche Regel: gemeinsame Aktivität verstärkt die Synapse
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct HebbianRule {
+    /// Lernrate pro Zyklus
+    pub learning_rate: f32,
+}
+
+impl HebbianRule {
+    /// Erstellt eine Hebbsche Regel mit der angegebenen Lernrate
+    pub fn new(learning_rate: f32) -> Self {
+        Self { learning_rate }
+    }
+}
+
+impl Default for HebbianRule {
+    fn default() -> Self {
+        Self::new(constants::DEFAULT_LEARNING_RATE)
+    }
+}
+
+#[typetag::serde]
+impl PlasticityRule for HebbianRule {
+    fn name(&self) -> &'static str {
+        "hebbian"
+    }
+
+    fn weight_change(&self, activity: &SynapticActivity) -> f32 {
+        self.learning_rate * activity.pre * activity.post
+    }
+
+    fn clone_box(&self) -> Box<dyn PlasticityRule> {
+        Box::new(*self)
+    }
+}
+
+/// Regel nach Oja: Hebbsches Lernen mit Gewichtsnormierung
+///
+/// Der Zerfallsterm `-y² · w` begrenzt das Gewicht, ohne auf Abschneiden
+/// angewiesen zu sein.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct OjaRule {
+    /// Lernrate pro Zyklus
+    pub learning_rate: f32,
+}
+
+impl OjaRule {
+    /// Erstellt eine Oja-Regel mit der angegebenen Lernrate
+    pub fn new(learning_rate: f32) -> Self {
+        Self { learning_rate }
+    }
+}
+
+impl Default for OjaRule {
+    fn default() -> Self {
+        Self::new(constants::DEFAULT_LEARNING_RATE)
+    }
+}
+
+#[typetag::serde]
+impl PlasticityRule for OjaRule {
+    fn name(&self) -> &'static str {
+        "oja"
+    }
+
+    fn weight_change(&self, activity: &SynapticActivity) -> f32 {
+        self.learning_rate * activity.post * (activity.pre - activity.post * activity.weight)
+    }
+
+    fn clone_box(&self) -> Box<dyn PlasticityRule> {
+        Box::new(*self)
+    }
+}
+
+/// Regel nach Bienenstock, Cooper und Munro (BCM)
+///
+/// Liegt die postsynaptische Aktivität über der gleitenden Schwelle `θ`, wird die
+/// Synapse verstärkt, sonst abgeschwächt. Die Schwelle steigt mit der mittleren
+/// quadrierten Aktivität und stabilisiert so die Feuerrate um die Zielaktivität.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct BcmRule {
+    /// Lernrate pro Zyklus
+    pub learning_rate: f32,
+    /// Zielaktivität `y₀`, auf die die Schwelle normiert wird
+    pub target_activity: f32,
+}
+
+impl BcmRule {
+    /// Erstellt eine BCM-Regel mit der angegebenen Lernrate und Zielaktivität
+    pub fn new(learning_rate: f32, target_activity: f32) -> Self {
+        Self {
+            learning_rate,
+            target_activity,
+        }
+    }
+
+    /// Gibt die gleitende Schwelle `θ` für die angegebene Aktivität zurück
+    pub fn threshold(&self, activity: &SynapticActivity) -> f32 {
+        activity.post_mean_square / self.target_activity
+    }
+}
+
+impl Default for BcmRule {
+    fn default() -> Self {
+        Self::new(
+            constants::DEFAULT_LEARNING_RATE,
+            constants::DEFAULT_BCM_TARGET_ACTIVITY,
+        )
+    }
+}
+
+#[typetag::serde]
+impl PlasticityRule for BcmRule {
+    fn name(&self) -> &'static str {
+        "bcm"
+    }
+
+    fn weight_change(&self, activity: &SynapticActivity) -> f32 {
+        let theta = self.threshold(activity);
+        self.learning_rate * activity.pre * activity.post * (activity.post - theta)
+    }
+
+    fn clone_box(&self) -> Box<dyn PlasticityRule> {
+        Box::new(*self)
+    }
+}
+
+/// Kovarianzregel: Lernen aus Abweichungen vom Mittelwert
+///
+/// Gemeinsam überdurchschnittliche Aktivität verstärkt die Synapse, gegenläufige
+/// Abweichungen schwächen sie ab.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct CovarianceRule {
+    /// Lernrate pro Zyklus
+    pub learning_rate: f32,
+}
+
+impl CovarianceRule {
+    /// Erstellt eine Kovarianzregel mit der angegebenen Lernrate
+    pub fn new(learning_rate: f32) -> Self {
+        Self { learning_rate }
+    }
+}
+
+impl Default for CovarianceRule {
+    fn default() -> Self {
+        Self::new(constants::DEFAULT_LEARNING_RATE)
+    }
+}
+
+#[typetag::serde]
+impl PlasticityRule for CovarianceRule {
+    fn name(&self) -> &'static str {
+        "covariance"
+    }
+
+    fn weight_change(&self, activity: &SynapticActivity) -> f32 {
+        self.learning_rate
+            * (activity.pre - activity.pre_mean)
+            * (activity.post - activity.post_mean)
+    }
+
+    fn clone_box(&self) -> Box<dyn PlasticityRule> {
+        Box::new(*self)
+    }
+}
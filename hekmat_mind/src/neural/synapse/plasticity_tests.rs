@@ -0,0 +1,127 @@
+use crate::neural::synapse::plasticity::{
+    ActivityAverage, BcmRule, CovarianceRule, HebbianRule, OjaRule, PlasticityRule,
+    SynapticActivity,
+};
+
+/// Erzeugt eine Aktivität ohne Vorgeschichte
+fn activity(pre: f32, post: f32, weight: f32) -> SynapticActivity {
+    SynapticActivity {
+        pre,
+        post,
+        weight,
+        ..SynapticActivity::default()
+    }
+}
+
+#[test]
+fn test_hebbian_rule_requires_joint_activity() {
+    let rule = HebbianRule::new(0.1);
+
+    assert!((rule.weight_change(&activity(1.0, 1.0, 0.5)) - 0.1).abs() < 1e-6);
+    assert_eq!(rule.weight_change(&activity(1.0, 0.0, 0.5)), 0.0);
+    assert_eq!(rule.weight_change(&activity(0.0, 1.0, 0.5)), 0.0);
+    assert_eq!(rule.name(), "hebbian");
+}
+
+#[test]
+fn test_oja_rule_normalizes_weight() {
+    let rule = OjaRule::new(0.05);
+
+    // Bei gemeinsamer Aktivität strebt das Gewicht gegen x / y = 1
+    let mut weight = 0.2;
+    for _ in 0..500 {
+        weight += rule.weight_change(&activity(1.0, 1.0, weight));
+    }
+    assert!((weight - 1.0).abs() < 1e-3);
+
+    // Postsynaptische Aktivität ohne präsynaptische Eingabe schwächt ab
+    assert!(rule.weight_change(&activity(0.0, 1.0, 0.5)) < 0.0);
+    // Ohne postsynaptische Aktivität keine Änderung
+    assert_eq!(rule.weight_change(&activity(1.0, 0.0, 0.5)), 0.0);
+}
+
+#[test]
+fn test_bcm_rule_sliding_threshold() {
+    let rule = BcmRule::new(0.1, 0.1);
+
+    // Seltene postsynaptische Aktivität liegt über der Schwelle: Verstärkung
+    let quiet = SynapticActivity {
+        post_mean_square: 0.05,
+        ..activity(1.0, 1.0, 0.5)
+    };
+    assert!((rule.threshold(&quiet) - 0.5).abs() < 1e-6);
+    assert!(rule.weight_change(&quiet) > 0.0);
+
+    // Häufige Aktivität hebt die Schwelle über die aktuelle Aktivität: Abschwächung
+    let busy = SynapticActivity {
+        post_mean_square: 0.3,
+        ..activity(1.0, 1.0, 0.5)
+    };
+    assert!(rule.threshold(&busy) > 1.0);
+    assert!(rule.weight_change(&busy) < 0.0);
+
+    // Ohne präsynaptische Aktivität keine Änderung
+    assert_eq!(rule.weight_change(&activity(0.0, 1.0, 0.5)), 0.0);
+}
+
+#[test]
+fn test_covariance_rule_uses_deviations_from_mean() {
+    let rule = CovarianceRule::new(0.1);
+    let with_means = |pre, post| SynapticActivity {
+        pre_mean: 0.2,
+        post_mean: 0.2,
+        ..activity(pre, post, 0.5)
+    };
+
+    // Gemeinsam überdurchschnittlich: Verstärkung
+    assert!((rule.weight_change(&with_means(1.0, 1.0)) - 0.064).abs() < 1e-6);
+    // Gegenläufig: Abschwächung
+    assert!(rule.weight_change(&with_means(1.0, 0.0)) < 0.0);
+    assert!(rule.weight_change(&with_means(0.0, 1.0)) < 0.0);
+    // Gemeinsam unterdurchschnittlich: schwache Verstärkung
+    assert!(rule.weight_change(&with_means(0.0, 0.0)) > 0.0);
+}
+
+#[test]
+fn test_activity_average_tracks_rate() {
+    let mut average = ActivityAverage::default();
+
+    // Jeder zehnte Zyklus aktiv: Mittelwert nähert sich 0.1
+    for step in 0..5000 {
+        let active = if step % 10 == 0 { 1.0 } else { 0.0 };
+        average.update(active, 0.001, 0.1);
+    }
+    assert!((average.mean - 0.1).abs() < 0.02);
+    // Für binäre Aktivität stimmen Mittelwert und quadratischer Mittelwert überein
+    assert!((average.mean - average.mean_square).abs() < 1e-5);
+
+    // Zeitschritte über der Zeitkonstante übernehmen den aktuellen Wert
+    average.update(1.0, 1.0, 0.1);
+    assert_eq!(average.mean, 1.0);
+}
+
+#[test]
+fn test_boxed_rules_clone_and_serialize() {
+    let rules: Vec<Box<dyn PlasticityRule>> = vec![
+        Box::new(HebbianRule::default()),
+        Box::new(OjaRule::default()),
+        Box::new(BcmRule::default()),
+        Box::new(CovarianceRule::default()),
+    ];
+    let probe = SynapticActivity {
+        pre_mean: 0.1,
+        post_mean: 0.3,
+        post_mean_square: 0.02,
+        ..activity(1.0, 1.0, 0.4)
+    };
+
+    for rule in &rules {
+        let cloned = rule.clone();
+        assert_eq!(cloned.name(), rule.name());
+
+        let json = serde_json::to_string(rule).unwrap();
+        let restored: Box<dyn PlasticityRule> = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.name(), rule.name());
+        assert_eq!(restored.weight_change(&probe), rule.weight_change(&probe));
+    }
+}
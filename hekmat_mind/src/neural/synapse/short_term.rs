@@ -0,0 +1,139 @@
+//! Kurzzeitplastizität nach Tsodyks und Markram
+//!
+//! Jede Übertragung verbraucht einen Anteil `u` der verfügbaren synaptischen
+//! Ressourcen `x`. Zwischen den Spikes erholen sich die Ressourcen mit `τ_D` auf 1,
+//! die Fazilitation `u` klingt mit `τ_F` ab:
+//!
+//! ```text
+//! dx/dt = (1 - x) / τ_D          bei einem Spike: u ← u + U · (1 - u)
+//! du/dt = -u / τ_F                                Ausschüttung = u · x,  x ← x - u · x
+//! ```
+//!
+//! Schnell aufeinanderfolgende Spikes erschöpfen die Ressourcen (Depression) oder
+//! erhöhen die Ausnutzung (Fazilitation). Die Ausschüttung wird auf `U` normiert,
+//! sodass eine ausgeruhte Synapse genau ihr Gewicht überträgt.
+
+use serde::{Deserialize, Serialize};
+
+/// Parameter der Kurzzeitplastizität
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct StpParams {
+    /// Ausnutzung `U` einer ausgeruhten Synapse (0.0 bis 1.0)
+    pub utilization: f32,
+    /// Erholungszeitkonstante `τ_D` der Ressourcen in Sekunden
+    pub tau_recovery: f32,
+    /// Zeitkonstante `τ_F` der Fazilitation in Sekunden (0 = keine Fazilitation)
+    pub tau_facilitation: f32,
+}
+
+impl StpParams {
+    /// Erstellt einen Parametersatz
+    pub fn new(utilization: f32, tau_recovery: f32, tau_facilitation: f32) -> Self {
+        Self {
+            utilization: utilization.clamp(f32::EPSILON, 1.0),
+            tau_recovery: tau_recovery.max(0.0),
+            tau_facilitation: tau_facilitation.max(0.0),
+        }
+    }
+
+    /// Depressive Synapse (kortikale Pyramidenzellen, Tsodyks et al. 2000)
+    pub fn depressing() -> Self {
+        Self::new(0.5, 0.8, 0.0)
+    }
+
+    /// Fazilitierende Synapse (Pyramidenzelle auf Interneuron, Tsodyks et al. 2000)
+    pub fn facilitating() -> Self {
+        Self::new(0.1, 0.1, 1.0)
+    }
+
+    /// Synapse für Arbeitsgedächtnis-Modelle nach Mongillo et al. (2008)
+    pub fn working_memory() -> Self {
+        Self::new(0.2, 0.2, 1.5)
+    }
+}
+
+/// Zustand der Kurzzeitplastizität einer Synapse
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ShortTermPlasticity {
+    /// Modellparameter
+    params: StpParams,
+    /// Ausnutzung `u` vor dem nächsten Spike (0 bei einer ausgeruhten Synapse)
+    utilization: f32,
+    /// Verfügbare Ressourcen `x`
+    resources: f32,
+}
+
+impl ShortTermPlasticity {
+    /// Erstellt eine ausgeruhte Synapse mit den angegebenen Parametern
+    pub fn new(params: StpParams) -> Self {
+        Self {
+            params,
+            utilization: 0.0,
+            resources: 1.0,
+        }
+    }
+
+    /// Gibt die Parameter zurück
+    pub fn params(&self) -> &StpParams {
+        &self.params
+    }
+
+    /// Gibt die aktuelle Ausnutzung `u` vor dem nächsten Spike zurück
+    pub fn utilization(&self) -> f32 {
+        self.utilization
+    }
+
+    /// Gibt die verfügbaren Ressourcen `x` zurück
+    pub fn resources(&self) -> f32 {
+        self.resources
+    }
+
+    /// Gibt den Verstärkungsfaktor zurück, mit dem der nächste Spike übertragen würde
+    pub fn efficacy(&self) -> f32 {
+        let u = self.facilitated_utilization();
+        u * self.resources / self.params.utilization
+    }
+
+    /// Ausnutzung unmittelbar nach einem Spike
+    fn facilitated_utilization(&self) -> f32 {
+        self.utilization + self.params.utilization * (1.0 - self.utilization)
+    }
+
+    /// Verarbeitet einen präsynaptischen Spike und gibt den Verstärkungsfaktor zurück
+    pub fn on_spike(&mut self) -> f32 {
+        self.utilization = self.facilitated_utilization();
+        let release = self.utilization * self.resources;
+        self.resources -= release;
+        release / self.params.utilization
+    }
+
+    /// Lässt Ressourcen und Ausnutzung über einen Zeitschritt relaxieren
+    ///
+    /// # Arguments
+    ///
+    /// * `time_step` - Zeitschritt in Sekunden
+    pub fn recover(&mut self, time_step: f32) {
+        let StpParams {
+            tau_recovery,
+            tau_facilitation,
+            ..
+        } = self.params;
+
+        self.resources = if tau_recovery > 0.0 {
+            1.0 - (1.0 - self.resources) * (-time_step / tau_recovery).exp()
+        } else {
+            1.0
+        };
+        self.utilization = if tau_facilitation > 0.0 {
+            self.utilization * (-time_step / tau_facilitation).exp()
+        } else {
+            0.0
+        };
+    }
+
+    /// Setzt die Synapse in den ausgeruhten Zustand zurück
+    pub fn reset(&mut self) {
+        self.utilization = 0.0;
+        self.resources = 1.0;
+    }
+}
@@ -0,0 +1,178 @@
+use crate::neural::network::model::Network;
+use crate::neural::neuron::model::Neuron;
+use crate::neural::synapse::model::{Synapse, SynapseBuilder};
+use crate::neural::synapse::short_term::{ShortTermPlasticity, StpParams};
+use uuid::Uuid;
+
+/// Überträgt einen regelmäßigen Spikezug und gibt die Verstärkungsfaktoren zurück
+fn spike_train_gains(stp: &mut ShortTermPlasticity, spikes: usize, interval: f32) -> Vec<f32> {
+    (0..spikes)
+        .map(|_| {
+            let gain = stp.on_spike();
+            stp.recover(interval);
+            gain
+        })
+        .collect()
+}
+
+#[test]
+fn test_rested_synapse_transmits_full_weight() {
+    for params in [
+        StpParams::depressing(),
+        StpParams::facilitating(),
+        StpParams::working_memory(),
+    ] {
+        let mut stp = ShortTermPlasticity::new(params);
+        assert!((stp.efficacy() - 1.0).abs() < 1e-6);
+        assert!((stp.on_spike() - 1.0).abs() < 1e-6);
+    }
+}
+
+#[test]
+fn test_depressing_synapse_weakens_and_recovers() {
+    let params = StpParams::depressing();
+    let mut stp = ShortTermPlasticity::new(params);
+
+    // 50 Hz: jeder weitere Spike findet weniger Ressourcen vor
+    let gains = spike_train_gains(&mut stp, 10, 0.02);
+    for pair in gains.windows(2) {
+        assert!(pair[1] < pair[0]);
+    }
+    assert!(gains[9] < 0.2);
+
+    // Nach τ_D ist das Ressourcendefizit auf 1/e gesunken
+    let deficit = 1.0 - stp.resources();
+    stp.recover(params.tau_recovery);
+    let expected = deficit * (-1.0_f32).exp();
+    assert!((1.0 - stp.resources() - expected).abs() < 1e-5);
+
+    // Nach langer Pause ist die Synapse wieder ausgeruht
+    stp.recover(10.0);
+    assert!((stp.efficacy() - 1.0).abs() < 1e-3);
+}
+
+#[test]
+fn test_facilitating_synapse_strengthens() {
+    let mut stp = ShortTermPlasticity::new(StpParams::facilitating());
+
+    // 20 Hz: die Ausnutzung wächst schneller, als die Ressourcen erschöpfen
+    let gains = spike_train_gains(&mut stp, 5, 0.05);
+    for pair in gains.windows(2) {
+        assert!(pair[1] > pair[0]);
+    }
+    assert!(gains[4] > 1.5);
+
+    // Die Fazilitation klingt mit τ_F ab
+    stp.recover(5.0);
+    assert!(stp.utilization() < 0.01);
+    stp.reset();
+    assert_eq!(stp.utilization(), 0.0);
+    assert_eq!(stp.resources(), 1.0);
+}
+
+#[test]
+fn test_synapse_short_term_plasticity_is_opt_in() {
+    let (pre, post) = (Uuid::new_v4(), Uuid::new_v4());
+
+    // Ohne Kurzzeitplastizität bleibt die Übertragung unverändert
+    let mut plain = Synapse::new(pre, post, 0.5);
+    assert!(plain.short_term_plasticity().is_none());
+    for _ in 0..5 {
+        assert_eq!(plain.transmit(1.0), 0.5);
+        plain.update(0.001);
+    }
+
+    let mut depressing = SynapseBuilder::new()
+        .with_pre_neuron_id(pre)
+        .with_post_neuron_id(post)
+        .with_weight(0.5)
+        .with_short_term_plasticity(StpParams::depressing())
+        .build();
+    assert!((depressing.efficacy() - 0.5).abs() < 1e-6);
+    let first = depressing.transmit(1.0);
+    depressing.update(0.001);
+    let second = depressing.transmit(1.0);
+    assert!((first - 0.5).abs() < 1e-6);
+    assert!(second < 0.3);
+
+    // Hemmende Synapsen werden im Betrag abgeschwächt
+    let mut inhibitory = Synapse::new(pre, post, -0.8);
+    inhibitory.set_short_term_plasticity(Some(StpParams::depressing()));
+    inhibitory.transmit(1.0);
+    assert!(inhibitory.efficacy() < 0.0 && inhibitory.efficacy() > -0.8);
+
+    depressing.reset_short_term_plasticity();
+    assert!((depressing.efficacy() - 0.5).abs() < 1e-6);
+    depressing.set_short_term_plasticity(None);
+    assert!(depressing.short_term_plasticity().is_none());
+}
+
+#[test]
+fn test_short_term_plasticity_survives_serialization() {
+    let mut synapse = SynapseBuilder::new()
+        .with_pre_neuron_id(Uuid::new_v4())
+        .with_post_neuron_id(Uuid::new_v4())
+        .with_short_term_plasticity(StpParams::facilitating())
+        .build();
+    synapse.transmit(1.0);
+
+    let json = serde_json::to_string(&synapse).unwrap();
+    let restored: Synapse = serde_json::from_str(&json).unwrap();
+    assert_eq!(
+        restored.short_term_plasticity(),
+        synapse.short_term_plasticity()
+    );
+}
+
+#[test]
+fn test_network_delivers_depressed_signals() {
+    let mut network = Network::new();
+    let source = Neuron::new(100).with_timing(0.002, 0.005);
+    // Hohe Schwelle: das Zielneuron sammelt die Eingaben, ohne zu feuern
+    let target = Neuron::with_params(100, 10.0, 0.0);
+    let (source_id, target_id) = (*source.id(), *target.id());
+    network.add_neuron(source);
+    network.add_neuron(target);
+    network.add_synapse(
+        SynapseBuilder::new()
+            .with_pre_neuron_id(source_id)
+            .with_post_neuron_id(target_id)
+            .with_weight(1.0)
+            .with_short_term_plasticity(StpParams::depressing())
+            .build(),
+    );
+    network.disable_plasticity();
+
+    // Wiederholte Spikes: die ersten Lieferungen erhöhen die Energie des Ziels immer weniger
+    let mut increments = Vec::new();
+    let mut energy = 0.0;
+    for cycle in 0..60 {
+        if cycle % 10 == 0 {
+            network.stimulate_neuron(&source_id, 1.0);
+        }
+        network.cycle(0.001);
+        let current = network.get_neuron(&target_id).unwrap().activation_energy();
+        if current > energy + 1e-6 {
+            increments.push(current - energy);
+        }
+        energy = current;
+    }
+
+    assert!(increments.len() >= 4, "Lieferungen: {:?}", increments);
+    assert!((increments[0] - 1.0).abs() < 1e-3);
+    for pair in increments[..4].windows(2) {
+        assert!(pair[1] < pair[0], "Lieferungen: {:?}", increments);
+    }
+
+    let stp = network
+        .get_synapse(&source_id, &target_id)
+        .unwrap()
+        .short_term_plasticity()
+        .unwrap();
+    assert!(stp.resources() < 0.5);
+
+    // Ein Netzwerk-Reset setzt auch die Kurzzeitplastizität zurück
+    network.reset();
+    let synapse = network.get_synapse(&source_id, &target_id).unwrap();
+    assert_eq!(synapse.short_term_plasticity().unwrap().resources(), 1.0);
+}
@@ -0,0 +1,277 @@
+//! Spike-Timing-abhängige Plastizität (STDP)
+//!
+//! Die Gewichtsänderung hängt vom zeitlichen Abstand `Δt = t_post - t_pre` zwischen
+//! prä- und postsynaptischen Spikes ab. Feuert das präsynaptische Neuron kurz vor dem
+//! postsynaptischen (`Δt > 0`), wird die Synapse verstärkt, im umgekehrten Fall
+//! abgeschwächt:
+//!
+//! ```text
+//! Δw(Δt) =  A+ · exp(-Δt / τ+)   für Δt > 0
+//! Δw(Δt) = -A- · exp( Δt / τ-)   für Δt < 0
+//! ```
+//!
+//! Implementiert ist die spurbasierte Formulierung nach Pfister & Gerstner (2006):
+//! Jedes Neuron trägt exponentiell abklingende Spuren seiner Spikes. Bei einem
+//! postsynaptischen Spike wird um `r1 · (A2+ + A3+ · o2)` verstärkt, bei einem
+//! präsynaptischen Spike um `o1 · (A2- + A3- · r2)` abgeschwächt. Ohne die
+//! Triplett-Terme `A3±` ergibt sich die klassische paarbasierte Regel mit
+//! Wechselwirkung aller Spikepaare.
+//!
+//! Alle Zeitkonstanten werden in Sekunden angegeben.
+
+use serde::{Deserialize, Serialize};
+
+/// Standardparameter der STDP-Regeln
+pub mod constants {
+    /// Amplitude der Verstärkung der paarbasierten Regel
+    pub const DEFAULT_A_PLUS: f32 = 0.01;
+    /// Amplitude der Abschwächung der paarbasierten Regel (leicht depressionsdominiert)
+    pub const DEFAULT_A_MINUS: f32 = 0.0105;
+    /// Zeitkonstante des Verstärkungsfensters in Sekunden (Bi & Poo, 1998)
+    pub const DEFAULT_TAU_PLUS: f32 = 0.0168;
+    /// Zeitkonstante des Abschwächungsfensters in Sekunden (Bi & Poo, 1998)
+    pub const DEFAULT_TAU_MINUS: f32 = 0.0337;
+
+    /// Paar-Amplitude der Verstärkung der Triplett-Regel (Pfister & Gerstner, 2006)
+    pub const TRIPLET_A2_PLUS: f32 = 5e-10;
+    /// Triplett-Amplitude der Verstärkung
+    pub const TRIPLET_A3_PLUS: f32 = 6.2e-3;
+    /// Paar-Amplitude der Abschwächung der Triplett-Regel
+    pub const TRIPLET_A2_MINUS: f32 = 7e-3;
+    /// Triplett-Amplitude der Abschwächung
+    pub const TRIPLET_A3_MINUS: f32 = 0.0;
+    /// Zeitkonstante der langsamen präsynaptischen Spur in Sekunden
+    pub const TRIPLET_TAU_X: f32 = 0.101;
+    /// Zeitkonstante der langsamen postsynaptischen Spur in Sekunden
+    pub const TRIPLET_TAU_Y: f32 = 0.125;
+}
+
+/// Begrenzung der Gewichte bei STDP
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum StdpBounds {
+    /// Additive Änderungen, das Ergebnis wird auf `[0, w_max]` abgeschnitten
+    #[default]
+    Hard,
+    /// Multiplikative Änderungen: Verstärkung skaliert mit `w_max - w`,
+    /// Abschwächung mit `w`, sodass sich die Grenzen nur asymptotisch nähern
+    Soft,
+}
+
+/// Parameter einer paar- oder triplettbasierten STDP-Regel
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct StdpRule {
+    /// Amplitude der Verstärkung (`A2+`)
+    pub a_plus: f32,
+    /// Amplitude der Abschwächung (`A2-`)
+    pub a_minus: f32,
+    /// Zeitkonstante der schnellen präsynaptischen Spur `r1` in Sekunden
+    pub tau_plus: f32,
+    /// Zeitkonstante der schnellen postsynaptischen Spur `o1` in Sekunden
+    pub tau_minus: f32,
+    /// Triplett-Amplitude der Verstärkung (`A3+`, 0 für die Paarregel)
+    pub a3_plus: f32,
+    /// Triplett-Amplitude der Abschwächung (`A3-`, 0 für die Paarregel)
+    pub a3_minus: f32,
+    /// Zeitkonstante der langsamen präsynaptischen Spur `r2` in Sekunden
+    pub tau_x: f32,
+    /// Zeitkonstante der langsamen postsynaptischen Spur `o2` in Sekunden
+    pub tau_y: f32,
+    /// Art der Gewichtsbegrenzung
+    pub bounds: StdpBounds,
+    /// Obere Grenze für den Betrag des Gewichts
+    pub max_weight: f32,
+}
+
+impl Default for StdpRule {
+    fn default() -> Self {
+        Self::pair()
+    }
+}
+
+impl StdpRule {
+    /// Erstellt eine paarbasierte Regel mit den Standardparametern
+    pub fn pair() -> Self {
+        Self {
+            a_plus: constants::DEFAULT_A_PLUS,
+            a_minus: constants::DEFAULT_A_MINUS,
+            tau_plus: constants::DEFAULT_TAU_PLUS,
+            tau_minus: constants::DEFAULT_TAU_MINUS,
+            a3_plus: 0.0,
+            a3_minus: 0.0,
+            tau_x: constants::TRIPLET_TAU_X,
+            tau_y: constants::TRIPLET_TAU_Y,
+            bounds: StdpBounds::Hard,
+            max_weight: 1.0,
+        }
+    }
+
+    /// Erstellt eine Triplett-Regel mit den Parametern des minimalen Modells für
+    /// den visuellen Kortex aus Pfister & Gerstner (2006)
+    ///
+    /// Die Regel reproduziert die Frequenzabhängigkeit nach Sjöström et al. (2001):
+    /// Bei niedriger Paarungsfrequenz überwiegt die Abschwächung, bei hoher
+    /// Frequenz die Verstärkung, unabhängig von der Reihenfolge der Spikes.
+    pub fn triplet() -> Self {
+        Self {
+            a_plus: constants::TRIPLET_A2_PLUS,
+            a_minus: constants::TRIPLET_A2_MINUS,
+            a3_plus: constants::TRIPLET_A3_PLUS,
+            a3_minus: constants::TRIPLET_A3_MINUS,
+            ..Self::pair()
+        }
+    }
+
+    /// Setzt die Amplituden der Paarterme
+    pub fn with_amplitudes(mut self, a_plus: f32, a_minus: f32) -> Self {
+        self.a_plus = a_plus;
+        self.a_minus = a_minus;
+        self
+    }
+
+    /// Setzt die Zeitkonstanten des STDP-Fensters in Sekunden
+    pub fn with_time_constants(mut self, tau_plus: f32, tau_minus: f32) -> Self {
+        self.tau_plus = tau_plus;
+        self.tau_minus = tau_minus;
+        self
+    }
+
+    /// Setzt die Triplett-Terme und die Zeitkonstanten der langsamen Spuren
+    pub fn with_triplet_terms(
+        mut self,
+        a3_plus: f32,
+        a3_minus: f32,
+        tau_x: f32,
+        tau_y: f32,
+    ) -> Self {
+        self.a3_plus = a3_plus;
+        self.a3_minus = a3_minus;
+        self.tau_x = tau_x;
+        self.tau_y = tau_y;
+        self
+    }
+
+    /// Setzt die Art der Gewichtsbegrenzung
+    pub fn with_bounds(mut self, bounds: StdpBounds) -> Self {
+        self.bounds = bounds;
+        self
+    }
+
+    /// Setzt die obere Grenze für den Betrag des Gewichts (höchstens 1.0)
+    pub fn with_max_weight(mut self, max_weight: f32) -> Self {
+        self.max_weight = max_weight.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Gibt an, ob die Regel Triplett-Terme enthält
+    pub fn is_triplet(&self) -> bool {
+        self.a3_plus != 0.0 || self.a3_minus != 0.0
+    }
+
+    /// Gibt die Gewichtsänderung für ein einzelnes Spikepaar zurück
+    ///
+    /// # Arguments
+    ///
+    /// * `delta_t` - Abstand `t_post - t_pre` in Sekunden
+    ///
+    /// Gleichzeitige Spikes (`Δt = 0`) verändern das Gewicht nicht.
+    pub fn window(&self, delta_t: f32) -> f32 {
+        if delta_t > 0.0 {
+            self.a_plus * (-delta_t / self.tau_plus).exp()
+        } else if delta_t < 0.0 {
+            -self.a_minus * (delta_t / self.tau_minus).exp()
+        } else {
+            0.0
+        }
+    }
+
+    /// Verstärkung bei einem postsynaptischen Spike
+    ///
+    /// Beide Spuren müssen den Wert unmittelbar vor dem Spike haben.
+    pub fn potentiation(&self, pre: &SpikeTrace, post: &SpikeTrace) -> f32 {
+        pre.r1 * (self.a_plus + self.a3_plus * post.o2)
+    }
+
+    /// Abschwächung bei einem präsynaptischen Spike
+    ///
+    /// Beide Spuren müssen den Wert unmittelbar vor dem Spike haben.
+    pub fn depression(&self, pre: &SpikeTrace, post: &SpikeTrace) -> f32 {
+        post.o1 * (self.a_minus + self.a3_minus * pre.r2)
+    }
+
+    /// Wendet Verstärkung und Abschwächung unter Beachtung der Grenzen auf ein Gewicht an
+    ///
+    /// # Arguments
+    ///
+    /// * `weight` - Aktueller Betrag des Gewichts
+    /// * `potentiation` - Nicht-negative Verstärkung
+    /// * `depression` - Nicht-negative Abschwächung
+    pub fn update_weight(&self, weight: f32, potentiation: f32, depression: f32) -> f32 {
+        let updated = match self.bounds {
+            StdpBounds::Hard => weight + potentiation - depression,
+            StdpBounds::Soft => {
+                weight + potentiation * (self.max_weight - weight) - depression * weight
+            }
+        };
+        updated.clamp(0.0, self.max_weight)
+    }
+}
+
+/// Exponentiell abklingende Spikespuren eines Neurons
+///
+/// Die Spuren werden nur bei Spikes aktualisiert und dazwischen analytisch
+/// fortgeschrieben.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct SpikeTrace {
+    /// Schnelle präsynaptische Spur (Zeitkonstante `τ+`)
+    r1: f32,
+    /// Langsame präsynaptische Spur (Zeitkonstante `τx`)
+    r2: f32,
+    /// Schnelle postsynaptische Spur (Zeitkonstante `τ-`)
+    o1: f32,
+    /// Langsame postsynaptische Spur (Zeitkonstante `τy`)
+    o2: f32,
+    /// Zeitpunkt, auf den sich die Werte beziehen, in Sekunden
+    time: f64,
+}
+
+impl SpikeTrace {
+    /// Erstellt leere Spuren
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Gibt die schnelle und die langsame präsynaptische Spur `(r1, r2)` zurück
+    pub fn pre(&self) -> (f32, f32) {
+        (self.r1, self.r2)
+    }
+
+    /// Gibt die schnelle und die langsame postsynaptische Spur `(o1, o2)` zurück
+    pub fn post(&self) -> (f32, f32) {
+        (self.o1, self.o2)
+    }
+
+    /// Gibt die Spuren zum Zeitpunkt `time` zurück
+    pub fn decayed(&self, time: f64, rule: &StdpRule) -> Self {
+        let elapsed = (time - self.time).max(0.0) as f32;
+        if elapsed == 0.0 {
+            return *self;
+        }
+
+        Self {
+            r1: self.r1 * (-elapsed / rule.tau_plus).exp(),
+            r2: self.r2 * (-elapsed / rule.tau_x).exp(),
+            o1: self.o1 * (-elapsed / rule.tau_minus).exp(),
+            o2: self.o2 * (-elapsed / rule.tau_y).exp(),
+            time,
+        }
+    }
+
+    /// Registriert einen Spike zum Zeitpunkt `time` und erhöht alle Spuren um 1
+    pub fn record_spike(&mut self, time: f64, rule: &StdpRule) {
+        *self = self.decayed(time, rule);
+        self.r1 += 1.0;
+        self.r2 += 1.0;
+        self.o1 += 1.0;
+        self.o2 += 1.0;
+    }
+}
@@ -0,0 +1,234 @@
+use crate::neural::network::model::Network;
+use crate::neural::neuron::model::Neuron;
+use crate::neural::synapse::model::Synapse;
+use crate::neural::synapse::stdp::{SpikeTrace, StdpBounds, StdpRule, constants};
+use uuid::Uuid;
+
+/// Summiert die Gewichtsänderungen eines Spikemusters ohne Gewichtsgrenzen
+///
+/// Gleichzeitige Spikes werten die Spuren vor ihrer Erhöhung aus, wie im Netzwerk.
+fn total_weight_change(rule: &StdpRule, pre_times: &[f64], post_times: &[f64]) -> f32 {
+    let mut events: Vec<(f64, bool)> = pre_times
+        .iter()
+        .map(|t| (*t, true))
+        .chain(post_times.iter().map(|t| (*t, false)))
+        .collect();
+    events.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+    let mut pre = SpikeTrace::new();
+    let mut post = SpikeTrace::new();
+    let mut change = 0.0;
+    let mut index = 0;
+    while index < events.len() {
+        let time = events[index].0;
+        let simultaneous: Vec<bool> = events[index..]
+            .iter()
+            .take_while(|(t, _)| *t == time)
+            .map(|(_, is_pre)| *is_pre)
+            .collect();
+        index += simultaneous.len();
+
+        let pre_now = pre.decayed(time, rule);
+        let post_now = post.decayed(time, rule);
+        for is_pre in &simultaneous {
+            if *is_pre {
+                change -= rule.depression(&pre_now, &post_now);
+            } else {
+                change += rule.potentiation(&pre_now, &post_now);
+            }
+        }
+        for is_pre in simultaneous {
+            if is_pre {
+                pre.record_spike(time, rule);
+            } else {
+                post.record_spike(time, rule);
+            }
+        }
+    }
+    change
+}
+
+/// Erzeugt ein Paarungsprotokoll mit `pairs` Paaren bei `frequency` Hz
+fn pairing_protocol(pairs: usize, frequency: f64, delta_t: f64) -> (Vec<f64>, Vec<f64>) {
+    let period = 1.0 / frequency;
+    let pre: Vec<f64> = (0..pairs).map(|i| 0.1 + i as f64 * period).collect();
+    let post = pre.iter().map(|t| t + delta_t).collect();
+    (pre, post)
+}
+
+#[test]
+fn test_stdp_window_shape() {
+    let rule = StdpRule::pair();
+
+    // Kausale Paare verstärken, antikausale schwächen ab
+    assert!(rule.window(0.005) > 0.0);
+    assert!(rule.window(-0.005) < 0.0);
+    assert_eq!(rule.window(0.0), 0.0);
+
+    // Maximum direkt neben Δt = 0, Abfall mit wachsendem Abstand
+    assert!((rule.window(1e-6) - constants::DEFAULT_A_PLUS).abs() < 1e-5);
+    assert!((rule.window(-1e-6) + constants::DEFAULT_A_MINUS).abs() < 1e-5);
+    for pair in [0.002, 0.005, 0.01, 0.02, 0.05, 0.1].windows(2) {
+        assert!(rule.window(pair[0]) > rule.window(pair[1]));
+        assert!(rule.window(-pair[0]) < rule.window(-pair[1]));
+    }
+
+    // Bei Δt = τ ist das Fenster auf 1/e abgefallen
+    let e_inv = (-1.0_f32).exp();
+    let at_tau_plus = rule.window(constants::DEFAULT_TAU_PLUS);
+    let at_tau_minus = rule.window(-constants::DEFAULT_TAU_MINUS);
+    assert!((at_tau_plus - constants::DEFAULT_A_PLUS * e_inv).abs() < 1e-6);
+    assert!((at_tau_minus + constants::DEFAULT_A_MINUS * e_inv).abs() < 1e-6);
+
+    // Die Abschwächung überwiegt im Integral über das Fenster
+    let integral: f32 = (-200..=200)
+        .map(|ms| rule.window(ms as f32 / 1000.0) * 0.001)
+        .sum();
+    assert!(integral < 0.0);
+}
+
+#[test]
+fn test_traces_reproduce_pair_window() {
+    for rule in [StdpRule::pair(), StdpRule::triplet()] {
+        for delta_ms in [-60, -30, -15, -5, -1, 1, 5, 15, 30, 60] {
+            let delta_t = delta_ms as f64 / 1000.0;
+            let change = total_weight_change(&rule, &[0.1], &[0.1 + delta_t]);
+            let expected = rule.window(delta_t as f32);
+            assert!(
+                (change - expected).abs() < 1e-6,
+                "Δt = {} ms: {} statt {}",
+                delta_ms,
+                change,
+                expected
+            );
+        }
+    }
+}
+
+/// Lässt zwei Neuronen im Netzwerk im Abstand von `delta_ms` feuern und gibt die
+/// Änderung des Gewichts zwischen ihnen zurück
+fn network_weight_change(rule: StdpRule, delta_ms: i32) -> f32 {
+    let mut network = Network::new();
+    let pre = Neuron::new(100);
+    let post = Neuron::new(100);
+    let (pre_id, post_id) = (*pre.id(), *post.id());
+    network.add_neuron(pre);
+    network.add_neuron(post);
+    // Unterschwelliges Gewicht, damit nur die Stimulation die Spikezeiten bestimmt
+    network.add_synapse(Synapse::new(pre_id, post_id, 0.1));
+    network.set_stdp(Some(rule));
+
+    let pre_step = 20;
+    let post_step = pre_step + delta_ms;
+    for step in 0..(pre_step + delta_ms.abs() + 10) {
+        if step == pre_step {
+            network.stimulate_neuron(&pre_id, 1.0);
+        }
+        if step == post_step {
+            network.stimulate_neuron(&post_id, 1.0);
+        }
+        network.cycle(0.001);
+    }
+
+    network.get_synapse(&pre_id, &post_id).unwrap().weight() - 0.1
+}
+
+#[test]
+fn test_network_reproduces_pair_window() {
+    for rule in [StdpRule::pair(), StdpRule::triplet()] {
+        let rule = rule.with_bounds(StdpBounds::Hard);
+        for delta_ms in [-15, -5, 5, 15] {
+            let change = network_weight_change(rule, delta_ms);
+            let expected = rule.window(delta_ms as f32 / 1000.0);
+            assert_eq!(change.signum(), (delta_ms as f32).signum());
+            assert!(
+                (change - expected).abs() < 1e-5,
+                "Δt = {} ms: {} statt {}",
+                delta_ms,
+                change,
+                expected
+            );
+        }
+    }
+}
+
+#[test]
+fn test_custom_parameters() {
+    let rule = StdpRule::pair()
+        .with_amplitudes(0.02, 0.01)
+        .with_time_constants(0.010, 0.020);
+
+    assert!((rule.window(0.010) - 0.02 * (-1.0_f32).exp()).abs() < 1e-6);
+    assert!((rule.window(-0.020) + 0.01 * (-1.0_f32).exp()).abs() < 1e-6);
+    assert!(!rule.is_triplet());
+    assert!(StdpRule::triplet().is_triplet());
+    assert!(rule.with_triplet_terms(0.001, 0.0, 0.1, 0.05).is_triplet());
+}
+
+#[test]
+fn test_hard_and_soft_bounds() {
+    let hard = StdpRule::pair().with_bounds(StdpBounds::Hard);
+    assert!((hard.update_weight(0.5, 0.1, 0.0) - 0.6).abs() < 1e-6);
+    assert!((hard.update_weight(0.5, 0.0, 0.1) - 0.4).abs() < 1e-6);
+    assert_eq!(hard.update_weight(0.95, 0.1, 0.0), 1.0);
+    assert_eq!(hard.update_weight(0.05, 0.0, 0.1), 0.0);
+
+    // Weiche Grenzen skalieren mit dem Abstand zur jeweiligen Grenze
+    let soft = StdpRule::pair().with_bounds(StdpBounds::Soft);
+    assert!((soft.update_weight(0.5, 0.1, 0.0) - 0.55).abs() < 1e-6);
+    assert!((soft.update_weight(0.9, 0.1, 0.0) - 0.91).abs() < 1e-6);
+    assert!((soft.update_weight(0.1, 0.0, 0.1) - 0.09).abs() < 1e-6);
+
+    let mut weight = 0.5;
+    for _ in 0..1000 {
+        weight = soft.update_weight(weight, 0.05, 0.0);
+    }
+    assert!(weight < 1.0 && weight > 0.99);
+
+    // Die obere Grenze ist konfigurierbar
+    let capped = StdpRule::pair().with_max_weight(0.8);
+    assert_eq!(capped.update_weight(0.75, 0.1, 0.0), 0.8);
+}
+
+#[test]
+fn test_synapse_applies_stdp_to_strength() {
+    let rule = StdpRule::pair();
+    let mut excitatory = Synapse::new(Uuid::new_v4(), Uuid::new_v4(), 0.5);
+    excitatory.apply_stdp(&rule, 0.1, 0.0);
+    assert!((excitatory.weight() - 0.6).abs() < 1e-6);
+
+    // Hemmende Synapsen behalten ihr Vorzeichen
+    let mut inhibitory = Synapse::new(Uuid::new_v4(), Uuid::new_v4(), -0.5);
+    inhibitory.apply_stdp(&rule, 0.1, 0.0);
+    assert!((inhibitory.weight() + 0.6).abs() < 1e-6);
+}
+
+#[test]
+fn test_triplet_rule_depends_on_pairing_frequency() {
+    let pair = StdpRule::pair();
+    let triplet = StdpRule::triplet();
+
+    // Antikausale Paarung (post 10 ms vor pre): bei niedriger Frequenz Abschwächung,
+    // bei hoher Frequenz überwiegt in der Triplett-Regel die Verstärkung
+    let (pre, post) = pairing_protocol(60, 1.0, -0.010);
+    assert!(total_weight_change(&triplet, &pre, &post) < 0.0);
+    let (pre, post) = pairing_protocol(60, 50.0, -0.010);
+    assert!(total_weight_change(&triplet, &pre, &post) > 0.0);
+
+    // Kausale Paarung: kaum Änderung bei 1 Hz, deutliche Verstärkung bei 40 Hz
+    let low = {
+        let (pre, post) = pairing_protocol(60, 1.0, 0.010);
+        total_weight_change(&triplet, &pre, &post)
+    };
+    let high = {
+        let (pre, post) = pairing_protocol(60, 40.0, 0.010);
+        total_weight_change(&triplet, &pre, &post)
+    };
+    assert!(low.abs() < 1e-3);
+    assert!(high > 100.0 * low.abs());
+
+    // Die Paarregel ist für isolierte Paare frequenzunabhängig
+    let (pre, post) = pairing_protocol(60, 1.0, 0.010);
+    let expected = 60.0 * pair.window(0.010);
+    assert!((total_weight_change(&pair, &pre, &post) - expected).abs() < 1e-4);
+}
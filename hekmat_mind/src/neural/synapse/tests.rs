@@ -1,6 +1,6 @@
 #[cfg(test)]
 mod synapse_tests {
-    use crate::neural::synapse::model::{Synapse, SynapseBuilder};
+    use crate::neural::synapse::model::{Synapse, SynapseBuilder, SynapseType};
     use uuid::Uuid;
 
     /// Testet die Erstellung einer Synapse zwischen zwei Neuronen
@@ -94,4 +94,76 @@ mod synapse_tests {
         assert_eq!(synapse.weight(), 0.7);
         assert!(synapse.delay() > 0.0);
     }
+
+    /// Testet, dass der Synapsentyp das Vorzeichen des Gewichts festlegt
+    #[test]
+    fn test_signed_weights_follow_synapse_type() {
+        let pre_id = Uuid::new_v4();
+        let post_id = Uuid::new_v4();
+
+        let excitatory = Synapse::new(pre_id, post_id, 0.4);
+        assert_eq!(excitatory.synapse_type(), SynapseType::Excitatory);
+
+        let mut inhibitory = Synapse::new(pre_id, post_id, -0.4);
+        assert_eq!(inhibitory.synapse_type(), SynapseType::Inhibitory);
+        assert!(inhibitory.is_inhibitory());
+        assert_eq!(inhibitory.strength(), 0.4);
+        assert_eq!(inhibitory.transmit(1.0), -0.4);
+
+        // Positive Gewichte werden bei hemmenden Synapsen auf 0.0 begrenzt
+        inhibitory.set_weight(0.3);
+        assert_eq!(inhibitory.weight(), 0.0);
+
+        let modulatory = Synapse::with_type(pre_id, post_id, -0.2, SynapseType::Modulatory);
+        assert_eq!(modulatory.weight(), 0.0);
+        assert_eq!(SynapseType::Modulatory.weight_range(), (0.0, 1.0));
+    }
+
+    /// Testet Hebbsches Lernen auf hemmenden Synapsen
+    #[test]
+    fn test_inhibitory_hebbian_plasticity() {
+        let mut synapse = Synapse::new(Uuid::new_v4(), Uuid::new_v4(), -0.5);
+
+        // Gemeinsame Aktivität verstärkt die Hemmung
+        synapse.apply_hebbian_plasticity(true, true, 0.1);
+        assert!(synapse.weight() < -0.5);
+
+        // Fehlende postsynaptische Aktivität schwächt sie ab, ohne das Vorzeichen zu wechseln
+        for _ in 0..100 {
+            synapse.apply_hebbian_plasticity(true, false, 0.2);
+        }
+        assert!(synapse.weight() <= 0.0 && synapse.weight() > -0.01);
+    }
+
+    /// Testet den Synapsentyp im SynapseBuilder
+    #[test]
+    fn test_synapse_builder_with_type() {
+        let pre_id = Uuid::new_v4();
+        let post_id = Uuid::new_v4();
+
+        let inhibitory = SynapseBuilder::new()
+            .with_pre_neuron_id(pre_id)
+            .with_post_neuron_id(post_id)
+            .with_type(SynapseType::Inhibitory)
+            .with_weight(-0.7)
+            .build();
+        assert_eq!(inhibitory.synapse_type(), SynapseType::Inhibitory);
+        assert_eq!(inhibitory.weight(), -0.7);
+
+        // Ohne expliziten Typ entscheidet das Vorzeichen
+        let inferred = SynapseBuilder::new()
+            .with_pre_neuron_id(pre_id)
+            .with_post_neuron_id(post_id)
+            .with_weight(-0.2)
+            .build();
+        assert_eq!(inferred.synapse_type(), SynapseType::Inhibitory);
+
+        let modulatory = SynapseBuilder::new()
+            .with_pre_neuron_id(pre_id)
+            .with_post_neuron_id(post_id)
+            .with_type(SynapseType::Modulatory)
+            .build();
+        assert_eq!(modulatory.synapse_type(), SynapseType::Modulatory);
+        assert_eq!(modulatory.weight(), 0.5);
+    }
 }
@@ -137,15 +137,15 @@ impl QueryableCollector for InMemoryCollector {
     fn query_metrics(&self, component: &str) -> HashMap<String, Vec<MetricPoint>> {
         let key = self.get_component_key(component);
 
-        if let Ok(data_guard) = self.data.read() {
-            if let Some(component_data) = data_guard.get(&key) {
-                // Klonen der Daten für die Rückgabe
-                let mut result = HashMap::new();
-                for (metric_name, points) in component_data {
-                    result.insert(metric_name.clone(), points.clone());
-                }
-                return result;
+        if let Ok(data_guard) = self.data.read()
+            && let Some(component_data) = data_guard.get(&key)
+        {
+            // Klonen der Daten für die Rückgabe
+            let mut result = HashMap::new();
+            for (metric_name, points) in component_data {
+                result.insert(metric_name.clone(), points.clone());
             }
+            return result;
         }
 
         HashMap::new()
@@ -154,52 +154,51 @@ impl QueryableCollector for InMemoryCollector {
     fn query_stats(&self, component: &str, metric: &str) -> Option<MetricStats> {
         let component_key = self.get_component_key(component);
 
-        if let Ok(data_guard) = self.data.read() {
-            if let Some(component_data) = data_guard.get(&component_key) {
-                if let Some(points) = component_data.get(metric) {
-                    if points.is_empty() {
-                        return None;
-                    }
-
-                    // Extrahiere die Werte
-                    let mut values: Vec<f64> = points.iter().map(|p| p.value).collect();
-                    values.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
-
-                    let count = values.len();
-                    let min = *values.first().unwrap_or(&0.0);
-                    let max = *values.last().unwrap_or(&0.0);
-                    let sum: f64 = values.iter().sum();
-                    let avg = if count > 0 { sum / count as f64 } else { 0.0 };
-
-                    // Berechne Perzentile
-                    let median_idx = count / 2;
-                    let median = if count > 0 { values[median_idx] } else { 0.0 };
-
-                    let p95_idx = (count as f64 * 0.95) as usize;
-                    let p95 = if p95_idx < count {
-                        values[p95_idx]
-                    } else {
-                        max
-                    };
-
-                    let p99_idx = (count as f64 * 0.99) as usize;
-                    let p99 = if p99_idx < count {
-                        values[p99_idx]
-                    } else {
-                        max
-                    };
-
-                    return Some(MetricStats {
-                        min,
-                        max,
-                        avg,
-                        median,
-                        p95,
-                        p99,
-                        count,
-                    });
-                }
+        if let Ok(data_guard) = self.data.read()
+            && let Some(component_data) = data_guard.get(&component_key)
+            && let Some(points) = component_data.get(metric)
+        {
+            if points.is_empty() {
+                return None;
             }
+
+            // Extrahiere die Werte
+            let mut values: Vec<f64> = points.iter().map(|p| p.value).collect();
+            values.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+
+            let count = values.len();
+            let min = *values.first().unwrap_or(&0.0);
+            let max = *values.last().unwrap_or(&0.0);
+            let sum: f64 = values.iter().sum();
+            let avg = if count > 0 { sum / count as f64 } else { 0.0 };
+
+            // Berechne Perzentile
+            let median_idx = count / 2;
+            let median = if count > 0 { values[median_idx] } else { 0.0 };
+
+            let p95_idx = (count as f64 * 0.95) as usize;
+            let p95 = if p95_idx < count {
+                values[p95_idx]
+            } else {
+                max
+            };
+
+            let p99_idx = (count as f64 * 0.99) as usize;
+            let p99 = if p99_idx < count {
+                values[p99_idx]
+            } else {
+                max
+            };
+
+            return Some(MetricStats {
+                min,
+                max,
+                avg,
+                median,
+                p95,
+                p99,
+                count,
+            });
         }
 
         None
@@ -4,15 +4,26 @@
 // im HekmatMind-Projekt und sorgen für eine hohe Testabdeckung.
 
 use hekmat_mind::benchmark::BenchmarkScenario;
-use hekmat_mind::benchmark::scenarios::{
-    Network, NetworkScalabilityBenchmark, SingleNeuronBenchmark,
-};
+use hekmat_mind::benchmark::scenarios::{NetworkScalabilityBenchmark, SingleNeuronBenchmark};
 use hekmat_mind::neural::neuron::Neuron;
+use hekmat_mind::neural::{Network, Synapse};
 use hekmat_mind::telemetry::collector::QueryableCollector;
 use hekmat_mind::telemetry::in_memory::InMemoryCollector;
 use hekmat_mind::telemetry::registry;
 use hekmat_mind::telemetry::registry_mut;
 
+/// Zeitschritt eines Netzwerkzyklus in den Netzwerktests
+const TIME_STEP: f32 = 0.001;
+
+// Hilfsfunktion: Anzahl der Neuronen, die im letzten Zyklus gefeuert haben
+fn active_count(network: &Network) -> usize {
+    network
+        .neurons()
+        .values()
+        .filter(|neuron| neuron.is_spiking())
+        .count()
+}
+
 // Hilfsfunktion zur Konfiguration der Telemetrie für Tests
 fn setup_telemetry() -> InMemoryCollector {
     let collector = InMemoryCollector::new(500);
@@ -107,11 +118,11 @@ fn test_single_neuron_benchmark_telemetry_labels() {
 }
 
 #[test]
-fn test_network_stub_creation() {
-    let network = Network::new("test_network");
+fn test_network_creation() {
+    let network = Network::new();
 
     // Teste Neuron-Hinzufügen
-    let mut network_with_neurons = Network::new("network_with_neurons");
+    let mut network_with_neurons = Network::new();
     network_with_neurons.add_neuron(Neuron::new(200));
     network_with_neurons.add_neuron(Neuron::new(300));
 
@@ -124,22 +135,24 @@ fn test_network_stub_creation() {
 }
 
 #[test]
-fn test_network_stub_input_and_cycle() {
-    let mut network = Network::new("test_network");
+fn test_network_input_and_cycle() {
+    let mut network = Network::new();
 
     // Füge Neuronen hinzu
-    network.add_neuron(Neuron::new(200));
+    let first = Neuron::new(200);
+    let first_id = *first.id();
+    network.add_neuron(first);
     network.add_neuron(Neuron::new(300));
 
     // Sende Eingaben an Neuronen
-    network.send_input(0, 0.8);
+    network.stimulate_neuron(&first_id, 0.8);
 
     // Führe einen Zyklus aus
-    let active_count = network.cycle();
+    network.cycle(TIME_STEP);
 
     // Prüfe, ob mindestens ein Neuron aktiv wurde (bei diesem Input sollte das so sein)
     assert!(
-        active_count > 0,
+        active_count(&network) > 0,
         "Mindestens ein Neuron sollte durch den Input aktiviert werden"
     );
 }
@@ -249,7 +262,7 @@ fn test_network_scalability_benchmark_telemetry_labels() {
 fn test_network_with_various_sizes() {
     // Teste die Erstellung von Netzwerken verschiedener Größen
     for size in [1, 5, 10] {
-        let mut network = Network::new(&format!("test_network_{}", size));
+        let mut network = Network::new();
 
         // Füge die angegebene Anzahl an Neuronen hinzu
         for i in 0..size {
@@ -265,13 +278,13 @@ fn test_network_with_various_sizes() {
         );
 
         // Führe einen Zyklus aus
-        let active_count = network.cycle();
+        network.cycle(TIME_STEP);
 
         // Wir können nicht genau vorhersagen, wie viele Neuronen aktiv sein werden,
         // da dies von den internen Zuständen und Schwellwerten abhängt, aber wir können
         // zumindest prüfen, ob die Funktion einen plausiblen Wert zurückgibt
         assert!(
-            active_count <= size as usize,
+            active_count(&network) <= size as usize,
             "Es können nicht mehr aktive Neuronen als Gesamtneuronen geben"
         );
     }
@@ -279,33 +292,39 @@ fn test_network_with_various_sizes() {
 
 #[test]
 fn test_network_with_complex_connectivity() {
-    let mut network = Network::new("complex_network");
+    let mut network = Network::new();
 
     // Erstelle ein Netzwerk mit 4 Neuronen
-    for _ in 0..4 {
-        network.add_neuron(Neuron::new(150));
-    }
+    let ids: Vec<_> = (0..4)
+        .map(|_| {
+            let neuron = Neuron::new(150);
+            let id = *neuron.id();
+            network.add_neuron(neuron);
+            id
+        })
+        .collect();
 
     // Erstelle verschiedene Verbindungsmuster
     // 1. Vollständig verbundenes Paar (0-1)
-    network.connect_neurons(0, 1, 0.9);
-    network.connect_neurons(1, 0, 0.9);
+    network.add_synapse(Synapse::new(ids[0], ids[1], 0.9));
+    network.add_synapse(Synapse::new(ids[1], ids[0], 0.9));
 
     // 2. Vorwärtsgerichtete Kette (2-3)
-    network.connect_neurons(2, 3, 0.8);
+    network.add_synapse(Synapse::new(ids[2], ids[3], 0.8));
 
     // 3. Verbinde erste mit zweiter Gruppe
-    network.connect_neurons(0, 2, 0.5);
+    network.add_synapse(Synapse::new(ids[0], ids[2], 0.5));
 
     // Aktiviere erstes Neuron
-    network.send_input(0, 1.0);
+    network.stimulate_neuron(&ids[0], 1.0);
 
     // Führe mehrere Zyklen aus, um Signalausbreitung zu testen
     for _ in 0..3 {
-        network.cycle();
+        network.cycle(TIME_STEP);
     }
 
     // Wir prüfen hier nicht auf spezifische Ergebnisse, da diese von der
     // internen Implementierung der Neuronen abhängen. Der Test dient hauptsächlich
     // zur Codeabdeckung und Sicherstellung, dass keine Ausnahmen auftreten.
+    assert_eq!(network.synapse_count(), 4);
 }
@@ -143,12 +143,12 @@ fn run_network_scalability_benchmark(
     }
 
     // Wenn ein Collector existiert, prüfen wir, ob er Metriken hat
-    if let Some(collector) = collectors.first() {
-        if let Some(in_memory) = collector.as_any().downcast_ref::<InMemoryCollector>() {
-            let network_metrics = in_memory.query_metrics("network");
-            if network_metrics.is_empty() {
-                return Ok(0.0);
-            }
+    if let Some(collector) = collectors.first()
+        && let Some(in_memory) = collector.as_any().downcast_ref::<InMemoryCollector>()
+    {
+        let network_metrics = in_memory.query_metrics("network");
+        if network_metrics.is_empty() {
+            return Ok(0.0);
         }
     }
 
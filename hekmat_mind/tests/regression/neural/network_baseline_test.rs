@@ -176,3 +176,104 @@ fn test_network_reset() {
         );
     }
 }
+
+/// Regressionstest für die Semantik eines Netzwerkzyklus
+///
+/// Ein Zyklus sammelt die Spikes zum Zeitpunkt `t`, stellt alle bis `t + dt` fälligen
+/// Signale zu und integriert danach. In einer Kette mit 1 ms Verzögerung wird das
+/// k-te Neuron daher nach genau k Millisekunden aktiv, unabhängig von der Schrittweite.
+#[test]
+fn test_network_step_semantics() {
+    for (time_step, steps_per_ms) in [(0.001_f32, 1_usize), (0.0005, 2)] {
+        let mut network = Network::new();
+        let ids: Vec<_> = (0..4)
+            .map(|_| {
+                let neuron = Neuron::new(500);
+                let id = *neuron.id();
+                network.add_neuron(neuron);
+                id
+            })
+            .collect();
+        for pair in ids.windows(2) {
+            network.add_synapse(Synapse::new(pair[0], pair[1], 1.0));
+        }
+
+        network.stimulate_neuron(&ids[0], 1.0);
+
+        let mut activation_step = vec![None; ids.len()];
+        activation_step[0] = Some(0);
+        for step in 1..=10 * steps_per_ms {
+            network.cycle(time_step);
+            for (index, id) in ids.iter().enumerate() {
+                if activation_step[index].is_none()
+                    && network.get_neuron(id).unwrap().state() == NeuronState::Active
+                {
+                    activation_step[index] = Some(step);
+                }
+            }
+        }
+
+        let expected: Vec<_> = (0..ids.len()).map(|k| Some(k * steps_per_ms)).collect();
+        assert_eq!(activation_step, expected, "Zeitschritt {} s", time_step);
+        assert!((network.time() - 0.010).abs() < 1e-6);
+
+        // Nach 2 ms aktiver Phase und 5 ms Refraktärzeit ist die Kette wieder ruhig
+        for id in &ids {
+            assert_eq!(
+                network.get_neuron(id).unwrap().state(),
+                NeuronState::Inactive
+            );
+        }
+    }
+}
+
+/// Hemmende Signale werden vor erregenden zugestellt und wirken nur über die
+/// Eingaberegeln des Neuronenmodells.
+#[test]
+fn test_network_inhibition_rules() {
+    let mut network = Network::new();
+    let ids: Vec<_> = (0..5)
+        .map(|_| {
+            let neuron = Neuron::new(500);
+            let id = *neuron.id();
+            network.add_neuron(neuron);
+            id
+        })
+        .collect();
+    let (exciter, inhibitor, late_inhibitor, balanced, active) =
+        (ids[0], ids[1], ids[2], ids[3], ids[4]);
+    network.add_synapse(Synapse::new(exciter, balanced, 0.6));
+    network.add_synapse(Synapse::new(inhibitor, balanced, -0.6));
+    network.add_synapse(Synapse::new(inhibitor, active, -1.0));
+    let mut late_inhibition = Synapse::new(late_inhibitor, active, -1.0);
+    late_inhibition.set_delay(0.003);
+    network.add_synapse(late_inhibition);
+
+    for id in [exciter, inhibitor, late_inhibitor, active] {
+        network.stimulate_neuron(&id, 1.0);
+    }
+    network.cycle(0.001);
+
+    // Gleichzeitig eintreffende Erregung und Hemmung heben sich auf
+    let balanced = network.get_neuron(&balanced).unwrap();
+    assert_eq!(balanced.state(), NeuronState::Inactive);
+    assert!(balanced.activation_energy().abs() < 1e-6);
+
+    // Hemmung nimmt eine bereits ausgelöste Aktivierung nicht zurück
+    let active_neuron = network.get_neuron(&active).unwrap();
+    assert_eq!(active_neuron.state(), NeuronState::Active);
+    assert!(active_neuron.activation_energy().abs() < 1e-6);
+
+    // Die verzögerte Hemmung trifft erst in der Refraktärzeit ein und bleibt wirkungslos
+    network.cycle(0.001);
+    assert_eq!(
+        network.get_neuron(&active).unwrap().state(),
+        NeuronState::Refractory
+    );
+    network.cycle(0.001);
+    network.cycle(0.001);
+    let active_neuron = network.get_neuron(&active).unwrap();
+    assert_eq!(active_neuron.state(), NeuronState::Refractory);
+    assert_eq!(active_neuron.activation_energy(), 0.0);
+    assert_eq!(network.pending_spike_count(), 0);
+}
@@ -1,4 +1,4 @@
-use hekmat_mind::neural::synapse::model::{Synapse, constants};
+use hekmat_mind::neural::synapse::model::{Synapse, SynapseType, constants};
 use std::time::Instant;
 use uuid::Uuid;
 
@@ -160,8 +160,18 @@ fn test_synapse_boundary_values() {
     let over_synapse = Synapse::new(pre_id, post_id, 1.5); // Sollte auf 1.0 begrenzt werden
     assert_eq!(over_synapse.weight(), 1.0);
 
-    let under_synapse = Synapse::new(pre_id, post_id, -0.5); // Sollte auf 0.0 begrenzt werden
-    assert_eq!(under_synapse.weight(), 0.0);
+    // Negative Gewichte ergeben hemmende Synapsen, begrenzt auf -1.0
+    let under_synapse = Synapse::new(pre_id, post_id, -0.5);
+    assert_eq!(under_synapse.weight(), -0.5);
+    assert_eq!(under_synapse.synapse_type(), SynapseType::Inhibitory);
+
+    let far_under_synapse = Synapse::new(pre_id, post_id, -1.5);
+    assert_eq!(far_under_synapse.weight(), -1.0);
+
+    // Gewichte können das Vorzeichen ihres Typs nicht wechseln
+    let mut excitatory = Synapse::new(pre_id, post_id, 0.5);
+    excitatory.set_weight(-0.5);
+    assert_eq!(excitatory.weight(), 0.0);
 
     // Test mit extremen Verzögerungswerten
     let mut test_synapse = Synapse::new(pre_id, post_id, 0.5);